@@ -1,7 +1,14 @@
 mod error;
 mod router;
 
-use std::{fmt::Debug, net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc};
+use std::{
+    fmt::Debug,
+    net::SocketAddr,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 use access_merkle_tree::{
     airdrop_merkle_tree::AirdropMerkleTree, utils::get_merkle_distributor_pda,
@@ -12,7 +19,10 @@ use solana_program::pubkey::Pubkey;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use tracing::{info, instrument};
 
-use crate::{error::ApiError, router::read_distributor};
+use crate::{
+    error::ApiError,
+    router::{load_transferred_claims, read_distributor, refresh_transferred_claims},
+};
 
 pub type Result<T> = std::result::Result<T, ApiError>;
 
@@ -50,6 +60,10 @@ pub struct Args {
     /// Enables the proof endpoint, which will allow users to claim
     #[clap(long, env)]
     enable_proof_endpoint: bool,
+
+    /// Seconds between reloads of the claims transferred to another claimant
+    #[clap(long, env, default_value_t = 60)]
+    transferred_claims_refresh_secs: u64,
 }
 
 #[tokio::main]
@@ -79,13 +93,23 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     let tree = AirdropMerkleTree::new_from_file(&args.merkle_tree_path)?.convert_to_hashmap();
 
+    let transferred_claims =
+        load_transferred_claims(&rpc_client, &args.program_id, &merkle_distributor, &tree).await?;
+    info!("loaded {} transferred claims", transferred_claims.len());
+
     let state = Arc::new(RouterState {
         tree,
         program_id: args.program_id,
         distributor_pubkey: merkle_distributor,
         rpc_client,
+        transferred_claims: RwLock::new(transferred_claims),
     });
 
+    tokio::spawn(refresh_transferred_claims(
+        state.clone(),
+        Duration::from_secs(args.transferred_claims_refresh_secs),
+    ));
+
     let app = router::get_routes(state, args.enable_proof_endpoint);
 
     axum::Server::bind(&args.bind_addr)
@@ -2,11 +2,16 @@ use std::{
     collections::HashMap,
     fmt::{Debug, Formatter},
     str::FromStr,
-    sync::Arc,
+    sync::{Arc, RwLock},
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use access_merkle_tree::{tree_node::TreeNode, utils::get_claim_status_pda};
+use access_merkle_tree::{
+    tree_node::{ExtraAmount, TreeNode},
+    utils::{
+        get_claim_bitmap_pda, get_claim_status_pda, get_custody_vault_pda, get_sol_custody_pda,
+    },
+};
 use anchor_lang::AccountDeserialize;
 use axum::{
     body::Body,
@@ -18,12 +23,17 @@ use axum::{
 };
 use http::Request;
 use merkle_distributor::state::{
-    claim_status::ClaimStatus as MerkleDistributorClaimStatus,
-    merkle_distributor::MerkleDistributor,
+    claim_bitmap::ClaimBitmap, claim_status::ClaimStatus as MerkleDistributorClaimStatus,
+    merkle_distributor::MerkleDistributor, sol_custody::SolCustody,
 };
 use serde_derive::{Deserialize, Serialize};
+use solana_account_decoder::UiAccountEncoding;
 use solana_program::pubkey::Pubkey;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
 use tower::{
     buffer::BufferLayer, limit::RateLimitLayer, load_shed::LoadShedLayer, timeout::TimeoutLayer,
     ServiceBuilder,
@@ -41,6 +51,8 @@ pub struct RouterState {
     pub program_id: Pubkey,
     pub rpc_client: RpcClient,
     pub tree: HashMap<Pubkey, TreeNode>,
+    /// Claimants of transferred claims mapped to their leaf claimants, refreshed in the background
+    pub transferred_claims: RwLock<HashMap<Pubkey, Pubkey>>,
 }
 
 impl Debug for RouterState {
@@ -48,6 +60,10 @@ impl Debug for RouterState {
         f.debug_struct("RouterState")
             .field("program_id", &self.program_id)
             .field("tree", &self.tree.len())
+            .field(
+                "transferred_claims",
+                &self.transferred_claims.read().unwrap().len(),
+            )
             .field("rpc_client", &self.rpc_client.url())
             .finish()
     }
@@ -78,6 +94,7 @@ pub fn get_routes(state: Arc<RouterState>, enable_proof_endpoint: bool) -> Route
         .route("/users", get(get_users))
         .route("/distributor", get(get_distributor))
         .route("/status/:user_pubkey", get(get_status))
+        .route("/custody/:owner_pubkey", get(get_custody))
         .route("/version", get(get_version));
 
     // don't enable until airdrop starts
@@ -102,12 +119,16 @@ async fn get_proof(
         .ok_or(ApiError::UserNotFound(user_pubkey.to_string()))?;
 
     let proof = Proof {
+        index: node.index,
         amount_locked: node.amount_locked(),
         amount_unlocked: node.amount_unlocked(),
+        start_ts: node.start_ts,
+        end_ts: node.end_ts,
         proof: node
             .proof
             .to_owned()
             .ok_or(ApiError::ProofNotFound(user_pubkey.to_string()))?,
+        extra_amounts: node.extra_amounts.clone(),
     };
 
     Ok(Json(proof))
@@ -135,7 +156,7 @@ async fn get_claim_status(
         .pop()
         .ok_or_else(|| ApiError::InternalError)?
         .ok_or_else(|| ApiError::MerkleDistributorError("distributor not found".into()))?;
-    let distributor = MerkleDistributor::try_deserialize(&mut distributor_account.data.as_slice())
+    let distributor = MerkleDistributor::try_deserialize_any_layout(&distributor_account.data)
         .map_err(|e| {
             warn!("error deserializing MerkleDistributor: {:?}", e);
             ApiError::MerkleDistributorError("Error parsing MerkleDistributor".into())
@@ -143,11 +164,26 @@ async fn get_claim_status(
 
     let claim_status_account = accounts.pop().ok_or_else(|| ApiError::InternalError)?;
 
+    if let Some(index) = node.index {
+        return get_claim_bitmap_status(state, node, index, &distributor).await;
+    }
+
+    // the leaf's own vesting schedule, used until the claim status records it
+    let (start_ts, end_ts) = node
+        .vesting_schedule()
+        .unwrap_or((distributor.start_ts, distributor.end_ts));
+
+    let curr_ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
     if distributor.clawed_back {
         match claim_status_account {
             Some(claim_status_account) => {
-                match MerkleDistributorClaimStatus::try_deserialize(
-                    &mut claim_status_account.data.as_slice(),
+                match MerkleDistributorClaimStatus::try_deserialize_any_layout(
+                    &claim_status_account.data,
+                    &distributor,
                 ) {
                     // claimed some, but its expired now, so no more locked funds can be withdrawn
                     Ok(_claim_status) => Ok(ClaimStatus {
@@ -156,6 +192,10 @@ async fn get_claim_status(
                         total_locked: node.total_locked,
                         amount_locked_withdrawable: 0,
                         amount_locked_withdrawn: 0,
+                        amount_locked_staked: 0,
+                        paused: distributor.paused,
+                        revoked: false,
+                        transferred_to: None,
                     }),
                     // account parsing error, assume they didn't claim.
                     // let them know what could have been but it's expired
@@ -167,6 +207,10 @@ async fn get_claim_status(
                             total_locked: node.total_locked,
                             amount_locked_withdrawable: 0,
                             amount_locked_withdrawn: 0,
+                            amount_locked_staked: 0,
+                            paused: distributor.paused,
+                            revoked: false,
+                            transferred_to: None,
                         })
                     }
                 }
@@ -178,13 +222,30 @@ async fn get_claim_status(
                 total_locked: node.total_locked,
                 amount_locked_withdrawable: 0,
                 amount_locked_withdrawn: 0,
+                amount_locked_staked: 0,
+                paused: distributor.paused,
+                revoked: false,
+                transferred_to: None,
             }),
         }
     } else {
         match claim_status_account {
+            // closed after withdrawing everything, only the empty tombstone is left
+            Some(claim_status_account) if claim_status_account.data.is_empty() => Ok(ClaimStatus {
+                status: Status::Claimed,
+                total_unlocked: node.total_unlocked,
+                total_locked: node.total_locked,
+                amount_locked_withdrawable: 0,
+                amount_locked_withdrawn: node.total_locked,
+                amount_locked_staked: 0,
+                paused: distributor.paused,
+                revoked: false,
+                transferred_to: None,
+            }),
             Some(claim_status_account) => {
-                match MerkleDistributorClaimStatus::try_deserialize(
-                    &mut claim_status_account.data.as_slice(),
+                match MerkleDistributorClaimStatus::try_deserialize_any_layout(
+                    &claim_status_account.data,
+                    &distributor,
                 ) {
                     // claimed, but might still have some locked tokens
                     Ok(claim_status) => Ok(ClaimStatus {
@@ -193,15 +254,22 @@ async fn get_claim_status(
                         total_locked: node.total_locked,
                         amount_locked_withdrawable: claim_status
                             .amount_withdrawable(
-                                SystemTime::now()
-                                    .duration_since(UNIX_EPOCH)
-                                    .unwrap()
-                                    .as_secs() as i64,
-                                distributor.start_ts,
-                                distributor.end_ts,
+                                curr_ts,
+                                claim_status.start_ts,
+                                claim_status.end_ts,
+                                distributor
+                                    .claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+                                distributor.cliff_percentage,
+                                &distributor.vesting_mode,
                             )
                             .unwrap(),
                         amount_locked_withdrawn: claim_status.locked_amount_withdrawn,
+                        amount_locked_staked: claim_status.locked_amount_staked,
+                        paused: distributor.paused,
+                        revoked: claim_status.is_revoked(),
+                        transferred_to: claim_status
+                            .is_transferred()
+                            .then_some(claim_status.claimant),
                     }),
                     // error parsing account, assume didn't claim. might have locked tokens too, so simulate the amount withdrawable
                     Err(e) => {
@@ -218,19 +286,35 @@ async fn get_claim_status(
                                 ..MerkleDistributorClaimStatus::default()
                             }
                             .amount_withdrawable(
-                                SystemTime::now()
-                                    .duration_since(UNIX_EPOCH)
-                                    .unwrap()
-                                    .as_secs() as i64,
-                                distributor.start_ts,
-                                distributor.end_ts,
+                                curr_ts,
+                                start_ts,
+                                end_ts,
+                                distributor.claim_cliff_ts(start_ts, end_ts),
+                                distributor.cliff_percentage,
+                                &distributor.vesting_mode,
                             )
                             .unwrap(),
                             amount_locked_withdrawn: 0, /* never withdrew any because account doesn't exist */
+                            amount_locked_staked: 0,
+                            paused: distributor.paused,
+                            revoked: false,
+                            transferred_to: None,
                         })
                     }
                 }
             }
+            // never claimed and can't claim anymore, existing claims keep vesting
+            None if !distributor.claim_window_open(curr_ts) => Ok(ClaimStatus {
+                status: Status::ClaimWindowClosed,
+                total_unlocked: node.total_unlocked,
+                total_locked: node.total_locked,
+                amount_locked_withdrawable: 0,
+                amount_locked_withdrawn: 0,
+                amount_locked_staked: 0,
+                paused: distributor.paused,
+                revoked: false,
+                transferred_to: None,
+            }),
             None => {
                 // haven't claimed yet. might have some claimable tokens
                 Ok(ClaimStatus {
@@ -245,21 +329,85 @@ async fn get_claim_status(
                         ..MerkleDistributorClaimStatus::default()
                     }
                     .amount_withdrawable(
-                        SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .unwrap()
-                            .as_secs() as i64,
-                        distributor.start_ts,
-                        distributor.end_ts,
+                        curr_ts,
+                        start_ts,
+                        end_ts,
+                        distributor.claim_cliff_ts(start_ts, end_ts),
+                        distributor.cliff_percentage,
+                        &distributor.vesting_mode,
                     )
                     .unwrap(),
                     amount_locked_withdrawn: 0, // never withdrew any because account doesn't exist
+                    amount_locked_staked: 0,
+                    paused: distributor.paused,
+                    revoked: false,
+                    transferred_to: None,
                 })
             }
         }
     }
 }
 
+/// Get the claim status of a leaf of a distributor tracking claims in a bitmap,
+/// these leaves have no locked tokens
+async fn get_claim_bitmap_status(
+    state: &RouterState,
+    node: &TreeNode,
+    index: u64,
+    distributor: &MerkleDistributor,
+) -> Result<ClaimStatus> {
+    let (claim_bitmap_pda, _bump) = get_claim_bitmap_pda(
+        &state.program_id,
+        &state.distributor_pubkey,
+        ClaimBitmap::page_of(index),
+    );
+
+    let claimed = match state
+        .rpc_client
+        .get_multiple_accounts(&[claim_bitmap_pda])
+        .await?
+        .pop()
+        .flatten()
+    {
+        Some(claim_bitmap_account) => {
+            ClaimBitmap::try_deserialize(&mut claim_bitmap_account.data.as_slice())
+                .map_err(|e| {
+                    warn!("error deserializing ClaimBitmap: {:?}", e);
+                    ApiError::MerkleDistributorError("Error parsing ClaimBitmap".into())
+                })?
+                .is_claimed(index)
+        }
+        None => false,
+    };
+
+    let curr_ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let status = if distributor.clawed_back {
+        Status::Expired
+    } else if claimed {
+        Status::Claimed
+    } else if !distributor.claim_window_open(curr_ts) {
+        Status::ClaimWindowClosed
+    } else {
+        Status::Unclaimed
+    };
+
+    Ok(ClaimStatus {
+        status,
+        total_unlocked: node.total_unlocked,
+        total_locked: node.total_locked,
+        amount_locked_withdrawable: 0,
+        amount_locked_withdrawn: 0,
+        amount_locked_staked: 0,
+        paused: distributor.paused,
+        revoked: false,
+        transferred_to: None,
+    })
+}
+
 /// Fetch and deserialize merkle distributor information
 pub async fn read_distributor(
     rpc_client: &RpcClient,
@@ -267,7 +415,7 @@ pub async fn read_distributor(
 ) -> Result<MerkleDistributor> {
     return match rpc_client.get_account_data(distributor).await {
         Ok(data) => {
-            return match MerkleDistributor::try_deserialize(&mut data.as_slice()) {
+            return match MerkleDistributor::try_deserialize_any_layout(&data) {
                 Ok(distributor) => Ok(distributor),
                 Err(e) => Err(ApiError::MerkleDistributorError(e.to_string())),
             };
@@ -323,14 +471,87 @@ struct Distributor {
     pub end_ts: i64,
     /// Admin wallet
     pub admin: Pubkey,
+    /// Proposed admin wallet, default if there is no pending proposal
+    pub pending_admin: Pubkey,
     /// Creator wallet
     pub creator: Pubkey,
     /// Whether or not the distributor has been clawed back
     pub clawed_back: bool,
+    /// Lockup cliff (Unix Timestamp)
+    pub cliff_ts: i64,
+    /// Percentage of the locked amount unlocked at the cliff
+    pub cliff_percentage: u8,
+    /// Whether or not claims are paused by the admin
+    pub paused: bool,
+    /// Clawback start (Unix Timestamp)
+    pub clawback_start_ts: i64,
+    /// Token account the unclaimed tokens are clawed back to
+    pub clawback_receiver: Pubkey,
+    /// Whether claims are tracked in a bitmap instead of claim status accounts
+    pub claim_bitmap: bool,
+    /// Claim window end (Unix Timestamp)
+    pub claim_end_ts: i64,
+    /// Locked tokens of existing claims that have not been withdrawn yet
+    pub total_locked_outstanding: u64,
+    /// Whether the unclaimed allocation has been clawed back
+    pub unclaimed_clawed_back: bool,
+    /// Whether the admin can revoke the unvested locked tokens of a claim
+    pub revocable: bool,
+    /// Total amount of tokens funded through fund_distributor
+    pub total_funded: u64,
+    /// Whether the vault was funded, new claims are rejected until then
+    pub funded: bool,
+    /// Amount of tokens the vault is missing to cover all unclaimed tokens
+    pub funding_shortfall: u64,
+    /// Whether the distributor pays out native SOL, amounts are then in lamports, the token
+    /// vault is the SOL vault PDA and the clawback receiver a wallet
+    pub native_sol: bool,
+    /// Mints paid out alongside the distributor's mint
+    pub extra_mints: Vec<ExtraMint>,
+    /// Staking program claimed tokens can be staked into, default if staking is disabled
+    pub staking_program: Pubkey,
+    /// Stake pool of the staking program
+    pub stake_pool: Pubkey,
+    /// Whether anyone can claim to a claimant's associated token account without their signature
+    pub permissionless_claims: bool,
+    /// Layout of the distributor account, 0 until migrated to the current layout
+    pub layout_version: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ExtraMint {
+    /// [Mint] of the token to be distributed.
+    pub mint: Pubkey,
+    /// Token Address of the vault
+    pub token_vault: Pubkey,
+    /// Maximum number of tokens of this mint that can ever be claimed.
+    pub max_total_claim: u64,
+    /// Total amount of tokens of this mint that have been claimed.
+    pub total_amount_claimed: u64,
 }
 
 async fn get_distributor(State(state): State<Arc<RouterState>>) -> Result<Json<Distributor>> {
     let d = read_distributor(&state.rpc_client, &state.distributor_pubkey).await?;
+    let vault_amount = match d.native_sol {
+        // the SOL vault keeps its rent-exempt reserve out of the distribution
+        true => state
+            .rpc_client
+            .get_balance(&d.token_vault)
+            .await?
+            .saturating_sub(
+                state
+                    .rpc_client
+                    .get_minimum_balance_for_rent_exemption(0)
+                    .await?,
+            ),
+        false => state
+            .rpc_client
+            .get_token_account_balance(&d.token_vault)
+            .await?
+            .amount
+            .parse()
+            .map_err(|_| ApiError::InternalError)?,
+    };
     Ok(Json(Distributor {
         pubkey: state.distributor_pubkey,
         program_id: state.program_id,
@@ -346,7 +567,36 @@ async fn get_distributor(State(state): State<Arc<RouterState>>) -> Result<Json<D
         end_ts: d.end_ts,
         creator: d.creator,
         admin: d.admin,
+        pending_admin: d.pending_admin,
         clawed_back: d.clawed_back,
+        cliff_ts: d.cliff_ts,
+        cliff_percentage: d.cliff_percentage,
+        paused: d.paused,
+        clawback_start_ts: d.clawback_start_ts,
+        clawback_receiver: d.clawback_receiver,
+        claim_bitmap: d.claim_bitmap,
+        claim_end_ts: d.claim_end_ts,
+        total_locked_outstanding: d.total_locked_outstanding,
+        unclaimed_clawed_back: d.unclaimed_clawed_back,
+        revocable: d.revocable,
+        total_funded: d.total_funded,
+        funded: d.funded,
+        funding_shortfall: d.funding_shortfall(vault_amount),
+        native_sol: d.native_sol,
+        extra_mints: d
+            .extra_mints()
+            .iter()
+            .map(|extra_mint| ExtraMint {
+                mint: extra_mint.mint,
+                token_vault: extra_mint.token_vault,
+                max_total_claim: extra_mint.max_total_claim,
+                total_amount_claimed: extra_mint.total_amount_claimed,
+            })
+            .collect(),
+        staking_program: d.staking_program,
+        stake_pool: d.stake_pool,
+        permissionless_claims: d.permissionless_claims,
+        layout_version: d.layout_version,
     }))
 }
 
@@ -359,11 +609,131 @@ async fn get_status(
     let merkle_tree = &state.tree;
 
     let user_pubkey: Pubkey = Pubkey::from_str(user_pubkey.as_str())?;
-    let node = merkle_tree
+    if let Some(node) = merkle_tree.get(&user_pubkey) {
+        return Ok(Json(get_claim_status(&state, node, &user_pubkey).await?));
+    }
+
+    // not a leaf claimant, but a claim might have been transferred to the user
+    let leaf_claimant = state
+        .transferred_claims
+        .read()
+        .unwrap()
         .get(&user_pubkey)
+        .copied()
+        .ok_or(ApiError::UserNotFound(user_pubkey.to_string()))?;
+    let node = merkle_tree
+        .get(&leaf_claimant)
         .ok_or(ApiError::UserNotFound(user_pubkey.to_string()))?;
 
-    Ok(Json(get_claim_status(&state, node, &user_pubkey).await?))
+    Ok(Json(get_claim_status(&state, node, &leaf_claimant).await?))
+}
+
+/// Loads the claims of this distributor that were transferred away from their leaf claimant,
+/// keyed by the current claimant
+pub async fn load_transferred_claims(
+    rpc_client: &RpcClient,
+    program_id: &Pubkey,
+    distributor_pubkey: &Pubkey,
+    tree: &HashMap<Pubkey, TreeNode>,
+) -> Result<HashMap<Pubkey, Pubkey>> {
+    let accounts = rpc_client
+        .get_program_accounts_with_config(
+            program_id,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::DataSize(
+                    MerkleDistributorClaimStatus::LEN as u64,
+                )]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcProgramAccountsConfig::default()
+            },
+        )
+        .await?;
+
+    let mut transferred_claims = HashMap::new();
+    for (pubkey, account) in accounts {
+        let Ok(claim_status) =
+            MerkleDistributorClaimStatus::try_deserialize(&mut account.data.as_slice())
+        else {
+            continue;
+        };
+        if !claim_status.is_transferred() || !tree.contains_key(&claim_status.leaf_claimant) {
+            continue;
+        }
+        // claim statuses of other distributors of the program match the filters too
+        let (claim_status_pda, _bump) =
+            get_claim_status_pda(program_id, &claim_status.leaf_claimant, distributor_pubkey);
+        if pubkey == claim_status_pda {
+            transferred_claims.insert(claim_status.claimant, claim_status.leaf_claimant);
+        }
+    }
+
+    Ok(transferred_claims)
+}
+
+/// Periodically reloads the transferred claims, keeping the previous ones if a reload fails
+pub async fn refresh_transferred_claims(state: Arc<RouterState>, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+    // the first tick completes immediately, and the claims were loaded at startup
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        match load_transferred_claims(
+            &state.rpc_client,
+            &state.program_id,
+            &state.distributor_pubkey,
+            &state.tree,
+        )
+        .await
+        {
+            Ok(transferred_claims) => {
+                info!("loaded {} transferred claims", transferred_claims.len());
+                *state.transferred_claims.write().unwrap() = transferred_claims;
+            }
+            Err(e) => warn!("failed to reload transferred claims: {}", e),
+        }
+    }
+}
+
+/// Get the SOL custody balances of an owner, zero if nothing was ever deposited
+#[instrument(ret)]
+async fn get_custody(
+    State(state): State<Arc<RouterState>>,
+    Path(owner_pubkey): Path<String>,
+) -> Result<impl IntoResponse> {
+    let owner: Pubkey = Pubkey::from_str(owner_pubkey.as_str())?;
+    let (sol_custody_pda, _bump) = get_sol_custody_pda(&state.program_id, &owner);
+    let (vault, _bump) = get_custody_vault_pda(&state.program_id, &owner);
+
+    let vault_lamports = state.rpc_client.get_balance(&vault).await?;
+    let sol_custody = match state
+        .rpc_client
+        .get_account_with_commitment(&sol_custody_pda, state.rpc_client.commitment())
+        .await?
+        .value
+    {
+        Some(account) => SolCustody::try_deserialize(&mut account.data.as_slice())
+            .map_err(|e| ApiError::MerkleDistributorError(e.to_string()))?,
+        None => SolCustody::default(),
+    };
+    let curr_ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    Ok(Json(Custody {
+        owner,
+        vault,
+        // lamports sent to the vault directly are only tracked from the next deposit or withdrawal
+        balance: vault_lamports,
+        vault_lamports,
+        total_deposited: sol_custody.total_deposited,
+        total_withdrawn: sol_custody.total_withdrawn,
+        lock_until_ts: sol_custody.lock_until_ts,
+        locked: sol_custody.is_locked(curr_ts),
+    }))
 }
 
 /// Gets the current airdrop version
@@ -382,16 +752,39 @@ async fn root() -> impl IntoResponse {
 
 #[derive(Serialize, Deserialize, Debug)]
 struct Proof {
+    pub index: Option<u64>,
     pub amount_locked: u64,
     pub amount_unlocked: u64,
+    pub start_ts: Option<i64>,
+    pub end_ts: Option<i64>,
     pub proof: Vec<[u8; 32]>,
+    /// Amounts of the distributor's extra mints, in the order they were added
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_amounts: Vec<ExtraAmount>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Custody {
+    pub owner: Pubkey,
+    /// Custody vault PDA holding the lamports
+    pub vault: Pubkey,
+    /// Lamports held in custody for the owner
+    pub balance: u64,
+    /// Lamports in the vault, including rent
+    pub vault_lamports: u64,
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    /// Withdrawals are locked until this timestamp, 0 if never locked
+    pub lock_until_ts: i64,
+    pub locked: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 enum Status {
-    Unclaimed, // User has not yet claimed any tokens
-    Claimed,   // User already claimed unlocked tokens
-    Expired,   // claim period has expired
+    Unclaimed,         // User has not yet claimed any tokens
+    Claimed,           // User already claimed unlocked tokens
+    Expired,           // claim period has expired
+    ClaimWindowClosed, // never claimed and the claim window has ended or was clawed back
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -401,6 +794,14 @@ struct ClaimStatus {
     pub total_locked: u64,
     pub amount_locked_withdrawn: u64,
     pub amount_locked_withdrawable: u64,
+    /// Locked tokens staked through the distributor, they have to be unstaked before withdrawing
+    pub amount_locked_staked: u64,
+    /// Claims and withdrawals are paused by the admin
+    pub paused: bool,
+    /// The unvested locked tokens were revoked by the admin
+    pub revoked: bool,
+    /// Wallet the claim was transferred to, if it was
+    pub transferred_to: Option<Pubkey>,
 }
 
 #[cfg(test)]
@@ -417,6 +818,10 @@ mod tests {
             total_locked: 100,
             amount_locked_withdrawable: 100,
             amount_locked_withdrawn: 0,
+            amount_locked_staked: 0,
+            paused: false,
+            revoked: false,
+            transferred_to: None,
         };
 
         let json = serde_json::to_string(&claim_status).unwrap();
@@ -0,0 +1,92 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class ClaimBitmapJSON(typing.TypedDict):
+    distributor: str
+    page: int
+    bits: list[int]
+
+
+@dataclass
+class ClaimBitmap:
+    discriminator: typing.ClassVar = b"=\x19\x94\xc4\xa4\xd0A\xa9"
+    layout: typing.ClassVar = borsh.CStruct(
+        "distributor" / BorshPubkey, "page" / borsh.U32, "bits" / borsh.U8[1024]
+    )
+    distributor: Pubkey
+    page: int
+    bits: list[int]
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["ClaimBitmap"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["ClaimBitmap"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["ClaimBitmap"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "ClaimBitmap":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = ClaimBitmap.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            distributor=dec.distributor,
+            page=dec.page,
+            bits=dec.bits,
+        )
+
+    def to_json(self) -> ClaimBitmapJSON:
+        return {
+            "distributor": str(self.distributor),
+            "page": self.page,
+            "bits": self.bits,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ClaimBitmapJSON) -> "ClaimBitmap":
+        return cls(
+            distributor=Pubkey.from_string(obj["distributor"]),
+            page=obj["page"],
+            bits=obj["bits"],
+        )
@@ -9,6 +9,7 @@ from anchorpy.error import AccountInvalidDiscriminator
 from anchorpy.utils.rpc import get_multiple_accounts
 from anchorpy.borsh_extension import BorshPubkey
 from ..program_id import PROGRAM_ID
+from .. import types
 
 
 class MerkleDistributorJSON(typing.TypedDict):
@@ -26,6 +27,31 @@ class MerkleDistributorJSON(typing.TypedDict):
     creator: str
     admin: str
     clawed_back: bool
+    cliff_ts: int
+    cliff_percentage: int
+    vesting_mode: types.vesting_mode.VestingModeJSON
+    paused: bool
+    clawback_start_ts: int
+    clawback_receiver: str
+    pending_admin: str
+    claim_bitmap: bool
+    total_funded: int
+    funded: bool
+    claim_end_ts: int
+    total_locked_outstanding: int
+    amount_clawed_back: int
+    unclaimed_clawed_back: bool
+    revocable: bool
+    recoverable: bool
+    native_sol: bool
+    extra_mints: list[types.extra_mint.ExtraMintJSON]
+    num_extra_mints: int
+    staking_program: str
+    stake_pool: str
+    total_locked_staked: int
+    permissionless_claims: bool
+    layout_version: int
+    reserved: list[int]
 
 
 @dataclass
@@ -46,6 +72,31 @@ class MerkleDistributor:
         "creator" / BorshPubkey,
         "admin" / BorshPubkey,
         "clawed_back" / borsh.Bool,
+        "cliff_ts" / borsh.I64,
+        "cliff_percentage" / borsh.U8,
+        "vesting_mode" / types.vesting_mode.layout,
+        "paused" / borsh.Bool,
+        "clawback_start_ts" / borsh.I64,
+        "clawback_receiver" / BorshPubkey,
+        "pending_admin" / BorshPubkey,
+        "claim_bitmap" / borsh.Bool,
+        "total_funded" / borsh.U64,
+        "funded" / borsh.Bool,
+        "claim_end_ts" / borsh.I64,
+        "total_locked_outstanding" / borsh.U64,
+        "amount_clawed_back" / borsh.U64,
+        "unclaimed_clawed_back" / borsh.Bool,
+        "revocable" / borsh.Bool,
+        "recoverable" / borsh.Bool,
+        "native_sol" / borsh.Bool,
+        "extra_mints" / types.extra_mint.ExtraMint.layout[2],
+        "num_extra_mints" / borsh.U8,
+        "staking_program" / BorshPubkey,
+        "stake_pool" / BorshPubkey,
+        "total_locked_staked" / borsh.U64,
+        "permissionless_claims" / borsh.Bool,
+        "layout_version" / borsh.U8,
+        "reserved" / borsh.U64[16],
     )
     bump: int
     version: int
@@ -61,6 +112,31 @@ class MerkleDistributor:
     creator: Pubkey
     admin: Pubkey
     clawed_back: bool
+    cliff_ts: int
+    cliff_percentage: int
+    vesting_mode: types.vesting_mode.VestingModeKind
+    paused: bool
+    clawback_start_ts: int
+    clawback_receiver: Pubkey
+    pending_admin: Pubkey
+    claim_bitmap: bool
+    total_funded: int
+    funded: bool
+    claim_end_ts: int
+    total_locked_outstanding: int
+    amount_clawed_back: int
+    unclaimed_clawed_back: bool
+    revocable: bool
+    recoverable: bool
+    native_sol: bool
+    extra_mints: list[types.extra_mint.ExtraMint]
+    num_extra_mints: int
+    staking_program: Pubkey
+    stake_pool: Pubkey
+    total_locked_staked: int
+    permissionless_claims: bool
+    layout_version: int
+    reserved: list[int]
 
     @classmethod
     async def fetch(
@@ -120,6 +196,36 @@ class MerkleDistributor:
             creator=dec.creator,
             admin=dec.admin,
             clawed_back=dec.clawed_back,
+            cliff_ts=dec.cliff_ts,
+            cliff_percentage=dec.cliff_percentage,
+            vesting_mode=types.vesting_mode.from_decoded(dec.vesting_mode),
+            paused=dec.paused,
+            clawback_start_ts=dec.clawback_start_ts,
+            clawback_receiver=dec.clawback_receiver,
+            pending_admin=dec.pending_admin,
+            claim_bitmap=dec.claim_bitmap,
+            total_funded=dec.total_funded,
+            funded=dec.funded,
+            claim_end_ts=dec.claim_end_ts,
+            total_locked_outstanding=dec.total_locked_outstanding,
+            amount_clawed_back=dec.amount_clawed_back,
+            unclaimed_clawed_back=dec.unclaimed_clawed_back,
+            revocable=dec.revocable,
+            recoverable=dec.recoverable,
+            native_sol=dec.native_sol,
+            extra_mints=list(
+                map(
+                    lambda item: types.extra_mint.ExtraMint.from_decoded(item),
+                    dec.extra_mints,
+                )
+            ),
+            num_extra_mints=dec.num_extra_mints,
+            staking_program=dec.staking_program,
+            stake_pool=dec.stake_pool,
+            total_locked_staked=dec.total_locked_staked,
+            permissionless_claims=dec.permissionless_claims,
+            layout_version=dec.layout_version,
+            reserved=dec.reserved,
         )
 
     def to_json(self) -> MerkleDistributorJSON:
@@ -138,6 +244,31 @@ class MerkleDistributor:
             "creator": str(self.creator),
             "admin": str(self.admin),
             "clawed_back": self.clawed_back,
+            "cliff_ts": self.cliff_ts,
+            "cliff_percentage": self.cliff_percentage,
+            "vesting_mode": self.vesting_mode.to_json(),
+            "paused": self.paused,
+            "clawback_start_ts": self.clawback_start_ts,
+            "clawback_receiver": str(self.clawback_receiver),
+            "pending_admin": str(self.pending_admin),
+            "claim_bitmap": self.claim_bitmap,
+            "total_funded": self.total_funded,
+            "funded": self.funded,
+            "claim_end_ts": self.claim_end_ts,
+            "total_locked_outstanding": self.total_locked_outstanding,
+            "amount_clawed_back": self.amount_clawed_back,
+            "unclaimed_clawed_back": self.unclaimed_clawed_back,
+            "revocable": self.revocable,
+            "recoverable": self.recoverable,
+            "native_sol": self.native_sol,
+            "extra_mints": list(map(lambda item: item.to_json(), self.extra_mints)),
+            "num_extra_mints": self.num_extra_mints,
+            "staking_program": str(self.staking_program),
+            "stake_pool": str(self.stake_pool),
+            "total_locked_staked": self.total_locked_staked,
+            "permissionless_claims": self.permissionless_claims,
+            "layout_version": self.layout_version,
+            "reserved": self.reserved,
         }
 
     @classmethod
@@ -157,4 +288,34 @@ class MerkleDistributor:
             creator=Pubkey.from_string(obj["creator"]),
             admin=Pubkey.from_string(obj["admin"]),
             clawed_back=obj["clawed_back"],
+            cliff_ts=obj["cliff_ts"],
+            cliff_percentage=obj["cliff_percentage"],
+            vesting_mode=types.vesting_mode.from_json(obj["vesting_mode"]),
+            paused=obj["paused"],
+            clawback_start_ts=obj["clawback_start_ts"],
+            clawback_receiver=Pubkey.from_string(obj["clawback_receiver"]),
+            pending_admin=Pubkey.from_string(obj["pending_admin"]),
+            claim_bitmap=obj["claim_bitmap"],
+            total_funded=obj["total_funded"],
+            funded=obj["funded"],
+            claim_end_ts=obj["claim_end_ts"],
+            total_locked_outstanding=obj["total_locked_outstanding"],
+            amount_clawed_back=obj["amount_clawed_back"],
+            unclaimed_clawed_back=obj["unclaimed_clawed_back"],
+            revocable=obj["revocable"],
+            recoverable=obj["recoverable"],
+            native_sol=obj["native_sol"],
+            extra_mints=list(
+                map(
+                    lambda item: types.extra_mint.ExtraMint.from_json(item),
+                    obj["extra_mints"],
+                )
+            ),
+            num_extra_mints=obj["num_extra_mints"],
+            staking_program=Pubkey.from_string(obj["staking_program"]),
+            stake_pool=Pubkey.from_string(obj["stake_pool"]),
+            total_locked_staked=obj["total_locked_staked"],
+            permissionless_claims=obj["permissionless_claims"],
+            layout_version=obj["layout_version"],
+            reserved=obj["reserved"],
         )
@@ -119,6 +119,436 @@ class InsufficientFunds(ProgramError):
     msg = "Insufficient funds"
 
 
+class CliffOutsideVestingPeriod(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6013, "Cliff timestamp must be between start and end timestamps"
+        )
+
+    code = 6013
+    name = "CliffOutsideVestingPeriod"
+    msg = "Cliff timestamp must be between start and end timestamps"
+
+
+class InvalidCliffPercentage(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6014, "Cliff percentage cannot exceed 100")
+
+    code = 6014
+    name = "InvalidCliffPercentage"
+    msg = "Cliff percentage cannot exceed 100"
+
+
+class PartialVestingSchedule(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6015, "Leaf vesting schedule requires both start and end timestamps"
+        )
+
+    code = 6015
+    name = "PartialVestingSchedule"
+    msg = "Leaf vesting schedule requires both start and end timestamps"
+
+
+class InvalidVestingMode(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6016,
+            "Vesting period must be positive and milestones must be ordered and end at 100%",
+        )
+
+    code = 6016
+    name = "InvalidVestingMode"
+    msg = "Vesting period must be positive and milestones must be ordered and end at 100%"
+
+
+class DistributorPaused(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6017, "Claims are paused")
+
+    code = 6017
+    name = "DistributorPaused"
+    msg = "Claims are paused"
+
+
+class InsufficientClawbackDelay(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6018, "Clawback start must be at least one day after end timestamp"
+        )
+
+    code = 6018
+    name = "InsufficientClawbackDelay"
+    msg = "Clawback start must be at least one day after end timestamp"
+
+
+class ClawbackBeforeStart(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6019, "Clawback is not allowed before the clawback start timestamp"
+        )
+
+    code = 6019
+    name = "ClawbackBeforeStart"
+    msg = "Clawback is not allowed before the clawback start timestamp"
+
+
+class SameClawbackReceiver(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6020, "New and old clawback receivers are identical")
+
+    code = 6020
+    name = "SameClawbackReceiver"
+    msg = "New and old clawback receivers are identical"
+
+
+class InvalidClawbackReceiver(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6021, "Clawback receiver does not match the distributor's clawback receiver"
+        )
+
+    code = 6021
+    name = "InvalidClawbackReceiver"
+    msg = "Clawback receiver does not match the distributor's clawback receiver"
+
+
+class NoPendingAdmin(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6022, "No admin has been proposed")
+
+    code = 6022
+    name = "NoPendingAdmin"
+    msg = "No admin has been proposed"
+
+
+class ClaimantSignatureRequired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6023,
+            "Claimant must sign unless permissionless claims go to their associated token account",
+        )
+
+    code = 6023
+    name = "ClaimantSignatureRequired"
+    msg = "Claimant must sign unless permissionless claims go to their associated token account"
+
+
+class ClaimNotFullyWithdrawn(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6024, "Claim still has locked tokens to withdraw")
+
+    code = 6024
+    name = "ClaimNotFullyWithdrawn"
+    msg = "Claim still has locked tokens to withdraw"
+
+
+class InvalidClaimMode(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6025, "Claim does not match the distributor's claim tracking mode"
+        )
+
+    code = 6025
+    name = "InvalidClaimMode"
+    msg = "Claim does not match the distributor's claim tracking mode"
+
+
+class InvalidClaimBitmap(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6026, "Claim bitmap page does not cover the leaf index")
+
+    code = 6026
+    name = "InvalidClaimBitmap"
+    msg = "Claim bitmap page does not cover the leaf index"
+
+
+class AlreadyClaimed(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6027, "Leaf has already been claimed")
+
+    code = 6027
+    name = "AlreadyClaimed"
+    msg = "Leaf has already been claimed"
+
+
+class DistributorShrinks(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6028,
+            "New max total claim and max num nodes cannot be less than the current ones",
+        )
+
+    code = 6028
+    name = "DistributorShrinks"
+    msg = "New max total claim and max num nodes cannot be less than the current ones"
+
+
+class DistributorNotFunded(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6029, "Distributor vault does not cover the unclaimed tokens")
+
+    code = 6029
+    name = "DistributorNotFunded"
+    msg = "Distributor vault does not cover the unclaimed tokens"
+
+
+class NotRevocable(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6030, "Distributor claims are not revocable")
+
+    code = 6030
+    name = "NotRevocable"
+    msg = "Distributor claims are not revocable"
+
+
+class ClaimRevoked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6031, "Claim has already been revoked")
+
+    code = 6031
+    name = "ClaimRevoked"
+    msg = "Claim has already been revoked"
+
+
+class ClaimTransferred(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6032, "Claim has been transferred to another claimant")
+
+    code = 6032
+    name = "ClaimTransferred"
+    msg = "Claim has been transferred to another claimant"
+
+
+class SameClaimant(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6033, "New claimant must differ from the current claimant")
+
+    code = 6033
+    name = "SameClaimant"
+    msg = "New claimant must differ from the current claimant"
+
+
+class NotRecoverable(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6034, "Distributor claims are not recoverable by the admin")
+
+    code = 6034
+    name = "NotRecoverable"
+    msg = "Distributor claims are not recoverable by the admin"
+
+
+class DistributorKindMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6035,
+            "Instruction does not match whether the distributor pays out native SOL or tokens",
+        )
+
+    code = 6035
+    name = "DistributorKindMismatch"
+    msg = "Instruction does not match whether the distributor pays out native SOL or tokens"
+
+
+class TooManyExtraMints(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6036, "Distributor already distributes the maximum number of extra mints"
+        )
+
+    code = 6036
+    name = "TooManyExtraMints"
+    msg = "Distributor already distributes the maximum number of extra mints"
+
+
+class ExtraMintsUnsupported(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6037, "Extra mints are not supported by the distributor or instruction"
+        )
+
+    code = 6037
+    name = "ExtraMintsUnsupported"
+    msg = "Extra mints are not supported by the distributor or instruction"
+
+
+class DuplicateMint(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6038, "Mint is already distributed by the distributor")
+
+    code = 6038
+    name = "DuplicateMint"
+    msg = "Mint is already distributed by the distributor"
+
+
+class InvalidExtraMintAccounts(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6039, "Remaining accounts do not match the distributor's extra mints"
+        )
+
+    code = 6039
+    name = "InvalidExtraMintAccounts"
+    msg = "Remaining accounts do not match the distributor's extra mints"
+
+
+class StakingDisabled(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6040, "Distributor has no staking program")
+
+    code = 6040
+    name = "StakingDisabled"
+    msg = "Distributor has no staking program"
+
+
+class InvalidStakingProgram(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6041, "Staking program or stake pool does not match the distributor's"
+        )
+
+    code = 6041
+    name = "InvalidStakingProgram"
+    msg = "Staking program or stake pool does not match the distributor's"
+
+
+class InsufficientLockedTokens(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6042, "Claim does not have enough idle locked tokens")
+
+    code = 6042
+    name = "InsufficientLockedTokens"
+    msg = "Claim does not have enough idle locked tokens"
+
+
+class InsufficientStakedTokens(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6043, "Claim does not have enough staked locked tokens")
+
+    code = 6043
+    name = "InsufficientStakedTokens"
+    msg = "Claim does not have enough staked locked tokens"
+
+
+class LockedTokensStaked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6044, "Locked tokens are staked, they have to be unstaked first"
+        )
+
+    code = 6044
+    name = "LockedTokensStaked"
+    msg = "Locked tokens are staked, they have to be unstaked first"
+
+
+class CustodyLocked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6045, "SOL custody is locked")
+
+    code = 6045
+    name = "CustodyLocked"
+    msg = "SOL custody is locked"
+
+
+class CustodyLockShortened(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6046, "SOL custody lock can only be extended")
+
+    code = 6046
+    name = "CustodyLockShortened"
+    msg = "SOL custody lock can only be extended"
+
+
+class DistributorAlreadyMigrated(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6047, "Distributor already has the current layout")
+
+    code = 6047
+    name = "DistributorAlreadyMigrated"
+    msg = "Distributor already has the current layout"
+
+
+class SolTransferBelowRentExempt(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6048, "SOL transfer would leave the recipient below the rent-exempt minimum"
+        )
+
+    code = 6048
+    name = "SolTransferBelowRentExempt"
+    msg = "SOL transfer would leave the recipient below the rent-exempt minimum"
+
+
+class StakingProgramAlreadySet(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6049, "Distributor already has a staking program, it can't be changed"
+        )
+
+    code = 6049
+    name = "StakingProgramAlreadySet"
+    msg = "Distributor already has a staking program, it can't be changed"
+
+
+class InvalidStakeTransfer(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6050, "Staking program did not move exactly the staked amount")
+
+    code = 6050
+    name = "InvalidStakeTransfer"
+    msg = "Staking program did not move exactly the staked amount"
+
+
+class ClaimStatusAlreadyMigrated(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6051, "Claim status already has the current layout")
+
+    code = 6051
+    name = "ClaimStatusAlreadyMigrated"
+    msg = "Claim status already has the current layout"
+
+
+class InvalidFundingTransfer(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6052, "Vault did not receive the funded amount net of the transfer fee"
+        )
+
+    code = 6052
+    name = "InvalidFundingTransfer"
+    msg = "Vault did not receive the funded amount net of the transfer fee"
+
+
+class DistributorFunded(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6053, "Distributor is already funded")
+
+    code = 6053
+    name = "DistributorFunded"
+    msg = "Distributor is already funded"
+
+
+class RootUnchanged(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6054, "New root is the distributor's current root")
+
+    code = 6054
+    name = "RootUnchanged"
+    msg = "New root is the distributor's current root"
+
+
+class StakingFeeNotSupported(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(
+            6055,
+            "Stake pool charges a staking fee, only fee-free stake pools are supported",
+        )
+
+    code = 6055
+    name = "StakingFeeNotSupported"
+    msg = "Stake pool charges a staking fee, only fee-free stake pools are supported"
+
+
 CustomError = typing.Union[
     InsufficientUnlockedTokens,
     InvalidProof,
@@ -133,6 +563,49 @@ CustomError = typing.Union[
     StartTimestampAfterEnd,
     TimestampsNotInFuture,
     InsufficientFunds,
+    CliffOutsideVestingPeriod,
+    InvalidCliffPercentage,
+    PartialVestingSchedule,
+    InvalidVestingMode,
+    DistributorPaused,
+    InsufficientClawbackDelay,
+    ClawbackBeforeStart,
+    SameClawbackReceiver,
+    InvalidClawbackReceiver,
+    NoPendingAdmin,
+    ClaimantSignatureRequired,
+    ClaimNotFullyWithdrawn,
+    InvalidClaimMode,
+    InvalidClaimBitmap,
+    AlreadyClaimed,
+    DistributorShrinks,
+    DistributorNotFunded,
+    NotRevocable,
+    ClaimRevoked,
+    ClaimTransferred,
+    SameClaimant,
+    NotRecoverable,
+    DistributorKindMismatch,
+    TooManyExtraMints,
+    ExtraMintsUnsupported,
+    DuplicateMint,
+    InvalidExtraMintAccounts,
+    StakingDisabled,
+    InvalidStakingProgram,
+    InsufficientLockedTokens,
+    InsufficientStakedTokens,
+    LockedTokensStaked,
+    CustodyLocked,
+    CustodyLockShortened,
+    DistributorAlreadyMigrated,
+    SolTransferBelowRentExempt,
+    StakingProgramAlreadySet,
+    InvalidStakeTransfer,
+    ClaimStatusAlreadyMigrated,
+    InvalidFundingTransfer,
+    DistributorFunded,
+    RootUnchanged,
+    StakingFeeNotSupported,
 ]
 CUSTOM_ERROR_MAP: dict[int, CustomError] = {
     6000: InsufficientUnlockedTokens(),
@@ -148,6 +621,49 @@ CUSTOM_ERROR_MAP: dict[int, CustomError] = {
     6010: StartTimestampAfterEnd(),
     6011: TimestampsNotInFuture(),
     6012: InsufficientFunds(),
+    6013: CliffOutsideVestingPeriod(),
+    6014: InvalidCliffPercentage(),
+    6015: PartialVestingSchedule(),
+    6016: InvalidVestingMode(),
+    6017: DistributorPaused(),
+    6018: InsufficientClawbackDelay(),
+    6019: ClawbackBeforeStart(),
+    6020: SameClawbackReceiver(),
+    6021: InvalidClawbackReceiver(),
+    6022: NoPendingAdmin(),
+    6023: ClaimantSignatureRequired(),
+    6024: ClaimNotFullyWithdrawn(),
+    6025: InvalidClaimMode(),
+    6026: InvalidClaimBitmap(),
+    6027: AlreadyClaimed(),
+    6028: DistributorShrinks(),
+    6029: DistributorNotFunded(),
+    6030: NotRevocable(),
+    6031: ClaimRevoked(),
+    6032: ClaimTransferred(),
+    6033: SameClaimant(),
+    6034: NotRecoverable(),
+    6035: DistributorKindMismatch(),
+    6036: TooManyExtraMints(),
+    6037: ExtraMintsUnsupported(),
+    6038: DuplicateMint(),
+    6039: InvalidExtraMintAccounts(),
+    6040: StakingDisabled(),
+    6041: InvalidStakingProgram(),
+    6042: InsufficientLockedTokens(),
+    6043: InsufficientStakedTokens(),
+    6044: LockedTokensStaked(),
+    6045: CustodyLocked(),
+    6046: CustodyLockShortened(),
+    6047: DistributorAlreadyMigrated(),
+    6048: SolTransferBelowRentExempt(),
+    6049: StakingProgramAlreadySet(),
+    6050: InvalidStakeTransfer(),
+    6051: ClaimStatusAlreadyMigrated(),
+    6052: InvalidFundingTransfer(),
+    6053: DistributorFunded(),
+    6054: RootUnchanged(),
+    6055: StakingFeeNotSupported(),
 }
 
 
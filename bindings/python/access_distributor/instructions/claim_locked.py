@@ -1,7 +1,6 @@
 from __future__ import annotations
 import typing
 from solders.pubkey import Pubkey
-from spl.token.constants import TOKEN_PROGRAM_ID
 from solders.instruction import Instruction, AccountMeta
 from ..program_id import PROGRAM_ID
 
@@ -12,20 +11,28 @@ class ClaimLockedAccounts(typing.TypedDict):
     from_: Pubkey
     to: Pubkey
     claimant: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
 
 
 def claim_locked(
     accounts: ClaimLockedAccounts,
     program_id: Pubkey = PROGRAM_ID,
     remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+    claimant_signer: bool = True,
 ) -> Instruction:
     keys: list[AccountMeta] = [
         AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["from_"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["to"], is_signer=False, is_writable=True),
-        AccountMeta(pubkey=accounts["claimant"], is_signer=True, is_writable=True),
-        AccountMeta(pubkey=TOKEN_PROGRAM_ID, is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["claimant"], is_signer=claimant_signer, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
     ]
     if remaining_accounts is not None:
         keys += remaining_accounts
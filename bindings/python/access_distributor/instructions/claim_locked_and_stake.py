@@ -0,0 +1,76 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class ClaimNested(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    from_: Pubkey
+    to: Pubkey
+    claimant: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
+
+
+class ClaimLockedAndStakeAccounts(typing.TypedDict):
+    claim: ClaimNested
+    central_state: Pubkey
+    stake_pool: Pubkey
+    stake_account: Pubkey
+    stake_vault: Pubkey
+    fee_account: Pubkey
+    staking_program: Pubkey
+
+
+def claim_locked_and_stake(
+    accounts: ClaimLockedAndStakeAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+    claimant_signer: bool = True,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["claim"]["distributor"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["claim_status"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["from_"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["claim"]["to"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["claim"]["claimant"],
+            is_signer=claimant_signer,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["mint"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["token_program"],
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["central_state"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["stake_pool"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["stake_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["stake_vault"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["fee_account"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["staking_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xad\xd0Q\x08\r\x13\xca\x96"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
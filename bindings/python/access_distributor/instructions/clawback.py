@@ -2,7 +2,6 @@ from __future__ import annotations
 import typing
 from solders.pubkey import Pubkey
 from solders.system_program import ID as SYS_PROGRAM_ID
-from spl.token.constants import TOKEN_PROGRAM_ID
 from solders.instruction import Instruction, AccountMeta
 from ..program_id import PROGRAM_ID
 
@@ -12,6 +11,8 @@ class ClawbackAccounts(typing.TypedDict):
     from_: Pubkey
     to: Pubkey
     admin: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
 
 
 def clawback(
@@ -25,7 +26,10 @@ def clawback(
         AccountMeta(pubkey=accounts["to"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=True),
         AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
-        AccountMeta(pubkey=TOKEN_PROGRAM_ID, is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
     ]
     if remaining_accounts is not None:
         keys += remaining_accounts
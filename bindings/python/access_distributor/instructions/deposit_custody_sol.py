@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class DepositCustodySolArgs(typing.TypedDict):
+    amount_lamports: int
+    lock_until_ts: typing.Optional[int]
+
+
+layout = borsh.CStruct(
+    "amount_lamports" / borsh.U64, "lock_until_ts" / borsh.Option(borsh.I64)
+)
+
+
+class DepositCustodySolAccounts(typing.TypedDict):
+    sol_custody: Pubkey
+    custody_vault: Pubkey
+    owner: Pubkey
+
+
+def deposit_custody_sol(
+    args: DepositCustodySolArgs,
+    accounts: DepositCustodySolAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["sol_custody"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["custody_vault"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x1c\xa5\xb3}]\x91\xcb\x87"
+    encoded_args = layout.build(
+        {
+            "amount_lamports": args["amount_lamports"],
+            "lock_until_ts": args["lock_until_ts"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
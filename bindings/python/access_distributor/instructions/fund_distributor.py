@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class FundDistributorArgs(typing.TypedDict):
+    amount: int
+
+
+layout = borsh.CStruct("amount" / borsh.U64)
+
+
+class FundDistributorAccounts(typing.TypedDict):
+    distributor: Pubkey
+    token_vault: Pubkey
+    from_: Pubkey
+    creator: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
+
+
+def fund_distributor(
+    args: FundDistributorArgs,
+    accounts: FundDistributorAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["token_vault"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["from_"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["creator"], is_signer=True, is_writable=False),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xdf\xff\xa3Y$\xfaA\x9c"
+    encoded_args = layout.build({"amount": args["amount"]})
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
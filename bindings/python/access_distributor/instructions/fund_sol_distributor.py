@@ -0,0 +1,40 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class FundSolDistributorArgs(typing.TypedDict):
+    amount: int
+
+
+layout = borsh.CStruct("amount" / borsh.U64)
+
+
+class FundSolDistributorAccounts(typing.TypedDict):
+    distributor: Pubkey
+    sol_vault: Pubkey
+    creator: Pubkey
+
+
+def fund_sol_distributor(
+    args: FundSolDistributorArgs,
+    accounts: FundSolDistributorAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["sol_vault"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["creator"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xad\xec)\xfa\\\x00\x10\x02"
+    encoded_args = layout.build({"amount": args["amount"]})
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,31 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class MigrateClaimStatusAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    payer: Pubkey
+
+
+def migrate_claim_status(
+    accounts: MigrateClaimStatusAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xcd\xa5\xbf\xe0\x1f;\xd9\x0b"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,44 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class MigrateDistributorArgs(typing.TypedDict):
+    claim_end_ts: typing.Optional[int]
+
+
+layout = borsh.CStruct("claim_end_ts" / borsh.Option(borsh.I64))
+
+
+class MigrateDistributorAccounts(typing.TypedDict):
+    distributor: Pubkey
+    token_vault: Pubkey
+    clawback_receiver: Pubkey
+    admin: Pubkey
+
+
+def migrate_distributor(
+    args: MigrateDistributorArgs,
+    accounts: MigrateDistributorAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["token_vault"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["clawback_receiver"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"@5\x10\xab\x11\xe0\x04("
+    encoded_args = layout.build({"claim_end_ts": args["claim_end_ts"]})
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -2,23 +2,31 @@ from __future__ import annotations
 import typing
 from solders.pubkey import Pubkey
 from solders.system_program import ID as SYS_PROGRAM_ID
-from spl.token.constants import TOKEN_PROGRAM_ID
 from solders.instruction import Instruction, AccountMeta
 from construct import Construct
 import borsh_construct as borsh
+from .. import types
 from ..program_id import PROGRAM_ID
 
 
 class NewClaimArgs(typing.TypedDict):
     amount_unlocked: int
     amount_locked: int
+    start_vesting_ts: typing.Optional[int]
+    end_vesting_ts: typing.Optional[int]
     proof: list[list[int]]
+    extra_amounts: list[types.extra_leaf_amount.ExtraLeafAmount]
 
 
 layout = borsh.CStruct(
     "amount_unlocked" / borsh.U64,
     "amount_locked" / borsh.U64,
+    "start_vesting_ts" / borsh.Option(borsh.I64),
+    "end_vesting_ts" / borsh.Option(borsh.I64),
     "proof" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+    "extra_amounts" / borsh.Vec(
+        typing.cast(Construct, types.extra_leaf_amount.ExtraLeafAmount.layout)
+    ),
 )
 
 
@@ -28,6 +36,9 @@ class NewClaimAccounts(typing.TypedDict):
     from_: Pubkey
     to: Pubkey
     claimant: Pubkey
+    payer: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
 
 
 def new_claim(
@@ -35,14 +46,21 @@ def new_claim(
     accounts: NewClaimAccounts,
     program_id: Pubkey = PROGRAM_ID,
     remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+    claimant_signer: bool = True,
 ) -> Instruction:
     keys: list[AccountMeta] = [
         AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["from_"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["to"], is_signer=False, is_writable=True),
-        AccountMeta(pubkey=accounts["claimant"], is_signer=True, is_writable=True),
-        AccountMeta(pubkey=TOKEN_PROGRAM_ID, is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["claimant"], is_signer=claimant_signer, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
         AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
     ]
     if remaining_accounts is not None:
@@ -52,7 +70,12 @@ def new_claim(
         {
             "amount_unlocked": args["amount_unlocked"],
             "amount_locked": args["amount_locked"],
+            "start_vesting_ts": args["start_vesting_ts"],
+            "end_vesting_ts": args["end_vesting_ts"],
             "proof": args["proof"],
+            "extra_amounts": list(
+                map(lambda item: item.to_encodable(), args["extra_amounts"])
+            ),
         }
     )
     data = identifier + encoded_args
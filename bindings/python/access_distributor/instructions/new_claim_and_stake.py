@@ -0,0 +1,118 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class NewClaimAndStakeArgs(typing.TypedDict):
+    amount_unlocked: int
+    amount_locked: int
+    start_vesting_ts: typing.Optional[int]
+    end_vesting_ts: typing.Optional[int]
+    proof: list[list[int]]
+    extra_amounts: list[types.extra_leaf_amount.ExtraLeafAmount]
+
+
+layout = borsh.CStruct(
+    "amount_unlocked" / borsh.U64,
+    "amount_locked" / borsh.U64,
+    "start_vesting_ts" / borsh.Option(borsh.I64),
+    "end_vesting_ts" / borsh.Option(borsh.I64),
+    "proof" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+    "extra_amounts" / borsh.Vec(
+        typing.cast(Construct, types.extra_leaf_amount.ExtraLeafAmount.layout)
+    ),
+)
+
+
+class ClaimNested(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    from_: Pubkey
+    to: Pubkey
+    claimant: Pubkey
+    payer: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
+
+
+class NewClaimAndStakeAccounts(typing.TypedDict):
+    claim: ClaimNested
+    central_state: Pubkey
+    stake_pool: Pubkey
+    stake_account: Pubkey
+    stake_vault: Pubkey
+    fee_account: Pubkey
+    staking_program: Pubkey
+
+
+def new_claim_and_stake(
+    args: NewClaimAndStakeArgs,
+    accounts: NewClaimAndStakeAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+    claimant_signer: bool = True,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["claim"]["distributor"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["claim_status"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["from_"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["claim"]["to"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["claim"]["claimant"],
+            is_signer=claimant_signer,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["payer"], is_signer=True, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["mint"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["claim"]["token_program"],
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["central_state"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["stake_pool"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["stake_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["stake_vault"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["fee_account"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["staking_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"2o\xf2v3\xfa\x8d\xbb"
+    encoded_args = layout.build(
+        {
+            "amount_unlocked": args["amount_unlocked"],
+            "amount_locked": args["amount_locked"],
+            "start_vesting_ts": args["start_vesting_ts"],
+            "end_vesting_ts": args["end_vesting_ts"],
+            "proof": args["proof"],
+            "extra_amounts": list(
+                map(lambda item: item.to_encodable(), args["extra_amounts"])
+            ),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,84 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class NewSolDistributorArgs(typing.TypedDict):
+    version: int
+    root: list[int]
+    max_total_claim: int
+    max_num_nodes: int
+    start_vesting_ts: int
+    end_vesting_ts: int
+    cliff_vesting_ts: int
+    cliff_percentage: int
+    vesting_mode: types.vesting_mode.VestingModeKind
+    clawback_start_ts: int
+    claim_end_ts: int
+    recoverable: bool
+
+
+layout = borsh.CStruct(
+    "version" / borsh.U64,
+    "root" / borsh.U8[32],
+    "max_total_claim" / borsh.U64,
+    "max_num_nodes" / borsh.U64,
+    "start_vesting_ts" / borsh.I64,
+    "end_vesting_ts" / borsh.I64,
+    "cliff_vesting_ts" / borsh.I64,
+    "cliff_percentage" / borsh.U8,
+    "vesting_mode" / types.vesting_mode.layout,
+    "clawback_start_ts" / borsh.I64,
+    "claim_end_ts" / borsh.I64,
+    "recoverable" / borsh.Bool,
+)
+
+
+class NewSolDistributorAccounts(typing.TypedDict):
+    distributor: Pubkey
+    sol_vault: Pubkey
+    clawback_receiver: Pubkey
+    creator: Pubkey
+
+
+def new_sol_distributor(
+    args: NewSolDistributorArgs,
+    accounts: NewSolDistributorAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["sol_vault"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["clawback_receiver"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["creator"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\nA\x8e\x02\xbc@\xfd\xac"
+    encoded_args = layout.build(
+        {
+            "version": args["version"],
+            "root": args["root"],
+            "max_total_claim": args["max_total_claim"],
+            "max_num_nodes": args["max_num_nodes"],
+            "start_vesting_ts": args["start_vesting_ts"],
+            "end_vesting_ts": args["end_vesting_ts"],
+            "cliff_vesting_ts": args["cliff_vesting_ts"],
+            "cliff_percentage": args["cliff_percentage"],
+            "vesting_mode": args["vesting_mode"].to_encodable(),
+            "clawback_start_ts": args["clawback_start_ts"],
+            "claim_end_ts": args["claim_end_ts"],
+            "recoverable": args["recoverable"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,31 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class RecoverClaimAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    admin: Pubkey
+    new_claimant: Pubkey
+
+
+def recover_claim(
+    accounts: RecoverClaimAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=False),
+        AccountMeta(pubkey=accounts["new_claimant"], is_signer=True, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"3d\xe4\xc4\xd9\xa0\xb9`"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
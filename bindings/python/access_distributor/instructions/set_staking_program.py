@@ -0,0 +1,33 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class SetStakingProgramAccounts(typing.TypedDict):
+    distributor: Pubkey
+    staking_program: Pubkey
+    stake_pool: Pubkey
+    admin: Pubkey
+
+
+def set_staking_program(
+    accounts: SetStakingProgramAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["staking_program"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["stake_pool"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b")n\xa7S\xce\x06\xf5\x15"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,33 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class TransferClaimAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    claimant: Pubkey
+    new_claimant: Pubkey
+
+
+def transfer_claim(
+    accounts: TransferClaimAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["claimant"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["new_claimant"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xbe\xe5\x87C\x90\x91\xca\xfd"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
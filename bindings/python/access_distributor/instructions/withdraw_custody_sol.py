@@ -1,6 +1,7 @@
 from __future__ import annotations
 import typing
 from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
 from solders.instruction import Instruction, AccountMeta
 import borsh_construct as borsh
 from ..program_id import PROGRAM_ID
@@ -14,8 +15,9 @@ layout = borsh.CStruct("amount_lamports" / borsh.U64)
 
 
 class WithdrawCustodySolAccounts(typing.TypedDict):
-    sol_custody: Pubkey
+    custody_vault: Pubkey
     owner: Pubkey
+    sol_custody: Pubkey
 
 
 def withdraw_custody_sol(
@@ -25,16 +27,16 @@ def withdraw_custody_sol(
     remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
 ) -> Instruction:
     keys: list[AccountMeta] = [
-        AccountMeta(pubkey=accounts["sol_custody"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["custody_vault"], is_signer=False, is_writable=True
+        ),
         AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["sol_custody"], is_signer=False, is_writable=True),
     ]
     if remaining_accounts is not None:
         keys += remaining_accounts
     identifier = b"v\x86T';|\xd9\x9a"
-    encoded_args = layout.build(
-        {
-            "amount_lamports": args["amount_lamports"],
-        }
-    )
+    encoded_args = layout.build({"amount_lamports": args["amount_lamports"]})
     data = identifier + encoded_args
     return Instruction(program_id, data, keys)
@@ -0,0 +1,11 @@
+import typing
+from . import extra_mint
+from .extra_mint import ExtraMint, ExtraMintJSON
+from . import extra_leaf_amount
+from .extra_leaf_amount import ExtraLeafAmount, ExtraLeafAmountJSON
+from . import extra_claim_amount
+from .extra_claim_amount import ExtraClaimAmount, ExtraClaimAmountJSON
+from . import milestone
+from .milestone import Milestone, MilestoneJSON
+from . import vesting_mode
+from .vesting_mode import VestingModeKind, VestingModeJSON
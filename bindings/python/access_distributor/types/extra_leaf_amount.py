@@ -0,0 +1,45 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class ExtraLeafAmountJSON(typing.TypedDict):
+    amount_unlocked: int
+    amount_locked: int
+
+
+@dataclass
+class ExtraLeafAmount:
+    layout: typing.ClassVar = borsh.CStruct(
+        "amount_unlocked" / borsh.U64, "amount_locked" / borsh.U64
+    )
+    amount_unlocked: int
+    amount_locked: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ExtraLeafAmount":
+        return cls(
+            amount_unlocked=obj.amount_unlocked,
+            amount_locked=obj.amount_locked,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "amount_unlocked": self.amount_unlocked,
+            "amount_locked": self.amount_locked,
+        }
+
+    def to_json(self) -> ExtraLeafAmountJSON:
+        return {
+            "amount_unlocked": self.amount_unlocked,
+            "amount_locked": self.amount_locked,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ExtraLeafAmountJSON) -> "ExtraLeafAmount":
+        return cls(
+            amount_unlocked=obj["amount_unlocked"],
+            amount_locked=obj["amount_locked"],
+        )
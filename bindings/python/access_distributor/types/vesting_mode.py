@@ -0,0 +1,164 @@
+from __future__ import annotations
+from . import milestone
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+PeriodicJSONValue = typing.TypedDict("PeriodicJSONValue", {"period": int})
+MilestonesJSONValue = typing.TypedDict(
+    "MilestonesJSONValue",
+    {"milestones": list[milestone.MilestoneJSON], "num_milestones": int},
+)
+PeriodicValue = typing.TypedDict("PeriodicValue", {"period": int})
+MilestonesValue = typing.TypedDict(
+    "MilestonesValue", {"milestones": list[milestone.Milestone], "num_milestones": int}
+)
+
+
+class LinearJSON(typing.TypedDict):
+    kind: typing.Literal["Linear"]
+
+
+class PeriodicJSON(typing.TypedDict):
+    value: PeriodicJSONValue
+    kind: typing.Literal["Periodic"]
+
+
+class MilestonesJSON(typing.TypedDict):
+    value: MilestonesJSONValue
+    kind: typing.Literal["Milestones"]
+
+
+@dataclass
+class Linear:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Linear"
+
+    def to_json(self) -> LinearJSON:
+        return LinearJSON(
+            kind="Linear",
+        )
+
+    def to_encodable(self) -> dict:
+        return {
+            "Linear": {},
+        }
+
+
+@dataclass
+class Periodic:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Periodic"
+    value: PeriodicValue
+
+    def to_json(self) -> PeriodicJSON:
+        return PeriodicJSON(
+            kind="Periodic",
+            value={
+                "period": self.value["period"],
+            },
+        )
+
+    def to_encodable(self) -> dict:
+        return {
+            "Periodic": {
+                "period": self.value["period"],
+            },
+        }
+
+
+@dataclass
+class Milestones:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "Milestones"
+    value: MilestonesValue
+
+    def to_json(self) -> MilestonesJSON:
+        return MilestonesJSON(
+            kind="Milestones",
+            value={
+                "milestones": list(
+                    map(lambda item: item.to_json(), self.value["milestones"])
+                ),
+                "num_milestones": self.value["num_milestones"],
+            },
+        )
+
+    def to_encodable(self) -> dict:
+        return {
+            "Milestones": {
+                "milestones": list(
+                    map(lambda item: item.to_encodable(), self.value["milestones"])
+                ),
+                "num_milestones": self.value["num_milestones"],
+            },
+        }
+
+
+VestingModeKind = typing.Union[Linear, Periodic, Milestones]
+VestingModeJSON = typing.Union[LinearJSON, PeriodicJSON, MilestonesJSON]
+
+
+def from_decoded(obj: dict) -> VestingModeKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Linear" in obj:
+        return Linear()
+    if "Periodic" in obj:
+        val = obj["Periodic"]
+        return Periodic(
+            PeriodicValue(
+                period=val["period"],
+            )
+        )
+    if "Milestones" in obj:
+        val = obj["Milestones"]
+        return Milestones(
+            MilestonesValue(
+                milestones=list(
+                    map(
+                        lambda item: milestone.Milestone.from_decoded(item),
+                        val["milestones"],
+                    )
+                ),
+                num_milestones=val["num_milestones"],
+            )
+        )
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: VestingModeJSON) -> VestingModeKind:
+    if obj["kind"] == "Linear":
+        return Linear()
+    if obj["kind"] == "Periodic":
+        periodic_json_value = typing.cast(PeriodicJSONValue, obj["value"])
+        return Periodic(
+            PeriodicValue(
+                period=periodic_json_value["period"],
+            )
+        )
+    if obj["kind"] == "Milestones":
+        milestones_json_value = typing.cast(MilestonesJSONValue, obj["value"])
+        return Milestones(
+            MilestonesValue(
+                milestones=list(
+                    map(
+                        lambda item: milestone.Milestone.from_json(item),
+                        milestones_json_value["milestones"],
+                    )
+                ),
+                num_milestones=milestones_json_value["num_milestones"],
+            )
+        )
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Linear" / borsh.CStruct(),
+    "Periodic" / borsh.CStruct("period" / borsh.I64),
+    "Milestones" / borsh.CStruct(
+        "milestones" / milestone.Milestone.layout[16], "num_milestones" / borsh.U8
+    ),
+)
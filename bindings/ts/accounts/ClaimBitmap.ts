@@ -0,0 +1,117 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimBitmapFields {
+  /** The distributor this page belongs to */
+  distributor: PublicKey
+  /** Page number, covering leaf indices page * CLAIMS_PER_BITMAP onwards */
+  page: number
+  /** One bit per leaf, set once claimed */
+  bits: Array<number>
+}
+
+export interface ClaimBitmapJSON {
+  /** The distributor this page belongs to */
+  distributor: string
+  /** Page number, covering leaf indices page * CLAIMS_PER_BITMAP onwards */
+  page: number
+  /** One bit per leaf, set once claimed */
+  bits: Array<number>
+}
+
+/**
+ * Records which leaves of a bitmap tracked [crate::state::merkle_distributor::MerkleDistributor]
+ * have been claimed, one bit per leaf index.
+ */
+export class ClaimBitmap {
+  /** The distributor this page belongs to */
+  readonly distributor: PublicKey
+  /** Page number, covering leaf indices page * CLAIMS_PER_BITMAP onwards */
+  readonly page: number
+  /** One bit per leaf, set once claimed */
+  readonly bits: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    61, 25, 148, 196, 164, 208, 65, 169,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("distributor"),
+    borsh.u32("page"),
+    borsh.array(borsh.u8(), 1024, "bits"),
+  ])
+
+  constructor(fields: ClaimBitmapFields) {
+    this.distributor = fields.distributor
+    this.page = fields.page
+    this.bits = fields.bits
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ClaimBitmap | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ClaimBitmap | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ClaimBitmap {
+    if (!data.slice(0, 8).equals(ClaimBitmap.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ClaimBitmap.layout.decode(data.slice(8))
+
+    return new ClaimBitmap({
+      distributor: dec.distributor,
+      page: dec.page,
+      bits: dec.bits,
+    })
+  }
+
+  toJSON(): ClaimBitmapJSON {
+    return {
+      distributor: this.distributor.toString(),
+      page: this.page,
+      bits: this.bits,
+    }
+  }
+
+  static fromJSON(obj: ClaimBitmapJSON): ClaimBitmap {
+    return new ClaimBitmap({
+      distributor: new PublicKey(obj.distributor),
+      page: obj.page,
+      bits: obj.bits,
+    })
+  }
+}
@@ -1,6 +1,7 @@
 import { PublicKey, Connection } from "@solana/web3.js"
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface ClaimStatusFields {
@@ -12,6 +13,27 @@ export interface ClaimStatusFields {
   lockedAmountWithdrawn: BN
   /** Unlocked amount */
   unlockedAmount: BN
+  /** Lockup time start (Unix Timestamp) */
+  startTs: BN
+  /** Lockup time end (Unix Timestamp) */
+  endTs: BN
+  /** Revocation time (Unix Timestamp), vesting stops at it, 0 if not revoked */
+  revokedTs: BN
+  /** Locked amount returned to the clawback receiver on revocation */
+  lockedAmountRevoked: BN
+  /**
+   * Claimant of the merkle leaf, the claim status PDA is derived from it.
+   * Differs from the claimant once the claim has been transferred.
+   */
+  leafClaimant: PublicKey
+  /** Amounts of the distributor's extra mints, in the order of the distributor's extra mints */
+  extraAmounts: Array<types.ExtraClaimAmountFields>
+  /** Locked amount staked through the distributor, it has to be unstaked before it is withdrawn */
+  lockedAmountStaked: BN
+  /** Layout of the account, new fields are carved out of the reserved bytes and bump it */
+  layoutVersion: number
+  /** Zeroed bytes reserved for future fields, so adding them doesn't resize the account */
+  reserved: Array<BN>
 }
 
 export interface ClaimStatusJSON {
@@ -23,6 +45,27 @@ export interface ClaimStatusJSON {
   lockedAmountWithdrawn: string
   /** Unlocked amount */
   unlockedAmount: string
+  /** Lockup time start (Unix Timestamp) */
+  startTs: string
+  /** Lockup time end (Unix Timestamp) */
+  endTs: string
+  /** Revocation time (Unix Timestamp), vesting stops at it, 0 if not revoked */
+  revokedTs: string
+  /** Locked amount returned to the clawback receiver on revocation */
+  lockedAmountRevoked: string
+  /**
+   * Claimant of the merkle leaf, the claim status PDA is derived from it.
+   * Differs from the claimant once the claim has been transferred.
+   */
+  leafClaimant: string
+  /** Amounts of the distributor's extra mints, in the order of the distributor's extra mints */
+  extraAmounts: Array<types.ExtraClaimAmountJSON>
+  /** Locked amount staked through the distributor, it has to be unstaked before it is withdrawn */
+  lockedAmountStaked: string
+  /** Layout of the account, new fields are carved out of the reserved bytes and bump it */
+  layoutVersion: number
+  /** Zeroed bytes reserved for future fields, so adding them doesn't resize the account */
+  reserved: Array<string>
 }
 
 /** Holds whether or not a claimant has claimed tokens. */
@@ -35,6 +78,27 @@ export class ClaimStatus {
   readonly lockedAmountWithdrawn: BN
   /** Unlocked amount */
   readonly unlockedAmount: BN
+  /** Lockup time start (Unix Timestamp) */
+  readonly startTs: BN
+  /** Lockup time end (Unix Timestamp) */
+  readonly endTs: BN
+  /** Revocation time (Unix Timestamp), vesting stops at it, 0 if not revoked */
+  readonly revokedTs: BN
+  /** Locked amount returned to the clawback receiver on revocation */
+  readonly lockedAmountRevoked: BN
+  /**
+   * Claimant of the merkle leaf, the claim status PDA is derived from it.
+   * Differs from the claimant once the claim has been transferred.
+   */
+  readonly leafClaimant: PublicKey
+  /** Amounts of the distributor's extra mints, in the order of the distributor's extra mints */
+  readonly extraAmounts: Array<types.ExtraClaimAmount>
+  /** Locked amount staked through the distributor, it has to be unstaked before it is withdrawn */
+  readonly lockedAmountStaked: BN
+  /** Layout of the account, new fields are carved out of the reserved bytes and bump it */
+  readonly layoutVersion: number
+  /** Zeroed bytes reserved for future fields, so adding them doesn't resize the account */
+  readonly reserved: Array<BN>
 
   static readonly discriminator = Buffer.from([
     22, 183, 249, 157, 247, 95, 150, 96,
@@ -45,6 +109,15 @@ export class ClaimStatus {
     borsh.u64("lockedAmount"),
     borsh.u64("lockedAmountWithdrawn"),
     borsh.u64("unlockedAmount"),
+    borsh.i64("startTs"),
+    borsh.i64("endTs"),
+    borsh.i64("revokedTs"),
+    borsh.u64("lockedAmountRevoked"),
+    borsh.publicKey("leafClaimant"),
+    borsh.array(types.ExtraClaimAmount.layout(), 2, "extraAmounts"),
+    borsh.u64("lockedAmountStaked"),
+    borsh.u8("layoutVersion"),
+    borsh.array(borsh.u64(), 8, "reserved"),
   ])
 
   static readonly getAddress = (claimant: PublicKey, distributor: PublicKey, programId: PublicKey = PROGRAM_ID) => {
@@ -56,6 +129,17 @@ export class ClaimStatus {
     this.lockedAmount = fields.lockedAmount
     this.lockedAmountWithdrawn = fields.lockedAmountWithdrawn
     this.unlockedAmount = fields.unlockedAmount
+    this.startTs = fields.startTs
+    this.endTs = fields.endTs
+    this.revokedTs = fields.revokedTs
+    this.lockedAmountRevoked = fields.lockedAmountRevoked
+    this.leafClaimant = fields.leafClaimant
+    this.extraAmounts = fields.extraAmounts.map(
+      (item) => new types.ExtraClaimAmount({ ...item })
+    )
+    this.lockedAmountStaked = fields.lockedAmountStaked
+    this.layoutVersion = fields.layoutVersion
+    this.reserved = fields.reserved
   }
 
   static async fetch(
@@ -106,6 +190,19 @@ export class ClaimStatus {
       lockedAmount: dec.lockedAmount,
       lockedAmountWithdrawn: dec.lockedAmountWithdrawn,
       unlockedAmount: dec.unlockedAmount,
+      startTs: dec.startTs,
+      endTs: dec.endTs,
+      revokedTs: dec.revokedTs,
+      lockedAmountRevoked: dec.lockedAmountRevoked,
+      leafClaimant: dec.leafClaimant,
+      extraAmounts: dec.extraAmounts.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.ExtraClaimAmount.fromDecoded(item)
+      ),
+      lockedAmountStaked: dec.lockedAmountStaked,
+      layoutVersion: dec.layoutVersion,
+      reserved: dec.reserved,
     })
   }
 
@@ -115,6 +212,15 @@ export class ClaimStatus {
       lockedAmount: this.lockedAmount.toString(),
       lockedAmountWithdrawn: this.lockedAmountWithdrawn.toString(),
       unlockedAmount: this.unlockedAmount.toString(),
+      startTs: this.startTs.toString(),
+      endTs: this.endTs.toString(),
+      revokedTs: this.revokedTs.toString(),
+      lockedAmountRevoked: this.lockedAmountRevoked.toString(),
+      leafClaimant: this.leafClaimant.toString(),
+      extraAmounts: this.extraAmounts.map((item) => item.toJSON()),
+      lockedAmountStaked: this.lockedAmountStaked.toString(),
+      layoutVersion: this.layoutVersion,
+      reserved: this.reserved.map((item) => item.toString()),
     }
   }
 
@@ -124,6 +230,17 @@ export class ClaimStatus {
       lockedAmount: new BN(obj.lockedAmount),
       lockedAmountWithdrawn: new BN(obj.lockedAmountWithdrawn),
       unlockedAmount: new BN(obj.unlockedAmount),
+      startTs: new BN(obj.startTs),
+      endTs: new BN(obj.endTs),
+      revokedTs: new BN(obj.revokedTs),
+      lockedAmountRevoked: new BN(obj.lockedAmountRevoked),
+      leafClaimant: new PublicKey(obj.leafClaimant),
+      extraAmounts: obj.extraAmounts.map(
+        (item) => types.ExtraClaimAmount.fromJSON(item)
+      ),
+      lockedAmountStaked: new BN(obj.lockedAmountStaked),
+      layoutVersion: obj.layoutVersion,
+      reserved: obj.reserved.map((item) => new BN(item)),
     })
   }
 }
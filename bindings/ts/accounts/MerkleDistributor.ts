@@ -1,6 +1,7 @@
 import { PublicKey, Connection } from "@solana/web3.js"
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface MerkleDistributorFields {
@@ -32,6 +33,75 @@ export interface MerkleDistributorFields {
   admin: PublicKey
   /** Whether or not the distributor has been clawed back */
   clawedBack: boolean
+  /** Lockup cliff (Unix Timestamp), nothing unlocks before it */
+  cliffTs: BN
+  /** Percentage of the locked amount unlocked at the cliff */
+  cliffPercentage: number
+  /** How locked tokens unlock over the lockup period */
+  vestingMode: types.VestingModeKind
+  /** Whether or not claims are paused by the admin */
+  paused: boolean
+  /**
+   * Clawback start (Unix Timestamp), the unclaimed tokens can't be clawed back before it.
+   * At least [MIN_CLAWBACK_DELAY] after end_ts and after every leaf's own vesting end, as a
+   * full clawback ignores per-leaf schedules. Leaves can't be checked on-chain, the CLI and
+   * merkle tree builder enforce it for them.
+   *
+   * [MIN_CLAWBACK_DELAY]: crate::instructions::new_distributor::MIN_CLAWBACK_DELAY
+   */
+  clawbackStartTs: BN
+  /** Token account the unclaimed tokens are clawed back to */
+  clawbackReceiver: PublicKey
+  /** Proposed admin wallet, default if there is no pending proposal */
+  pendingAdmin: PublicKey
+  /**
+   * Whether claims are tracked in [crate::state::claim_bitmap::ClaimBitmap] pages instead of
+   * claim status accounts, only for distributors without locked tokens
+   */
+  claimBitmap: boolean
+  /** Total amount of tokens the vault received through fund_distributor */
+  totalFunded: BN
+  /** Whether the vault covered all unclaimed tokens when last funded, new claims are rejected until then */
+  funded: boolean
+  /** Claim window end (Unix Timestamp), no new claims after it but existing claims keep vesting */
+  claimEndTs: BN
+  /** Locked tokens of existing claims that have not been withdrawn yet */
+  totalLockedOutstanding: BN
+  /** Amount of unclaimed or revoked tokens clawed back while existing claims keep vesting */
+  amountClawedBack: BN
+  /** Whether the unclaimed allocation has been clawed back, no new claims after it */
+  unclaimedClawedBack: boolean
+  /** Whether the admin can revoke the unvested locked tokens of a claim */
+  revocable: boolean
+  /** Whether the admin can move a claim to a new wallet of its claimant */
+  recoverable: boolean
+  /**
+   * Whether the distributor pays out native SOL from its SOL vault PDA instead of tokens,
+   * the token vault then holds the SOL vault address and the clawback receiver a wallet
+   */
+  nativeSol: boolean
+  /** Mints distributed alongside the mint, only the first num_extra_mints entries are used */
+  extraMints: Array<types.ExtraMintFields>
+  /** Number of extra mints, every leaf carries an amount of each */
+  numExtraMints: number
+  /**
+   * Staking program claimants can stake their claimed tokens into, default if staking is disabled,
+   * it can only be set once
+   */
+  stakingProgram: PublicKey
+  /** Stake pool of the staking program the claimed tokens are staked into */
+  stakePool: PublicKey
+  /** Locked tokens of existing claims staked by the distributor instead of held by the vault */
+  totalLockedStaked: BN
+  /**
+   * Whether anyone can claim to a claimant's associated token account without their
+   * signature, off unless enabled by the admin
+   */
+  permissionlessClaims: boolean
+  /** Layout of the account, new fields are carved out of the reserved bytes and bump it */
+  layoutVersion: number
+  /** Zeroed bytes reserved for future fields, so adding them doesn't resize the account */
+  reserved: Array<BN>
 }
 
 export interface MerkleDistributorJSON {
@@ -63,6 +133,75 @@ export interface MerkleDistributorJSON {
   admin: string
   /** Whether or not the distributor has been clawed back */
   clawedBack: boolean
+  /** Lockup cliff (Unix Timestamp), nothing unlocks before it */
+  cliffTs: string
+  /** Percentage of the locked amount unlocked at the cliff */
+  cliffPercentage: number
+  /** How locked tokens unlock over the lockup period */
+  vestingMode: types.VestingModeJSON
+  /** Whether or not claims are paused by the admin */
+  paused: boolean
+  /**
+   * Clawback start (Unix Timestamp), the unclaimed tokens can't be clawed back before it.
+   * At least [MIN_CLAWBACK_DELAY] after end_ts and after every leaf's own vesting end, as a
+   * full clawback ignores per-leaf schedules. Leaves can't be checked on-chain, the CLI and
+   * merkle tree builder enforce it for them.
+   *
+   * [MIN_CLAWBACK_DELAY]: crate::instructions::new_distributor::MIN_CLAWBACK_DELAY
+   */
+  clawbackStartTs: string
+  /** Token account the unclaimed tokens are clawed back to */
+  clawbackReceiver: string
+  /** Proposed admin wallet, default if there is no pending proposal */
+  pendingAdmin: string
+  /**
+   * Whether claims are tracked in [crate::state::claim_bitmap::ClaimBitmap] pages instead of
+   * claim status accounts, only for distributors without locked tokens
+   */
+  claimBitmap: boolean
+  /** Total amount of tokens the vault received through fund_distributor */
+  totalFunded: string
+  /** Whether the vault covered all unclaimed tokens when last funded, new claims are rejected until then */
+  funded: boolean
+  /** Claim window end (Unix Timestamp), no new claims after it but existing claims keep vesting */
+  claimEndTs: string
+  /** Locked tokens of existing claims that have not been withdrawn yet */
+  totalLockedOutstanding: string
+  /** Amount of unclaimed or revoked tokens clawed back while existing claims keep vesting */
+  amountClawedBack: string
+  /** Whether the unclaimed allocation has been clawed back, no new claims after it */
+  unclaimedClawedBack: boolean
+  /** Whether the admin can revoke the unvested locked tokens of a claim */
+  revocable: boolean
+  /** Whether the admin can move a claim to a new wallet of its claimant */
+  recoverable: boolean
+  /**
+   * Whether the distributor pays out native SOL from its SOL vault PDA instead of tokens,
+   * the token vault then holds the SOL vault address and the clawback receiver a wallet
+   */
+  nativeSol: boolean
+  /** Mints distributed alongside the mint, only the first num_extra_mints entries are used */
+  extraMints: Array<types.ExtraMintJSON>
+  /** Number of extra mints, every leaf carries an amount of each */
+  numExtraMints: number
+  /**
+   * Staking program claimants can stake their claimed tokens into, default if staking is disabled,
+   * it can only be set once
+   */
+  stakingProgram: string
+  /** Stake pool of the staking program the claimed tokens are staked into */
+  stakePool: string
+  /** Locked tokens of existing claims staked by the distributor instead of held by the vault */
+  totalLockedStaked: string
+  /**
+   * Whether anyone can claim to a claimant's associated token account without their
+   * signature, off unless enabled by the admin
+   */
+  permissionlessClaims: boolean
+  /** Layout of the account, new fields are carved out of the reserved bytes and bump it */
+  layoutVersion: number
+  /** Zeroed bytes reserved for future fields, so adding them doesn't resize the account */
+  reserved: Array<string>
 }
 
 /** State for the account which distributes tokens. */
@@ -95,6 +234,75 @@ export class MerkleDistributor {
   readonly admin: PublicKey
   /** Whether or not the distributor has been clawed back */
   readonly clawedBack: boolean
+  /** Lockup cliff (Unix Timestamp), nothing unlocks before it */
+  readonly cliffTs: BN
+  /** Percentage of the locked amount unlocked at the cliff */
+  readonly cliffPercentage: number
+  /** How locked tokens unlock over the lockup period */
+  readonly vestingMode: types.VestingModeKind
+  /** Whether or not claims are paused by the admin */
+  readonly paused: boolean
+  /**
+   * Clawback start (Unix Timestamp), the unclaimed tokens can't be clawed back before it.
+   * At least [MIN_CLAWBACK_DELAY] after end_ts and after every leaf's own vesting end, as a
+   * full clawback ignores per-leaf schedules. Leaves can't be checked on-chain, the CLI and
+   * merkle tree builder enforce it for them.
+   *
+   * [MIN_CLAWBACK_DELAY]: crate::instructions::new_distributor::MIN_CLAWBACK_DELAY
+   */
+  readonly clawbackStartTs: BN
+  /** Token account the unclaimed tokens are clawed back to */
+  readonly clawbackReceiver: PublicKey
+  /** Proposed admin wallet, default if there is no pending proposal */
+  readonly pendingAdmin: PublicKey
+  /**
+   * Whether claims are tracked in [crate::state::claim_bitmap::ClaimBitmap] pages instead of
+   * claim status accounts, only for distributors without locked tokens
+   */
+  readonly claimBitmap: boolean
+  /** Total amount of tokens the vault received through fund_distributor */
+  readonly totalFunded: BN
+  /** Whether the vault covered all unclaimed tokens when last funded, new claims are rejected until then */
+  readonly funded: boolean
+  /** Claim window end (Unix Timestamp), no new claims after it but existing claims keep vesting */
+  readonly claimEndTs: BN
+  /** Locked tokens of existing claims that have not been withdrawn yet */
+  readonly totalLockedOutstanding: BN
+  /** Amount of unclaimed or revoked tokens clawed back while existing claims keep vesting */
+  readonly amountClawedBack: BN
+  /** Whether the unclaimed allocation has been clawed back, no new claims after it */
+  readonly unclaimedClawedBack: boolean
+  /** Whether the admin can revoke the unvested locked tokens of a claim */
+  readonly revocable: boolean
+  /** Whether the admin can move a claim to a new wallet of its claimant */
+  readonly recoverable: boolean
+  /**
+   * Whether the distributor pays out native SOL from its SOL vault PDA instead of tokens,
+   * the token vault then holds the SOL vault address and the clawback receiver a wallet
+   */
+  readonly nativeSol: boolean
+  /** Mints distributed alongside the mint, only the first num_extra_mints entries are used */
+  readonly extraMints: Array<types.ExtraMint>
+  /** Number of extra mints, every leaf carries an amount of each */
+  readonly numExtraMints: number
+  /**
+   * Staking program claimants can stake their claimed tokens into, default if staking is disabled,
+   * it can only be set once
+   */
+  readonly stakingProgram: PublicKey
+  /** Stake pool of the staking program the claimed tokens are staked into */
+  readonly stakePool: PublicKey
+  /** Locked tokens of existing claims staked by the distributor instead of held by the vault */
+  readonly totalLockedStaked: BN
+  /**
+   * Whether anyone can claim to a claimant's associated token account without their
+   * signature, off unless enabled by the admin
+   */
+  readonly permissionlessClaims: boolean
+  /** Layout of the account, new fields are carved out of the reserved bytes and bump it */
+  readonly layoutVersion: number
+  /** Zeroed bytes reserved for future fields, so adding them doesn't resize the account */
+  readonly reserved: Array<BN>
 
   static readonly discriminator = Buffer.from([
     77, 119, 139, 70, 84, 247, 12, 26,
@@ -115,12 +323,42 @@ export class MerkleDistributor {
     borsh.publicKey("creator"),
     borsh.publicKey("admin"),
     borsh.bool("clawedBack"),
+    borsh.i64("cliffTs"),
+    borsh.u8("cliffPercentage"),
+    types.VestingMode.layout("vestingMode"),
+    borsh.bool("paused"),
+    borsh.i64("clawbackStartTs"),
+    borsh.publicKey("clawbackReceiver"),
+    borsh.publicKey("pendingAdmin"),
+    borsh.bool("claimBitmap"),
+    borsh.u64("totalFunded"),
+    borsh.bool("funded"),
+    borsh.i64("claimEndTs"),
+    borsh.u64("totalLockedOutstanding"),
+    borsh.u64("amountClawedBack"),
+    borsh.bool("unclaimedClawedBack"),
+    borsh.bool("revocable"),
+    borsh.bool("recoverable"),
+    borsh.bool("nativeSol"),
+    borsh.array(types.ExtraMint.layout(), 2, "extraMints"),
+    borsh.u8("numExtraMints"),
+    borsh.publicKey("stakingProgram"),
+    borsh.publicKey("stakePool"),
+    borsh.u64("totalLockedStaked"),
+    borsh.bool("permissionlessClaims"),
+    borsh.u8("layoutVersion"),
+    borsh.array(borsh.u64(), 16, "reserved"),
   ])
 
   static readonly getAddress = (creator: PublicKey, mint: PublicKey, version: BN, programId: PublicKey = PROGRAM_ID) => {
     return PublicKey.findProgramAddressSync([Buffer.from("MerkleDistributor"), mint.toBuffer(), creator.toBuffer(), version.toArrayLike(Buffer, "le", 8)], programId)[0]
   }
 
+  /** The address of a native SOL distributor, which has no mint seed. */
+  static readonly getSolAddress = (creator: PublicKey, version: BN, programId: PublicKey = PROGRAM_ID) => {
+    return PublicKey.findProgramAddressSync([Buffer.from("SolDistributor"), creator.toBuffer(), version.toArrayLike(Buffer, "le", 8)], programId)[0]
+  }
+
   constructor(fields: MerkleDistributorFields) {
     this.bump = fields.bump
     this.version = fields.version
@@ -136,6 +374,33 @@ export class MerkleDistributor {
     this.creator = fields.creator
     this.admin = fields.admin
     this.clawedBack = fields.clawedBack
+    this.cliffTs = fields.cliffTs
+    this.cliffPercentage = fields.cliffPercentage
+    this.vestingMode = fields.vestingMode
+    this.paused = fields.paused
+    this.clawbackStartTs = fields.clawbackStartTs
+    this.clawbackReceiver = fields.clawbackReceiver
+    this.pendingAdmin = fields.pendingAdmin
+    this.claimBitmap = fields.claimBitmap
+    this.totalFunded = fields.totalFunded
+    this.funded = fields.funded
+    this.claimEndTs = fields.claimEndTs
+    this.totalLockedOutstanding = fields.totalLockedOutstanding
+    this.amountClawedBack = fields.amountClawedBack
+    this.unclaimedClawedBack = fields.unclaimedClawedBack
+    this.revocable = fields.revocable
+    this.recoverable = fields.recoverable
+    this.nativeSol = fields.nativeSol
+    this.extraMints = fields.extraMints.map(
+      (item) => new types.ExtraMint({ ...item })
+    )
+    this.numExtraMints = fields.numExtraMints
+    this.stakingProgram = fields.stakingProgram
+    this.stakePool = fields.stakePool
+    this.totalLockedStaked = fields.totalLockedStaked
+    this.permissionlessClaims = fields.permissionlessClaims
+    this.layoutVersion = fields.layoutVersion
+    this.reserved = fields.reserved
   }
 
   static async fetch(
@@ -196,6 +461,35 @@ export class MerkleDistributor {
       creator: dec.creator,
       admin: dec.admin,
       clawedBack: dec.clawedBack,
+      cliffTs: dec.cliffTs,
+      cliffPercentage: dec.cliffPercentage,
+      vestingMode: types.VestingMode.fromDecoded(dec.vestingMode),
+      paused: dec.paused,
+      clawbackStartTs: dec.clawbackStartTs,
+      clawbackReceiver: dec.clawbackReceiver,
+      pendingAdmin: dec.pendingAdmin,
+      claimBitmap: dec.claimBitmap,
+      totalFunded: dec.totalFunded,
+      funded: dec.funded,
+      claimEndTs: dec.claimEndTs,
+      totalLockedOutstanding: dec.totalLockedOutstanding,
+      amountClawedBack: dec.amountClawedBack,
+      unclaimedClawedBack: dec.unclaimedClawedBack,
+      revocable: dec.revocable,
+      recoverable: dec.recoverable,
+      nativeSol: dec.nativeSol,
+      extraMints: dec.extraMints.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.ExtraMint.fromDecoded(item)
+      ),
+      numExtraMints: dec.numExtraMints,
+      stakingProgram: dec.stakingProgram,
+      stakePool: dec.stakePool,
+      totalLockedStaked: dec.totalLockedStaked,
+      permissionlessClaims: dec.permissionlessClaims,
+      layoutVersion: dec.layoutVersion,
+      reserved: dec.reserved,
     })
   }
 
@@ -215,6 +509,31 @@ export class MerkleDistributor {
       creator: this.creator.toString(),
       admin: this.admin.toString(),
       clawedBack: this.clawedBack,
+      cliffTs: this.cliffTs.toString(),
+      cliffPercentage: this.cliffPercentage,
+      vestingMode: this.vestingMode.toJSON(),
+      paused: this.paused,
+      clawbackStartTs: this.clawbackStartTs.toString(),
+      clawbackReceiver: this.clawbackReceiver.toString(),
+      pendingAdmin: this.pendingAdmin.toString(),
+      claimBitmap: this.claimBitmap,
+      totalFunded: this.totalFunded.toString(),
+      funded: this.funded,
+      claimEndTs: this.claimEndTs.toString(),
+      totalLockedOutstanding: this.totalLockedOutstanding.toString(),
+      amountClawedBack: this.amountClawedBack.toString(),
+      unclaimedClawedBack: this.unclaimedClawedBack,
+      revocable: this.revocable,
+      recoverable: this.recoverable,
+      nativeSol: this.nativeSol,
+      extraMints: this.extraMints.map((item) => item.toJSON()),
+      numExtraMints: this.numExtraMints,
+      stakingProgram: this.stakingProgram.toString(),
+      stakePool: this.stakePool.toString(),
+      totalLockedStaked: this.totalLockedStaked.toString(),
+      permissionlessClaims: this.permissionlessClaims,
+      layoutVersion: this.layoutVersion,
+      reserved: this.reserved.map((item) => item.toString()),
     }
   }
 
@@ -234,6 +553,31 @@ export class MerkleDistributor {
       creator: new PublicKey(obj.creator),
       admin: new PublicKey(obj.admin),
       clawedBack: obj.clawedBack,
+      cliffTs: new BN(obj.cliffTs),
+      cliffPercentage: obj.cliffPercentage,
+      vestingMode: types.VestingMode.fromJSON(obj.vestingMode),
+      paused: obj.paused,
+      clawbackStartTs: new BN(obj.clawbackStartTs),
+      clawbackReceiver: new PublicKey(obj.clawbackReceiver),
+      pendingAdmin: new PublicKey(obj.pendingAdmin),
+      claimBitmap: obj.claimBitmap,
+      totalFunded: new BN(obj.totalFunded),
+      funded: obj.funded,
+      claimEndTs: new BN(obj.claimEndTs),
+      totalLockedOutstanding: new BN(obj.totalLockedOutstanding),
+      amountClawedBack: new BN(obj.amountClawedBack),
+      unclaimedClawedBack: obj.unclaimedClawedBack,
+      revocable: obj.revocable,
+      recoverable: obj.recoverable,
+      nativeSol: obj.nativeSol,
+      extraMints: obj.extraMints.map((item) => types.ExtraMint.fromJSON(item)),
+      numExtraMints: obj.numExtraMints,
+      stakingProgram: new PublicKey(obj.stakingProgram),
+      stakePool: new PublicKey(obj.stakePool),
+      totalLockedStaked: new BN(obj.totalLockedStaked),
+      permissionlessClaims: obj.permissionlessClaims,
+      layoutVersion: obj.layoutVersion,
+      reserved: obj.reserved.map((item) => new BN(item)),
     })
   }
 }
@@ -3,11 +3,154 @@ import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
-export interface SolCustodyFields {}
+export interface SolCustodyFields {
+  /** Owner of the custody, the only one able to withdraw */
+  owner: PublicKey
+  /** Bump seed of this account. */
+  bump: number
+  /** Bump seed of the custody vault holding the lamports. */
+  vaultBump: number
+  /** Withdrawals are rejected before this timestamp, 0 if never locked */
+  lockUntilTs: BN
+  /** Lamports deposited, including those sent to the vault directly */
+  totalDeposited: BN
+  /** Lamports withdrawn */
+  totalWithdrawn: BN
+}
+
+export interface SolCustodyJSON {
+  /** Owner of the custody, the only one able to withdraw */
+  owner: string
+  /** Bump seed of this account. */
+  bump: number
+  /** Bump seed of the custody vault holding the lamports. */
+  vaultBump: number
+  /** Withdrawals are rejected before this timestamp, 0 if never locked */
+  lockUntilTs: string
+  /** Lamports deposited, including those sent to the vault directly */
+  totalDeposited: string
+  /** Lamports withdrawn */
+  totalWithdrawn: string
+}
 
-/** State for the account which distributes tokens. */
+/** Tracks the SOL an owner keeps in the custody vault PDA `[b"SolCustody", owner]`. */
 export class SolCustody {
+  /** Owner of the custody, the only one able to withdraw */
+  readonly owner: PublicKey
+  /** Bump seed of this account. */
+  readonly bump: number
+  /** Bump seed of the custody vault holding the lamports. */
+  readonly vaultBump: number
+  /** Withdrawals are rejected before this timestamp, 0 if never locked */
+  readonly lockUntilTs: BN
+  /** Lamports deposited, including those sent to the vault directly */
+  readonly totalDeposited: BN
+  /** Lamports withdrawn */
+  readonly totalWithdrawn: BN
+
+  static readonly discriminator = Buffer.from([
+    89, 34, 75, 160, 36, 105, 176, 191,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("owner"),
+    borsh.u8("bump"),
+    borsh.u8("vaultBump"),
+    borsh.i64("lockUntilTs"),
+    borsh.u64("totalDeposited"),
+    borsh.u64("totalWithdrawn"),
+  ])
+
   static readonly getAddress = (owner: PublicKey, programId: PublicKey = PROGRAM_ID) => {
+    return PublicKey.findProgramAddressSync([Buffer.from("SolCustodyState"), owner.toBuffer()], programId)[0]
+  }
+
+  /** The custody vault PDA holding the lamports of the owner. */
+  static readonly getVaultAddress = (owner: PublicKey, programId: PublicKey = PROGRAM_ID) => {
     return PublicKey.findProgramAddressSync([Buffer.from("SolCustody"), owner.toBuffer()], programId)[0]
   }
+
+  constructor(fields: SolCustodyFields) {
+    this.owner = fields.owner
+    this.bump = fields.bump
+    this.vaultBump = fields.vaultBump
+    this.lockUntilTs = fields.lockUntilTs
+    this.totalDeposited = fields.totalDeposited
+    this.totalWithdrawn = fields.totalWithdrawn
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<SolCustody | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<SolCustody | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): SolCustody {
+    if (!data.slice(0, 8).equals(SolCustody.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = SolCustody.layout.decode(data.slice(8))
+
+    return new SolCustody({
+      owner: dec.owner,
+      bump: dec.bump,
+      vaultBump: dec.vaultBump,
+      lockUntilTs: dec.lockUntilTs,
+      totalDeposited: dec.totalDeposited,
+      totalWithdrawn: dec.totalWithdrawn,
+    })
+  }
+
+  toJSON(): SolCustodyJSON {
+    return {
+      owner: this.owner.toString(),
+      bump: this.bump,
+      vaultBump: this.vaultBump,
+      lockUntilTs: this.lockUntilTs.toString(),
+      totalDeposited: this.totalDeposited.toString(),
+      totalWithdrawn: this.totalWithdrawn.toString(),
+    }
+  }
+
+  static fromJSON(obj: SolCustodyJSON): SolCustody {
+    return new SolCustody({
+      owner: new PublicKey(obj.owner),
+      bump: obj.bump,
+      vaultBump: obj.vaultBump,
+      lockUntilTs: new BN(obj.lockUntilTs),
+      totalDeposited: new BN(obj.totalDeposited),
+      totalWithdrawn: new BN(obj.totalWithdrawn),
+    })
+  }
 }
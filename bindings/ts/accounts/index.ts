@@ -1,3 +1,5 @@
+export { ClaimBitmap } from "./ClaimBitmap"
+export type { ClaimBitmapFields, ClaimBitmapJSON } from "./ClaimBitmap"
 export { ClaimStatus } from "./ClaimStatus"
 export type { ClaimStatusFields, ClaimStatusJSON } from "./ClaimStatus"
 export { MerkleDistributor } from "./MerkleDistributor"
@@ -6,3 +8,4 @@ export type {
   MerkleDistributorJSON,
 } from "./MerkleDistributor"
 export { SolCustody } from "./SolCustody"
+export type { SolCustodyFields, SolCustodyJSON } from "./SolCustody"
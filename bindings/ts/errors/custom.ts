@@ -11,6 +11,50 @@ export type CustomError =
   | ArithmeticError
   | StartTimestampAfterEnd
   | TimestampsNotInFuture
+  | InsufficientFunds
+  | CliffOutsideVestingPeriod
+  | InvalidCliffPercentage
+  | PartialVestingSchedule
+  | InvalidVestingMode
+  | DistributorPaused
+  | InsufficientClawbackDelay
+  | ClawbackBeforeStart
+  | SameClawbackReceiver
+  | InvalidClawbackReceiver
+  | NoPendingAdmin
+  | ClaimantSignatureRequired
+  | ClaimNotFullyWithdrawn
+  | InvalidClaimMode
+  | InvalidClaimBitmap
+  | AlreadyClaimed
+  | DistributorShrinks
+  | DistributorNotFunded
+  | NotRevocable
+  | ClaimRevoked
+  | ClaimTransferred
+  | SameClaimant
+  | NotRecoverable
+  | DistributorKindMismatch
+  | TooManyExtraMints
+  | ExtraMintsUnsupported
+  | DuplicateMint
+  | InvalidExtraMintAccounts
+  | StakingDisabled
+  | InvalidStakingProgram
+  | InsufficientLockedTokens
+  | InsufficientStakedTokens
+  | LockedTokensStaked
+  | CustodyLocked
+  | CustodyLockShortened
+  | DistributorAlreadyMigrated
+  | SolTransferBelowRentExempt
+  | StakingProgramAlreadySet
+  | InvalidStakeTransfer
+  | ClaimStatusAlreadyMigrated
+  | InvalidFundingTransfer
+  | DistributorFunded
+  | RootUnchanged
+  | StakingFeeNotSupported
 
 export class InsufficientUnlockedTokens extends Error {
   static readonly code = 6000
@@ -144,6 +188,526 @@ export class TimestampsNotInFuture extends Error {
   }
 }
 
+export class InsufficientFunds extends Error {
+  static readonly code = 6012
+  readonly code = 6012
+  readonly name = "InsufficientFunds"
+  readonly msg = "Insufficient funds"
+
+  constructor(readonly logs?: string[]) {
+    super("6012: Insufficient funds")
+  }
+}
+
+export class CliffOutsideVestingPeriod extends Error {
+  static readonly code = 6013
+  readonly code = 6013
+  readonly name = "CliffOutsideVestingPeriod"
+  readonly msg = "Cliff timestamp must be between start and end timestamps"
+
+  constructor(readonly logs?: string[]) {
+    super("6013: Cliff timestamp must be between start and end timestamps")
+  }
+}
+
+export class InvalidCliffPercentage extends Error {
+  static readonly code = 6014
+  readonly code = 6014
+  readonly name = "InvalidCliffPercentage"
+  readonly msg = "Cliff percentage cannot exceed 100"
+
+  constructor(readonly logs?: string[]) {
+    super("6014: Cliff percentage cannot exceed 100")
+  }
+}
+
+export class PartialVestingSchedule extends Error {
+  static readonly code = 6015
+  readonly code = 6015
+  readonly name = "PartialVestingSchedule"
+  readonly msg = "Leaf vesting schedule requires both start and end timestamps"
+
+  constructor(readonly logs?: string[]) {
+    super("6015: Leaf vesting schedule requires both start and end timestamps")
+  }
+}
+
+export class InvalidVestingMode extends Error {
+  static readonly code = 6016
+  readonly code = 6016
+  readonly name = "InvalidVestingMode"
+  readonly msg =
+    "Vesting period must be positive and milestones must be ordered and end at 100%"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6016: Vesting period must be positive and milestones must be ordered and end at 100%"
+    )
+  }
+}
+
+export class DistributorPaused extends Error {
+  static readonly code = 6017
+  readonly code = 6017
+  readonly name = "DistributorPaused"
+  readonly msg = "Claims are paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6017: Claims are paused")
+  }
+}
+
+export class InsufficientClawbackDelay extends Error {
+  static readonly code = 6018
+  readonly code = 6018
+  readonly name = "InsufficientClawbackDelay"
+  readonly msg = "Clawback start must be at least one day after end timestamp"
+
+  constructor(readonly logs?: string[]) {
+    super("6018: Clawback start must be at least one day after end timestamp")
+  }
+}
+
+export class ClawbackBeforeStart extends Error {
+  static readonly code = 6019
+  readonly code = 6019
+  readonly name = "ClawbackBeforeStart"
+  readonly msg = "Clawback is not allowed before the clawback start timestamp"
+
+  constructor(readonly logs?: string[]) {
+    super("6019: Clawback is not allowed before the clawback start timestamp")
+  }
+}
+
+export class SameClawbackReceiver extends Error {
+  static readonly code = 6020
+  readonly code = 6020
+  readonly name = "SameClawbackReceiver"
+  readonly msg = "New and old clawback receivers are identical"
+
+  constructor(readonly logs?: string[]) {
+    super("6020: New and old clawback receivers are identical")
+  }
+}
+
+export class InvalidClawbackReceiver extends Error {
+  static readonly code = 6021
+  readonly code = 6021
+  readonly name = "InvalidClawbackReceiver"
+  readonly msg =
+    "Clawback receiver does not match the distributor's clawback receiver"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6021: Clawback receiver does not match the distributor's clawback receiver"
+    )
+  }
+}
+
+export class NoPendingAdmin extends Error {
+  static readonly code = 6022
+  readonly code = 6022
+  readonly name = "NoPendingAdmin"
+  readonly msg = "No admin has been proposed"
+
+  constructor(readonly logs?: string[]) {
+    super("6022: No admin has been proposed")
+  }
+}
+
+export class ClaimantSignatureRequired extends Error {
+  static readonly code = 6023
+  readonly code = 6023
+  readonly name = "ClaimantSignatureRequired"
+  readonly msg =
+    "Claimant must sign unless permissionless claims go to their associated token account"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6023: Claimant must sign unless permissionless claims go to their associated token account"
+    )
+  }
+}
+
+export class ClaimNotFullyWithdrawn extends Error {
+  static readonly code = 6024
+  readonly code = 6024
+  readonly name = "ClaimNotFullyWithdrawn"
+  readonly msg = "Claim still has locked tokens to withdraw"
+
+  constructor(readonly logs?: string[]) {
+    super("6024: Claim still has locked tokens to withdraw")
+  }
+}
+
+export class InvalidClaimMode extends Error {
+  static readonly code = 6025
+  readonly code = 6025
+  readonly name = "InvalidClaimMode"
+  readonly msg = "Claim does not match the distributor's claim tracking mode"
+
+  constructor(readonly logs?: string[]) {
+    super("6025: Claim does not match the distributor's claim tracking mode")
+  }
+}
+
+export class InvalidClaimBitmap extends Error {
+  static readonly code = 6026
+  readonly code = 6026
+  readonly name = "InvalidClaimBitmap"
+  readonly msg = "Claim bitmap page does not cover the leaf index"
+
+  constructor(readonly logs?: string[]) {
+    super("6026: Claim bitmap page does not cover the leaf index")
+  }
+}
+
+export class AlreadyClaimed extends Error {
+  static readonly code = 6027
+  readonly code = 6027
+  readonly name = "AlreadyClaimed"
+  readonly msg = "Leaf has already been claimed"
+
+  constructor(readonly logs?: string[]) {
+    super("6027: Leaf has already been claimed")
+  }
+}
+
+export class DistributorShrinks extends Error {
+  static readonly code = 6028
+  readonly code = 6028
+  readonly name = "DistributorShrinks"
+  readonly msg =
+    "New max total claim and max num nodes cannot be less than the current ones"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6028: New max total claim and max num nodes cannot be less than the current ones"
+    )
+  }
+}
+
+export class DistributorNotFunded extends Error {
+  static readonly code = 6029
+  readonly code = 6029
+  readonly name = "DistributorNotFunded"
+  readonly msg = "Distributor vault does not cover the unclaimed tokens"
+
+  constructor(readonly logs?: string[]) {
+    super("6029: Distributor vault does not cover the unclaimed tokens")
+  }
+}
+
+export class NotRevocable extends Error {
+  static readonly code = 6030
+  readonly code = 6030
+  readonly name = "NotRevocable"
+  readonly msg = "Distributor claims are not revocable"
+
+  constructor(readonly logs?: string[]) {
+    super("6030: Distributor claims are not revocable")
+  }
+}
+
+export class ClaimRevoked extends Error {
+  static readonly code = 6031
+  readonly code = 6031
+  readonly name = "ClaimRevoked"
+  readonly msg = "Claim has already been revoked"
+
+  constructor(readonly logs?: string[]) {
+    super("6031: Claim has already been revoked")
+  }
+}
+
+export class ClaimTransferred extends Error {
+  static readonly code = 6032
+  readonly code = 6032
+  readonly name = "ClaimTransferred"
+  readonly msg = "Claim has been transferred to another claimant"
+
+  constructor(readonly logs?: string[]) {
+    super("6032: Claim has been transferred to another claimant")
+  }
+}
+
+export class SameClaimant extends Error {
+  static readonly code = 6033
+  readonly code = 6033
+  readonly name = "SameClaimant"
+  readonly msg = "New claimant must differ from the current claimant"
+
+  constructor(readonly logs?: string[]) {
+    super("6033: New claimant must differ from the current claimant")
+  }
+}
+
+export class NotRecoverable extends Error {
+  static readonly code = 6034
+  readonly code = 6034
+  readonly name = "NotRecoverable"
+  readonly msg = "Distributor claims are not recoverable by the admin"
+
+  constructor(readonly logs?: string[]) {
+    super("6034: Distributor claims are not recoverable by the admin")
+  }
+}
+
+export class DistributorKindMismatch extends Error {
+  static readonly code = 6035
+  readonly code = 6035
+  readonly name = "DistributorKindMismatch"
+  readonly msg =
+    "Instruction does not match whether the distributor pays out native SOL or tokens"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6035: Instruction does not match whether the distributor pays out native SOL or tokens"
+    )
+  }
+}
+
+export class TooManyExtraMints extends Error {
+  static readonly code = 6036
+  readonly code = 6036
+  readonly name = "TooManyExtraMints"
+  readonly msg =
+    "Distributor already distributes the maximum number of extra mints"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6036: Distributor already distributes the maximum number of extra mints"
+    )
+  }
+}
+
+export class ExtraMintsUnsupported extends Error {
+  static readonly code = 6037
+  readonly code = 6037
+  readonly name = "ExtraMintsUnsupported"
+  readonly msg =
+    "Extra mints are not supported by the distributor or instruction"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6037: Extra mints are not supported by the distributor or instruction"
+    )
+  }
+}
+
+export class DuplicateMint extends Error {
+  static readonly code = 6038
+  readonly code = 6038
+  readonly name = "DuplicateMint"
+  readonly msg = "Mint is already distributed by the distributor"
+
+  constructor(readonly logs?: string[]) {
+    super("6038: Mint is already distributed by the distributor")
+  }
+}
+
+export class InvalidExtraMintAccounts extends Error {
+  static readonly code = 6039
+  readonly code = 6039
+  readonly name = "InvalidExtraMintAccounts"
+  readonly msg = "Remaining accounts do not match the distributor's extra mints"
+
+  constructor(readonly logs?: string[]) {
+    super("6039: Remaining accounts do not match the distributor's extra mints")
+  }
+}
+
+export class StakingDisabled extends Error {
+  static readonly code = 6040
+  readonly code = 6040
+  readonly name = "StakingDisabled"
+  readonly msg = "Distributor has no staking program"
+
+  constructor(readonly logs?: string[]) {
+    super("6040: Distributor has no staking program")
+  }
+}
+
+export class InvalidStakingProgram extends Error {
+  static readonly code = 6041
+  readonly code = 6041
+  readonly name = "InvalidStakingProgram"
+  readonly msg =
+    "Staking program or stake pool does not match the distributor's"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6041: Staking program or stake pool does not match the distributor's"
+    )
+  }
+}
+
+export class InsufficientLockedTokens extends Error {
+  static readonly code = 6042
+  readonly code = 6042
+  readonly name = "InsufficientLockedTokens"
+  readonly msg = "Claim does not have enough idle locked tokens"
+
+  constructor(readonly logs?: string[]) {
+    super("6042: Claim does not have enough idle locked tokens")
+  }
+}
+
+export class InsufficientStakedTokens extends Error {
+  static readonly code = 6043
+  readonly code = 6043
+  readonly name = "InsufficientStakedTokens"
+  readonly msg = "Claim does not have enough staked locked tokens"
+
+  constructor(readonly logs?: string[]) {
+    super("6043: Claim does not have enough staked locked tokens")
+  }
+}
+
+export class LockedTokensStaked extends Error {
+  static readonly code = 6044
+  readonly code = 6044
+  readonly name = "LockedTokensStaked"
+  readonly msg = "Locked tokens are staked, they have to be unstaked first"
+
+  constructor(readonly logs?: string[]) {
+    super("6044: Locked tokens are staked, they have to be unstaked first")
+  }
+}
+
+export class CustodyLocked extends Error {
+  static readonly code = 6045
+  readonly code = 6045
+  readonly name = "CustodyLocked"
+  readonly msg = "SOL custody is locked"
+
+  constructor(readonly logs?: string[]) {
+    super("6045: SOL custody is locked")
+  }
+}
+
+export class CustodyLockShortened extends Error {
+  static readonly code = 6046
+  readonly code = 6046
+  readonly name = "CustodyLockShortened"
+  readonly msg = "SOL custody lock can only be extended"
+
+  constructor(readonly logs?: string[]) {
+    super("6046: SOL custody lock can only be extended")
+  }
+}
+
+export class DistributorAlreadyMigrated extends Error {
+  static readonly code = 6047
+  readonly code = 6047
+  readonly name = "DistributorAlreadyMigrated"
+  readonly msg = "Distributor already has the current layout"
+
+  constructor(readonly logs?: string[]) {
+    super("6047: Distributor already has the current layout")
+  }
+}
+
+export class SolTransferBelowRentExempt extends Error {
+  static readonly code = 6048
+  readonly code = 6048
+  readonly name = "SolTransferBelowRentExempt"
+  readonly msg =
+    "SOL transfer would leave the recipient below the rent-exempt minimum"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6048: SOL transfer would leave the recipient below the rent-exempt minimum"
+    )
+  }
+}
+
+export class StakingProgramAlreadySet extends Error {
+  static readonly code = 6049
+  readonly code = 6049
+  readonly name = "StakingProgramAlreadySet"
+  readonly msg =
+    "Distributor already has a staking program, it can't be changed"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6049: Distributor already has a staking program, it can't be changed"
+    )
+  }
+}
+
+export class InvalidStakeTransfer extends Error {
+  static readonly code = 6050
+  readonly code = 6050
+  readonly name = "InvalidStakeTransfer"
+  readonly msg = "Staking program did not move exactly the staked amount"
+
+  constructor(readonly logs?: string[]) {
+    super("6050: Staking program did not move exactly the staked amount")
+  }
+}
+
+export class ClaimStatusAlreadyMigrated extends Error {
+  static readonly code = 6051
+  readonly code = 6051
+  readonly name = "ClaimStatusAlreadyMigrated"
+  readonly msg = "Claim status already has the current layout"
+
+  constructor(readonly logs?: string[]) {
+    super("6051: Claim status already has the current layout")
+  }
+}
+
+export class InvalidFundingTransfer extends Error {
+  static readonly code = 6052
+  readonly code = 6052
+  readonly name = "InvalidFundingTransfer"
+  readonly msg =
+    "Vault did not receive the funded amount net of the transfer fee"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6052: Vault did not receive the funded amount net of the transfer fee"
+    )
+  }
+}
+
+export class DistributorFunded extends Error {
+  static readonly code = 6053
+  readonly code = 6053
+  readonly name = "DistributorFunded"
+  readonly msg = "Distributor is already funded"
+
+  constructor(readonly logs?: string[]) {
+    super("6053: Distributor is already funded")
+  }
+}
+
+export class RootUnchanged extends Error {
+  static readonly code = 6054
+  readonly code = 6054
+  readonly name = "RootUnchanged"
+  readonly msg = "New root is the distributor's current root"
+
+  constructor(readonly logs?: string[]) {
+    super("6054: New root is the distributor's current root")
+  }
+}
+
+export class StakingFeeNotSupported extends Error {
+  static readonly code = 6055
+  readonly code = 6055
+  readonly name = "StakingFeeNotSupported"
+  readonly msg =
+    "Stake pool charges a staking fee, only fee-free stake pools are supported"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6055: Stake pool charges a staking fee, only fee-free stake pools are supported"
+    )
+  }
+}
+
 export function fromCode(code: number, logs?: string[]): CustomError | null {
   switch (code) {
     case 6000:
@@ -170,6 +734,94 @@ export function fromCode(code: number, logs?: string[]): CustomError | null {
       return new StartTimestampAfterEnd(logs)
     case 6011:
       return new TimestampsNotInFuture(logs)
+    case 6012:
+      return new InsufficientFunds(logs)
+    case 6013:
+      return new CliffOutsideVestingPeriod(logs)
+    case 6014:
+      return new InvalidCliffPercentage(logs)
+    case 6015:
+      return new PartialVestingSchedule(logs)
+    case 6016:
+      return new InvalidVestingMode(logs)
+    case 6017:
+      return new DistributorPaused(logs)
+    case 6018:
+      return new InsufficientClawbackDelay(logs)
+    case 6019:
+      return new ClawbackBeforeStart(logs)
+    case 6020:
+      return new SameClawbackReceiver(logs)
+    case 6021:
+      return new InvalidClawbackReceiver(logs)
+    case 6022:
+      return new NoPendingAdmin(logs)
+    case 6023:
+      return new ClaimantSignatureRequired(logs)
+    case 6024:
+      return new ClaimNotFullyWithdrawn(logs)
+    case 6025:
+      return new InvalidClaimMode(logs)
+    case 6026:
+      return new InvalidClaimBitmap(logs)
+    case 6027:
+      return new AlreadyClaimed(logs)
+    case 6028:
+      return new DistributorShrinks(logs)
+    case 6029:
+      return new DistributorNotFunded(logs)
+    case 6030:
+      return new NotRevocable(logs)
+    case 6031:
+      return new ClaimRevoked(logs)
+    case 6032:
+      return new ClaimTransferred(logs)
+    case 6033:
+      return new SameClaimant(logs)
+    case 6034:
+      return new NotRecoverable(logs)
+    case 6035:
+      return new DistributorKindMismatch(logs)
+    case 6036:
+      return new TooManyExtraMints(logs)
+    case 6037:
+      return new ExtraMintsUnsupported(logs)
+    case 6038:
+      return new DuplicateMint(logs)
+    case 6039:
+      return new InvalidExtraMintAccounts(logs)
+    case 6040:
+      return new StakingDisabled(logs)
+    case 6041:
+      return new InvalidStakingProgram(logs)
+    case 6042:
+      return new InsufficientLockedTokens(logs)
+    case 6043:
+      return new InsufficientStakedTokens(logs)
+    case 6044:
+      return new LockedTokensStaked(logs)
+    case 6045:
+      return new CustodyLocked(logs)
+    case 6046:
+      return new CustodyLockShortened(logs)
+    case 6047:
+      return new DistributorAlreadyMigrated(logs)
+    case 6048:
+      return new SolTransferBelowRentExempt(logs)
+    case 6049:
+      return new StakingProgramAlreadySet(logs)
+    case 6050:
+      return new InvalidStakeTransfer(logs)
+    case 6051:
+      return new ClaimStatusAlreadyMigrated(logs)
+    case 6052:
+      return new InvalidFundingTransfer(logs)
+    case 6053:
+      return new DistributorFunded(logs)
+    case 6054:
+      return new RootUnchanged(logs)
+    case 6055:
+      return new StakingFeeNotSupported(logs)
   }
 
   return null
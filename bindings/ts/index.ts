@@ -2,4 +2,5 @@ export * from "./accounts/index.js";
 export * from "./errors/index.js";
 export * from "./secondary/index.js";
 export * as rawInstructions from "./raw_instructions/index.js";
+export * as types from "./types/index.js";
 export * from "./programId.js";
\ No newline at end of file
@@ -0,0 +1,23 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AcceptAdminAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Pending admin signer */
+  newAdmin: PublicKey
+}
+
+export function acceptAdmin(
+  accounts: AcceptAdminAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.newAdmin, isSigner: true, isWritable: true },
+  ]
+  const identifier = Buffer.from([112, 42, 45, 90, 116, 181, 13, 170])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
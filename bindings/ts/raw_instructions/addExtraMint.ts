@@ -0,0 +1,53 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AddExtraMintArgs {
+  root: Array<number>
+  maxTotalClaim: BN
+}
+
+export interface AddExtraMintAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** The extra mint to distribute. */
+  mint: PublicKey
+  /** Token vault of the extra mint, the distributor's associated token account */
+  tokenVault: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+  /** The SPL Token or Token-2022 program of the extra mint. */
+  tokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "root"),
+  borsh.u64("maxTotalClaim"),
+])
+
+export function addExtraMint(
+  args: AddExtraMintArgs,
+  accounts: AddExtraMintAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([88, 173, 95, 246, 242, 165, 41, 92])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      root: args.root,
+      maxTotalClaim: args.maxTotalClaim,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,23 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CancelAdminProposalAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+}
+
+export function cancelAdminProposal(
+  accounts: CancelAdminProposalAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: true },
+  ]
+  const identifier = Buffer.from([68, 6, 145, 131, 16, 73, 182, 229])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,78 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimAllArgs {
+  amountUnlocked: BN
+  amountLocked: BN
+  startVestingTs: BN | null
+  endVestingTs: BN | null
+  proof: Array<Array<number>>
+}
+
+export interface ClaimAllAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim status PDA, initialized on the first claim */
+  claimStatus: PublicKey
+  /** Distributor ATA containing the tokens to distribute. */
+  from: PublicKey
+  /** Account to send the claimed tokens to. */
+  to: PublicKey
+  /**
+   * Who is claiming the tokens.
+   * to their associated token account
+   */
+  claimant: PublicKey
+  /** Pays for the claim status rent, can be the claimant or a relayer. */
+  payer: PublicKey
+  /** The mint to distribute. */
+  mint: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
+  tokenProgram: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("amountUnlocked"),
+  borsh.u64("amountLocked"),
+  borsh.option(borsh.i64(), "startVestingTs"),
+  borsh.option(borsh.i64(), "endVestingTs"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "proof"),
+])
+
+export function claimAll(
+  args: ClaimAllArgs,
+  accounts: ClaimAllAccounts,
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.from, isSigner: false, isWritable: true },
+    { pubkey: accounts.to, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: claimantSigner, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([194, 194, 80, 194, 234, 210, 217, 90])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amountUnlocked: args.amountUnlocked,
+      amountLocked: args.amountLocked,
+      startVestingTs: args.startVestingTs,
+      endVestingTs: args.endVestingTs,
+      proof: args.proof,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -4,31 +4,39 @@ import { PROGRAM_ID } from "../programId"
 export interface ClaimLockedAccounts {
   /** The [MerkleDistributor]. */
   distributor: PublicKey
-  /** Claim Status PDA */
+  /** Claim Status PDA, derived from the leaf claimant even if the claim was transferred */
   claimStatus: PublicKey
   /** Distributor ATA containing the tokens to distribute. */
   from: PublicKey
   /**
    * Account to send the claimed tokens to.
-   * Claimant must sign the transaction and can only claim on behalf of themself
+   * Claimant must sign the transaction unless the distributor allows permissionless claims
+   * and this is their associated token account
    */
   to: PublicKey
-  /** Who is claiming the tokens. */
+  /**
+   * Who is claiming the tokens.
+   * to their associated token account
+   */
   claimant: PublicKey
-  /** SPL [Token] program. */
+  /** The mint to distribute. */
+  mint: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
   tokenProgram: PublicKey
 }
 
 export function claimLocked(
   accounts: ClaimLockedAccounts,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
 ) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.distributor, isSigner: false, isWritable: true },
     { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
     { pubkey: accounts.from, isSigner: false, isWritable: true },
     { pubkey: accounts.to, isSigner: false, isWritable: true },
-    { pubkey: accounts.claimant, isSigner: true, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: claimantSigner, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([34, 206, 181, 23, 11, 207, 147, 90])
@@ -0,0 +1,70 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimLockedAndStakeAccounts {
+  claim: {
+    /** The [MerkleDistributor]. */
+    distributor: PublicKey
+    /** Claim Status PDA, derived from the leaf claimant even if the claim was transferred */
+    claimStatus: PublicKey
+    /** Distributor ATA containing the tokens to distribute. */
+    from: PublicKey
+    /**
+     * Account to send the claimed tokens to.
+     * Claimant must sign the transaction unless the distributor allows permissionless claims
+     * and this is their associated token account
+     */
+    to: PublicKey
+    /**
+     * Who is claiming the tokens.
+     * to their associated token account
+     */
+    claimant: PublicKey
+    /** The mint to distribute. */
+    mint: PublicKey
+    /** The SPL Token or Token-2022 program of the mint. */
+    tokenProgram: PublicKey
+  }
+  /** Central state of the staking program */
+  centralState: PublicKey
+  /** Stake pool of the staking program */
+  stakePool: PublicKey
+  /** The claimant's stake account */
+  stakeAccount: PublicKey
+  /** Vault of the stake pool */
+  stakeVault: PublicKey
+  /** Fee account of the staking program */
+  feeAccount: PublicKey
+  /** The staking program */
+  stakingProgram: PublicKey
+}
+
+export function claimLockedAndStake(
+  accounts: ClaimLockedAndStakeAccounts,
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.claim.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claim.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.claim.from, isSigner: false, isWritable: true },
+    { pubkey: accounts.claim.to, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.claim.claimant,
+      isSigner: claimantSigner,
+      isWritable: false,
+    },
+    { pubkey: accounts.claim.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.claim.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.centralState, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakePool, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakeAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.feeAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakingProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([173, 208, 81, 8, 13, 19, 202, 150])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,36 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimLockedSolAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim Status PDA, derived from the leaf claimant even if the claim was transferred */
+  claimStatus: PublicKey
+  /** SOL vault PDA holding the lamports to distribute. */
+  solVault: PublicKey
+  /**
+   * Who is claiming the lamports, receives them directly.
+   * the distributor allows permissionless claims
+   */
+  claimant: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export function claimLockedSol(
+  accounts: ClaimLockedSolAccounts,
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: claimantSigner, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([56, 50, 109, 28, 10, 197, 232, 169])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
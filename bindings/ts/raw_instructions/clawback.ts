@@ -15,7 +15,9 @@ export interface ClawbackAccounts {
   admin: PublicKey
   /** The [System] program. */
   systemProgram: PublicKey
-  /** SPL [Token] program. */
+  /** The mint to distribute. */
+  mint: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
   tokenProgram: PublicKey
 }
 
@@ -29,6 +31,7 @@ export function clawback(
     { pubkey: accounts.to, isSigner: false, isWritable: true },
     { pubkey: accounts.admin, isSigner: true, isWritable: true },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([111, 92, 142, 79, 33, 234, 82, 27])
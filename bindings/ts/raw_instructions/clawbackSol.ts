@@ -0,0 +1,35 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClawbackSolAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** SOL vault PDA holding the lamports to distribute. */
+  solVault: PublicKey
+  /** The clawback receiver wallet. */
+  to: PublicKey
+  /**
+   * Admin account
+   * Only admin can claw back
+   */
+  admin: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export function clawbackSol(
+  accounts: ClawbackSolAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.to, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([181, 86, 33, 24, 14, 242, 225, 244])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
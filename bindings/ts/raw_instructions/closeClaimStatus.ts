@@ -0,0 +1,26 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseClaimStatusAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim Status PDA, left behind as an empty tombstone */
+  claimStatus: PublicKey
+  /** Claimant receiving the rent refund, only they can close their claim status. */
+  claimant: PublicKey
+}
+
+export function closeClaimStatus(
+  accounts: CloseClaimStatusAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: false },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: true, isWritable: true },
+  ]
+  const identifier = Buffer.from([163, 214, 191, 165, 245, 188, 17, 185])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
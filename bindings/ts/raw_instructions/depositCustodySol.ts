@@ -0,0 +1,50 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface DepositCustodySolArgs {
+  amountLamports: BN
+  lockUntilTs: BN | null
+}
+
+export interface DepositCustodySolAccounts {
+  /** The [SolCustody] of the owner, initialized on the first deposit. */
+  solCustody: PublicKey
+  /** Custody vault PDA holding the lamports. */
+  custodyVault: PublicKey
+  /** The owner of the sol custody. */
+  owner: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("amountLamports"),
+  borsh.option(borsh.i64(), "lockUntilTs"),
+])
+
+export function depositCustodySol(
+  args: DepositCustodySolArgs,
+  accounts: DepositCustodySolAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.solCustody, isSigner: false, isWritable: true },
+    { pubkey: accounts.custodyVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([28, 165, 179, 125, 93, 145, 203, 135])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amountLamports: args.amountLamports,
+      lockUntilTs: args.lockUntilTs,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,51 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FundDistributorArgs {
+  amount: BN
+}
+
+export interface FundDistributorAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Distributor ATA containing the tokens to distribute. */
+  tokenVault: PublicKey
+  /** Creator token account the tokens are transferred from. */
+  from: PublicKey
+  /** Creator wallet */
+  creator: PublicKey
+  /** The mint to distribute. */
+  mint: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
+  tokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+export function fundDistributor(
+  args: FundDistributorArgs,
+  accounts: FundDistributorAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.from, isSigner: false, isWritable: true },
+    { pubkey: accounts.creator, isSigner: true, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([223, 255, 163, 89, 36, 250, 65, 156])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
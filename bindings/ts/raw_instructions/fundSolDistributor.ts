@@ -0,0 +1,45 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FundSolDistributorArgs {
+  amount: BN
+}
+
+export interface FundSolDistributorAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** SOL vault PDA holding the lamports to distribute. */
+  solVault: PublicKey
+  /** Creator wallet the lamports are transferred from. */
+  creator: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+export function fundSolDistributor(
+  args: FundSolDistributorArgs,
+  accounts: FundSolDistributorAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.creator, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([173, 236, 41, 250, 92, 0, 16, 2])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
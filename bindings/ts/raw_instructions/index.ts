@@ -1,12 +1,74 @@
 export { newDistributor as newDistributorRaw } from "./newDistributor"
 export type { NewDistributorArgs as NewDistributorRawArgs, NewDistributorAccounts as NewDistributorRawAccounts } from "./newDistributor"
+export { fundDistributor as fundDistributorRaw } from "./fundDistributor"
+export type { FundDistributorArgs as FundDistributorRawArgs, FundDistributorAccounts as FundDistributorRawAccounts } from "./fundDistributor"
+export { newSolDistributor as newSolDistributorRaw } from "./newSolDistributor"
+export type { NewSolDistributorArgs as NewSolDistributorRawArgs, NewSolDistributorAccounts as NewSolDistributorRawAccounts } from "./newSolDistributor"
+export { fundSolDistributor as fundSolDistributorRaw } from "./fundSolDistributor"
+export type { FundSolDistributorArgs as FundSolDistributorRawArgs, FundSolDistributorAccounts as FundSolDistributorRawAccounts } from "./fundSolDistributor"
 export { newClaim as newClaimRaw } from "./newClaim"
 export type { NewClaimArgs as NewClaimRawArgs, NewClaimAccounts as NewClaimRawAccounts } from "./newClaim"
+export { newClaimAndStake as newClaimAndStakeRaw } from "./newClaimAndStake"
+export type { NewClaimAndStakeArgs as NewClaimAndStakeRawArgs, NewClaimAndStakeAccounts as NewClaimAndStakeRawAccounts } from "./newClaimAndStake"
+export { initClaimBitmap as initClaimBitmapRaw } from "./initClaimBitmap"
+export type { InitClaimBitmapArgs as InitClaimBitmapRawArgs, InitClaimBitmapAccounts as InitClaimBitmapRawAccounts } from "./initClaimBitmap"
+export { newClaimBitmap as newClaimBitmapRaw } from "./newClaimBitmap"
+export type { NewClaimBitmapArgs as NewClaimBitmapRawArgs, NewClaimBitmapAccounts as NewClaimBitmapRawAccounts } from "./newClaimBitmap"
+export { claimAll as claimAllRaw } from "./claimAll"
+export type { ClaimAllArgs as ClaimAllRawArgs, ClaimAllAccounts as ClaimAllRawAccounts } from "./claimAll"
 export { claimLocked as claimLockedRaw } from "./claimLocked"
 export type { ClaimLockedAccounts as ClaimLockedRawAccounts } from "./claimLocked"
+export { claimLockedAndStake as claimLockedAndStakeRaw } from "./claimLockedAndStake"
+export type { ClaimLockedAndStakeAccounts as ClaimLockedAndStakeRawAccounts } from "./claimLockedAndStake"
+export { stakeLocked as stakeLockedRaw } from "./stakeLocked"
+export type { StakeLockedArgs as StakeLockedRawArgs, StakeLockedAccounts as StakeLockedRawAccounts } from "./stakeLocked"
+export { unstakeLocked as unstakeLockedRaw } from "./unstakeLocked"
+export type { UnstakeLockedArgs as UnstakeLockedRawArgs, UnstakeLockedAccounts as UnstakeLockedRawAccounts } from "./unstakeLocked"
+export { newClaimSol as newClaimSolRaw } from "./newClaimSol"
+export type { NewClaimSolArgs as NewClaimSolRawArgs, NewClaimSolAccounts as NewClaimSolRawAccounts } from "./newClaimSol"
+export { claimLockedSol as claimLockedSolRaw } from "./claimLockedSol"
+export type { ClaimLockedSolAccounts as ClaimLockedSolRawAccounts } from "./claimLockedSol"
+export { closeClaimStatus as closeClaimStatusRaw } from "./closeClaimStatus"
+export type { CloseClaimStatusAccounts as CloseClaimStatusRawAccounts } from "./closeClaimStatus"
 export { clawback as clawbackRaw } from "./clawback"
 export type { ClawbackAccounts as ClawbackRawAccounts } from "./clawback"
-export { setAdmin as setAdminRaw } from "./setAdmin"
-export type { SetAdminAccounts as SetAdminRawAccounts } from "./setAdmin"
+export { clawbackUnclaimed as clawbackUnclaimedRaw } from "./clawbackUnclaimed"
+export type { ClawbackUnclaimedAccounts as ClawbackUnclaimedRawAccounts } from "./clawbackUnclaimed"
+export { clawbackSol as clawbackSolRaw } from "./clawbackSol"
+export type { ClawbackSolAccounts as ClawbackSolRawAccounts } from "./clawbackSol"
+export { clawbackUnclaimedSol as clawbackUnclaimedSolRaw } from "./clawbackUnclaimedSol"
+export type { ClawbackUnclaimedSolAccounts as ClawbackUnclaimedSolRawAccounts } from "./clawbackUnclaimedSol"
+export { revokeClaim as revokeClaimRaw } from "./revokeClaim"
+export type { RevokeClaimAccounts as RevokeClaimRawAccounts } from "./revokeClaim"
+export { transferClaim as transferClaimRaw } from "./transferClaim"
+export type { TransferClaimAccounts as TransferClaimRawAccounts } from "./transferClaim"
+export { recoverClaim as recoverClaimRaw } from "./recoverClaim"
+export type { RecoverClaimAccounts as RecoverClaimRawAccounts } from "./recoverClaim"
+export { proposeAdmin as proposeAdminRaw } from "./proposeAdmin"
+export type { ProposeAdminAccounts as ProposeAdminRawAccounts } from "./proposeAdmin"
+export { acceptAdmin as acceptAdminRaw } from "./acceptAdmin"
+export type { AcceptAdminAccounts as AcceptAdminRawAccounts } from "./acceptAdmin"
+export { cancelAdminProposal as cancelAdminProposalRaw } from "./cancelAdminProposal"
+export type { CancelAdminProposalAccounts as CancelAdminProposalRawAccounts } from "./cancelAdminProposal"
+export { setClawbackReceiver as setClawbackReceiverRaw } from "./setClawbackReceiver"
+export type { SetClawbackReceiverAccounts as SetClawbackReceiverRawAccounts } from "./setClawbackReceiver"
+export { setStakingProgram as setStakingProgramRaw } from "./setStakingProgram"
+export type { SetStakingProgramAccounts as SetStakingProgramRawAccounts } from "./setStakingProgram"
+export { updateRoot as updateRootRaw } from "./updateRoot"
+export type { UpdateRootArgs as UpdateRootRawArgs, UpdateRootAccounts as UpdateRootRawAccounts } from "./updateRoot"
+export { addExtraMint as addExtraMintRaw } from "./addExtraMint"
+export type { AddExtraMintArgs as AddExtraMintRawArgs, AddExtraMintAccounts as AddExtraMintRawAccounts } from "./addExtraMint"
+export { migrateDistributor as migrateDistributorRaw } from "./migrateDistributor"
+export type { MigrateDistributorArgs as MigrateDistributorRawArgs, MigrateDistributorAccounts as MigrateDistributorRawAccounts } from "./migrateDistributor"
+export { migrateClaimStatus as migrateClaimStatusRaw } from "./migrateClaimStatus"
+export type { MigrateClaimStatusAccounts as MigrateClaimStatusRawAccounts } from "./migrateClaimStatus"
+export { pause as pauseRaw } from "./pause"
+export type { PauseAccounts as PauseRawAccounts } from "./pause"
+export { unpause as unpauseRaw } from "./unpause"
+export type { UnpauseAccounts as UnpauseRawAccounts } from "./unpause"
+export { setPermissionlessClaims as setPermissionlessClaimsRaw } from "./setPermissionlessClaims"
+export type { SetPermissionlessClaimsArgs as SetPermissionlessClaimsRawArgs, SetPermissionlessClaimsAccounts as SetPermissionlessClaimsRawAccounts } from "./setPermissionlessClaims"
+export { depositCustodySol as depositCustodySolRaw } from "./depositCustodySol"
+export type { DepositCustodySolArgs as DepositCustodySolRawArgs, DepositCustodySolAccounts as DepositCustodySolRawAccounts } from "./depositCustodySol"
 export { withdrawCustodySol as withdrawCustodySolRaw } from "./withdrawCustodySol"
 export type { WithdrawCustodySolArgs as WithdrawCustodySolRawArgs, WithdrawCustodySolAccounts as WithdrawCustodySolRawAccounts } from "./withdrawCustodySol"
@@ -0,0 +1,45 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface InitClaimBitmapArgs {
+  page: number
+}
+
+export interface InitClaimBitmapAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim bitmap page PDA */
+  claimBitmap: PublicKey
+  /** Pays for the claim bitmap rent, usually the distributor creator. */
+  payer: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u32("page")])
+
+export function initClaimBitmap(
+  args: InitClaimBitmapArgs,
+  accounts: InitClaimBitmapAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: false },
+    { pubkey: accounts.claimBitmap, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([81, 138, 63, 243, 239, 39, 77, 218])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      page: args.page,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
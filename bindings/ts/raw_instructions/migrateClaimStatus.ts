@@ -0,0 +1,29 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MigrateClaimStatusAccounts {
+  /** The [MerkleDistributor], migrated to the current layout first. */
+  distributor: PublicKey
+  /** The [ClaimStatus] in any layout. */
+  claimStatus: PublicKey
+  /** Pays the rent of the grown account, can be anyone as the migration only fills in defaults */
+  payer: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export function migrateClaimStatus(
+  accounts: MigrateClaimStatusAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: false },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([205, 165, 191, 224, 31, 59, 217, 11])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,51 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MigrateDistributorArgs {
+  claimEndTs: BN | null
+}
+
+export interface MigrateDistributorAccounts {
+  /** The [MerkleDistributor] in any layout. */
+  distributor: PublicKey
+  /**
+   * Distributor ATA containing the tokens to distribute, its balance decides whether the
+   * distributor is funded.
+   */
+  tokenVault: PublicKey
+  /** Token account of the distributor's mint the unclaimed tokens are clawed back to */
+  clawbackReceiver: PublicKey
+  /** Admin signer, pays the rent of the grown account */
+  admin: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.option(borsh.i64(), "claimEndTs")])
+
+export function migrateDistributor(
+  args: MigrateDistributorArgs,
+  accounts: MigrateDistributorAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.clawbackReceiver, isSigner: false, isWritable: false },
+    { pubkey: accounts.admin, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([64, 53, 16, 171, 17, 224, 4, 40])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      claimEndTs: args.claimEndTs,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
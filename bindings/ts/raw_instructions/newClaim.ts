@@ -1,12 +1,16 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface NewClaimArgs {
   amountUnlocked: BN
   amountLocked: BN
+  startVestingTs: BN | null
+  endVestingTs: BN | null
   proof: Array<Array<number>>
+  extraAmounts: Array<types.ExtraLeafAmountFields>
 }
 
 export interface NewClaimAccounts {
@@ -18,9 +22,16 @@ export interface NewClaimAccounts {
   from: PublicKey
   /** Account to send the claimed tokens to. */
   to: PublicKey
-  /** Who is claiming the tokens. */
+  /**
+   * Who is claiming the tokens.
+   * to their associated token account
+   */
   claimant: PublicKey
-  /** SPL [Token] program. */
+  /** Pays for the claim status rent, can be the claimant or a relayer. */
+  payer: PublicKey
+  /** The mint to distribute. */
+  mint: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
   tokenProgram: PublicKey
   /** The [System] program. */
   systemProgram: PublicKey
@@ -29,20 +40,26 @@ export interface NewClaimAccounts {
 export const layout = borsh.struct([
   borsh.u64("amountUnlocked"),
   borsh.u64("amountLocked"),
+  borsh.option(borsh.i64(), "startVestingTs"),
+  borsh.option(borsh.i64(), "endVestingTs"),
   borsh.vec(borsh.array(borsh.u8(), 32), "proof"),
+  borsh.vec(types.ExtraLeafAmount.layout(), "extraAmounts"),
 ])
 
 export function newClaim(
   args: NewClaimArgs,
   accounts: NewClaimAccounts,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
 ) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.distributor, isSigner: false, isWritable: true },
     { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
     { pubkey: accounts.from, isSigner: false, isWritable: true },
     { pubkey: accounts.to, isSigner: false, isWritable: true },
-    { pubkey: accounts.claimant, isSigner: true, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: claimantSigner, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
   ]
@@ -52,7 +69,12 @@ export function newClaim(
     {
       amountUnlocked: args.amountUnlocked,
       amountLocked: args.amountLocked,
+      startVestingTs: args.startVestingTs,
+      endVestingTs: args.endVestingTs,
       proof: args.proof,
+      extraAmounts: args.extraAmounts.map(
+        (item) => types.ExtraLeafAmount.toEncodable(item)
+      ),
     },
     buffer
   )
@@ -0,0 +1,112 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NewClaimAndStakeArgs {
+  amountUnlocked: BN
+  amountLocked: BN
+  startVestingTs: BN | null
+  endVestingTs: BN | null
+  proof: Array<Array<number>>
+  extraAmounts: Array<types.ExtraLeafAmountFields>
+}
+
+export interface NewClaimAndStakeAccounts {
+  claim: {
+    /** The [MerkleDistributor]. */
+    distributor: PublicKey
+    /** Claim status PDA */
+    claimStatus: PublicKey
+    /** Distributor ATA containing the tokens to distribute. */
+    from: PublicKey
+    /** Account to send the claimed tokens to. */
+    to: PublicKey
+    /**
+     * Who is claiming the tokens.
+     * to their associated token account
+     */
+    claimant: PublicKey
+    /** Pays for the claim status rent, can be the claimant or a relayer. */
+    payer: PublicKey
+    /** The mint to distribute. */
+    mint: PublicKey
+    /** The SPL Token or Token-2022 program of the mint. */
+    tokenProgram: PublicKey
+    /** The [System] program. */
+    systemProgram: PublicKey
+  }
+  /** Central state of the staking program */
+  centralState: PublicKey
+  /** Stake pool of the staking program */
+  stakePool: PublicKey
+  /** The claimant's stake account */
+  stakeAccount: PublicKey
+  /** Vault of the stake pool */
+  stakeVault: PublicKey
+  /** Fee account of the staking program */
+  feeAccount: PublicKey
+  /** The staking program */
+  stakingProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("amountUnlocked"),
+  borsh.u64("amountLocked"),
+  borsh.option(borsh.i64(), "startVestingTs"),
+  borsh.option(borsh.i64(), "endVestingTs"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "proof"),
+  borsh.vec(types.ExtraLeafAmount.layout(), "extraAmounts"),
+])
+
+export function newClaimAndStake(
+  args: NewClaimAndStakeArgs,
+  accounts: NewClaimAndStakeAccounts,
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.claim.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claim.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.claim.from, isSigner: false, isWritable: true },
+    { pubkey: accounts.claim.to, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.claim.claimant,
+      isSigner: claimantSigner,
+      isWritable: false,
+    },
+    { pubkey: accounts.claim.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.claim.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.claim.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.claim.systemProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.centralState, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakePool, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakeAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.feeAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakingProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([50, 111, 242, 118, 51, 250, 141, 187])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amountUnlocked: args.amountUnlocked,
+      amountLocked: args.amountLocked,
+      startVestingTs: args.startVestingTs,
+      endVestingTs: args.endVestingTs,
+      proof: args.proof,
+      extraAmounts: args.extraAmounts.map(
+        (item) => types.ExtraLeafAmount.toEncodable(item)
+      ),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,66 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NewClaimBitmapArgs {
+  index: BN
+  amountUnlocked: BN
+  proof: Array<Array<number>>
+}
+
+export interface NewClaimBitmapAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim bitmap page PDA holding the leaf's bit */
+  claimBitmap: PublicKey
+  /** Distributor ATA containing the tokens to distribute. */
+  from: PublicKey
+  /** Account to send the claimed tokens to. */
+  to: PublicKey
+  /**
+   * Who is claiming the tokens.
+   * to their associated token account
+   */
+  claimant: PublicKey
+  /** The mint to distribute. */
+  mint: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
+  tokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("index"),
+  borsh.u64("amountUnlocked"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "proof"),
+])
+
+export function newClaimBitmap(
+  args: NewClaimBitmapArgs,
+  accounts: NewClaimBitmapAccounts,
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimBitmap, isSigner: false, isWritable: true },
+    { pubkey: accounts.from, isSigner: false, isWritable: true },
+    { pubkey: accounts.to, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: claimantSigner, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([151, 171, 237, 57, 219, 57, 43, 179])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      index: args.index,
+      amountUnlocked: args.amountUnlocked,
+      proof: args.proof,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,69 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NewClaimSolArgs {
+  amountUnlocked: BN
+  amountLocked: BN
+  startVestingTs: BN | null
+  endVestingTs: BN | null
+  proof: Array<Array<number>>
+}
+
+export interface NewClaimSolAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim status PDA */
+  claimStatus: PublicKey
+  /** SOL vault PDA holding the lamports to distribute. */
+  solVault: PublicKey
+  /**
+   * Who is claiming the lamports, receives them directly.
+   * the distributor allows permissionless claims
+   */
+  claimant: PublicKey
+  /** Pays for the claim status rent, can be the claimant or a relayer. */
+  payer: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("amountUnlocked"),
+  borsh.u64("amountLocked"),
+  borsh.option(borsh.i64(), "startVestingTs"),
+  borsh.option(borsh.i64(), "endVestingTs"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "proof"),
+])
+
+export function newClaimSol(
+  args: NewClaimSolArgs,
+  accounts: NewClaimSolAccounts,
+  programId: PublicKey = PROGRAM_ID,
+  claimantSigner: boolean = true
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: claimantSigner, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([137, 0, 189, 193, 166, 44, 191, 83])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amountUnlocked: args.amountUnlocked,
+      amountLocked: args.amountLocked,
+      startVestingTs: args.startVestingTs,
+      endVestingTs: args.endVestingTs,
+      proof: args.proof,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
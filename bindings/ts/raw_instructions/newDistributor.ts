@@ -1,6 +1,7 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface NewDistributorArgs {
@@ -10,6 +11,14 @@ export interface NewDistributorArgs {
   maxNumNodes: BN
   startVestingTs: BN
   endVestingTs: BN
+  cliffVestingTs: BN
+  cliffPercentage: number
+  vestingMode: types.VestingModeKind
+  clawbackStartTs: BN
+  claimBitmap: boolean
+  claimEndTs: BN
+  revocable: boolean
+  recoverable: boolean
 }
 
 export interface NewDistributorAccounts {
@@ -17,18 +26,17 @@ export interface NewDistributorAccounts {
   distributor: PublicKey
   /** The mint to distribute. */
   mint: PublicKey
-  /** Token vault */
+  /** Token vault, the distributor's associated token account */
   tokenVault: PublicKey
-  /**
-   * Creator wallet, responsible for creating the distributor and paying for the transaction.
-   * Also is set as the admin
-   */
+  /** Clawback receiver token account */
+  clawbackReceiver: PublicKey
+  /** Creator wallet, responsible for creating the distributor and paying for the transaction. */
   creator: PublicKey
   /** The [System] program. */
   systemProgram: PublicKey
   /** The [Associated Token] program. */
   associatedTokenProgram: PublicKey
-  /** The [Token] program. */
+  /** The SPL Token or Token-2022 program of the mint. */
   tokenProgram: PublicKey
 }
 
@@ -39,12 +47,16 @@ export const layout = borsh.struct([
   borsh.u64("maxNumNodes"),
   borsh.i64("startVestingTs"),
   borsh.i64("endVestingTs"),
+  borsh.i64("cliffVestingTs"),
+  borsh.u8("cliffPercentage"),
+  types.VestingMode.layout("vestingMode"),
+  borsh.i64("clawbackStartTs"),
+  borsh.bool("claimBitmap"),
+  borsh.i64("claimEndTs"),
+  borsh.bool("revocable"),
+  borsh.bool("recoverable"),
 ])
 
-/**
- * Creates a new MerkleDistributor.
- * After creating this MerkleDistributor, the token_vault should be seeded with max_total_claim tokens.
- */
 export function newDistributor(
   args: NewDistributorArgs,
   accounts: NewDistributorAccounts,
@@ -54,6 +66,7 @@ export function newDistributor(
     { pubkey: accounts.distributor, isSigner: false, isWritable: true },
     { pubkey: accounts.mint, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.clawbackReceiver, isSigner: false, isWritable: false },
     { pubkey: accounts.creator, isSigner: true, isWritable: true },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
     {
@@ -73,6 +86,14 @@ export function newDistributor(
       maxNumNodes: args.maxNumNodes,
       startVestingTs: args.startVestingTs,
       endVestingTs: args.endVestingTs,
+      cliffVestingTs: args.cliffVestingTs,
+      cliffPercentage: args.cliffPercentage,
+      vestingMode: args.vestingMode.toEncodable(),
+      clawbackStartTs: args.clawbackStartTs,
+      claimBitmap: args.claimBitmap,
+      claimEndTs: args.claimEndTs,
+      revocable: args.revocable,
+      recoverable: args.recoverable,
     },
     buffer
   )
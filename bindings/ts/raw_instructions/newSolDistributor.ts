@@ -0,0 +1,87 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NewSolDistributorArgs {
+  version: BN
+  root: Array<number>
+  maxTotalClaim: BN
+  maxNumNodes: BN
+  startVestingTs: BN
+  endVestingTs: BN
+  cliffVestingTs: BN
+  cliffPercentage: number
+  vestingMode: types.VestingModeKind
+  clawbackStartTs: BN
+  claimEndTs: BN
+  recoverable: boolean
+}
+
+export interface NewSolDistributorAccounts {
+  /**
+   * [MerkleDistributor], derived with its own seed so it can't collide with a wrapped SOL
+   * token distributor of the same creator and version.
+   */
+  distributor: PublicKey
+  /** SOL vault PDA holding the lamports to distribute. */
+  solVault: PublicKey
+  /** Clawback receiver wallet */
+  clawbackReceiver: PublicKey
+  /** Creator wallet, responsible for creating the distributor and paying for the transaction. */
+  creator: PublicKey
+  /** The [System] program. */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("version"),
+  borsh.array(borsh.u8(), 32, "root"),
+  borsh.u64("maxTotalClaim"),
+  borsh.u64("maxNumNodes"),
+  borsh.i64("startVestingTs"),
+  borsh.i64("endVestingTs"),
+  borsh.i64("cliffVestingTs"),
+  borsh.u8("cliffPercentage"),
+  types.VestingMode.layout("vestingMode"),
+  borsh.i64("clawbackStartTs"),
+  borsh.i64("claimEndTs"),
+  borsh.bool("recoverable"),
+])
+
+export function newSolDistributor(
+  args: NewSolDistributorArgs,
+  accounts: NewSolDistributorAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.solVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.clawbackReceiver, isSigner: false, isWritable: false },
+    { pubkey: accounts.creator, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([10, 65, 142, 2, 188, 64, 253, 172])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      version: args.version,
+      root: args.root,
+      maxTotalClaim: args.maxTotalClaim,
+      maxNumNodes: args.maxNumNodes,
+      startVestingTs: args.startVestingTs,
+      endVestingTs: args.endVestingTs,
+      cliffVestingTs: args.cliffVestingTs,
+      cliffPercentage: args.cliffPercentage,
+      vestingMode: args.vestingMode.toEncodable(),
+      clawbackStartTs: args.clawbackStartTs,
+      claimEndTs: args.claimEndTs,
+      recoverable: args.recoverable,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
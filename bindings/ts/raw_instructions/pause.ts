@@ -0,0 +1,23 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PauseAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+}
+
+export function pause(
+  accounts: PauseAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([211, 22, 221, 251, 74, 121, 193, 47])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -1,25 +1,25 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
-export interface SetAdminAccounts {
+export interface ProposeAdminAccounts {
   /** The [MerkleDistributor]. */
   distributor: PublicKey
   /** Admin signer */
   admin: PublicKey
-  /** New admin account */
+  /** Proposed new admin account */
   newAdmin: PublicKey
 }
 
-export function setAdmin(
-  accounts: SetAdminAccounts,
+export function proposeAdmin(
+  accounts: ProposeAdminAccounts,
   programId: PublicKey = PROGRAM_ID
 ) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.distributor, isSigner: false, isWritable: true },
     { pubkey: accounts.admin, isSigner: true, isWritable: true },
-    { pubkey: accounts.newAdmin, isSigner: false, isWritable: true },
+    { pubkey: accounts.newAdmin, isSigner: false, isWritable: false },
   ]
-  const identifier = Buffer.from([251, 163, 0, 52, 91, 194, 187, 92])
+  const identifier = Buffer.from([121, 214, 199, 212, 87, 39, 117, 234])
   const data = identifier
   const ix = new TransactionInstruction({ keys, programId, data })
   return ix
@@ -0,0 +1,29 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RecoverClaimAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim Status PDA, derived from the leaf claimant even if the claim was transferred */
+  claimStatus: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+  /** New wallet of the claimant, signs to prove it controls the key */
+  newClaimant: PublicKey
+}
+
+export function recoverClaim(
+  accounts: RecoverClaimAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: false },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.newClaimant, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([51, 100, 228, 196, 217, 160, 185, 96])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,38 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RevokeClaimAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim status PDA of the claim to revoke */
+  claimStatus: PublicKey
+  /** Distributor ATA containing the tokens to distribute. */
+  from: PublicKey
+  /** The Clawback token account. */
+  to: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+  /** The mint to distribute. */
+  mint: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
+  tokenProgram: PublicKey
+}
+
+export function revokeClaim(
+  accounts: RevokeClaimAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.from, isSigner: false, isWritable: true },
+    { pubkey: accounts.to, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([182, 1, 142, 33, 207, 153, 37, 132])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetPermissionlessClaimsArgs {
+  permissionlessClaims: boolean
+}
+
+export interface SetPermissionlessClaimsAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+}
+
+export const layout = borsh.struct([borsh.bool("permissionlessClaims")])
+
+export function setPermissionlessClaims(
+  args: SetPermissionlessClaimsArgs,
+  accounts: SetPermissionlessClaimsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([221, 173, 7, 234, 225, 47, 242, 207])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      permissionlessClaims: args.permissionlessClaims,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,29 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetStakingProgramAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Staking program claimed tokens are staked into */
+  stakingProgram: PublicKey
+  /** Stake pool of the staking program */
+  stakePool: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+}
+
+export function setStakingProgram(
+  accounts: SetStakingProgramAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakingProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.stakePool, isSigner: false, isWritable: false },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([41, 110, 167, 83, 206, 6, 245, 21])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
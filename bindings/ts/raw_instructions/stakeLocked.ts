@@ -0,0 +1,72 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface StakeLockedArgs {
+  amount: BN
+}
+
+export interface StakeLockedAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim Status PDA, derived from the leaf claimant even if the claim was transferred */
+  claimStatus: PublicKey
+  /** Distributor ATA holding the idle locked tokens. */
+  tokenVault: PublicKey
+  /** The claimant, or the admin when unstaking */
+  authority: PublicKey
+  /** The SPL Token or Token-2022 program of the mint. */
+  tokenProgram: PublicKey
+  /** Central state of the staking program */
+  centralState: PublicKey
+  /** Stake pool of the staking program */
+  stakePool: PublicKey
+  /**
+   * Owner of the claim's stake account, only ever delegated the amount being staked
+   * owner of the stake account
+   */
+  stakeAuthority: PublicKey
+  /** The claim's stake account, the stake authority's in the distributor's stake pool */
+  stakeAccount: PublicKey
+  /** Vault of the stake pool */
+  stakeVault: PublicKey
+  /** Fee account of the staking program */
+  feeAccount: PublicKey
+  /** The staking program */
+  stakingProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+export function stakeLocked(
+  args: StakeLockedArgs,
+  accounts: StakeLockedAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.centralState, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakePool, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakeAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.stakeAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.feeAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.stakingProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([165, 31, 139, 41, 64, 105, 46, 13])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
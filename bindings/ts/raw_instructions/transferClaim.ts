@@ -0,0 +1,29 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface TransferClaimAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Claim Status PDA, derived from the leaf claimant even if the claim was transferred */
+  claimStatus: PublicKey
+  /** Current claimant */
+  claimant: PublicKey
+  /** New claimant the claim is transferred to. */
+  newClaimant: PublicKey
+}
+
+export function transferClaim(
+  accounts: TransferClaimAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: false },
+    { pubkey: accounts.claimStatus, isSigner: false, isWritable: true },
+    { pubkey: accounts.claimant, isSigner: true, isWritable: false },
+    { pubkey: accounts.newClaimant, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([190, 229, 135, 67, 144, 145, 202, 253])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,23 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UnpauseAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+}
+
+export function unpause(
+  accounts: UnpauseAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([169, 144, 4, 38, 10, 141, 188, 255])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
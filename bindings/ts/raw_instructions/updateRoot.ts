@@ -0,0 +1,47 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UpdateRootArgs {
+  root: Array<number>
+  maxTotalClaim: BN
+  maxNumNodes: BN
+}
+
+export interface UpdateRootAccounts {
+  /** The [MerkleDistributor]. */
+  distributor: PublicKey
+  /** Admin signer */
+  admin: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "root"),
+  borsh.u64("maxTotalClaim"),
+  borsh.u64("maxNumNodes"),
+])
+
+export function updateRoot(
+  args: UpdateRootArgs,
+  accounts: UpdateRootAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.distributor, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([58, 195, 57, 246, 116, 198, 170, 138])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      root: args.root,
+      maxTotalClaim: args.maxTotalClaim,
+      maxNumNodes: args.maxNumNodes,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -10,10 +10,12 @@ export interface WithdrawCustodySolArgs {
 }
 
 export interface WithdrawCustodySolAccounts {
-  /** [SolCustody].\ */
-  solCustody: PublicKey
+  /** Custody vault PDA holding the lamports. */
+  custodyVault: PublicKey
   /** The owner of the sol custody. */
   owner: PublicKey
+  /** The [SolCustody] of the owner, initialized for vaults funded before it existed. */
+  solCustody: PublicKey
 }
 
 export const layout = borsh.struct([borsh.u64("amountLamports")])
@@ -23,15 +25,16 @@ export function withdrawCustodySol(
   programId: PublicKey = PROGRAM_ID
 ) {
 
-  const solCustody = SolCustody.getAddress(args.owner, programId)
   const accounts: WithdrawCustodySolAccounts = {
-    solCustody: solCustody,
+    custodyVault: SolCustody.getVaultAddress(args.owner, programId),
     owner: args.owner,
+    solCustody: SolCustody.getAddress(args.owner, programId),
   }
   const keys: Array<AccountMeta> = [
-    { pubkey: accounts.solCustody, isSigner: false, isWritable: true },
+    { pubkey: accounts.custodyVault, isSigner: false, isWritable: true },
     { pubkey: accounts.owner, isSigner: true, isWritable: true },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    { pubkey: accounts.solCustody, isSigner: false, isWritable: true },
   ]
   const identifier = Buffer.from([118, 134, 84, 39, 59, 124, 217, 154])
   const buffer = Buffer.alloc(1000)
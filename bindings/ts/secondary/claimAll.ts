@@ -0,0 +1,37 @@
+import { PublicKey, SystemProgram } from "@solana/web3.js"
+import BN from "bn.js"
+import { PROGRAM_ID } from "../programId"
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token"
+import { getAssociatedTokenAddressSync } from "@solana/spl-token"
+import { ClaimStatus } from "../accounts/ClaimStatus"
+import { claimAllRaw } from "../raw_instructions"
+
+/**
+ * Creates a single instruction claiming the unlocked and all vested locked tokens.
+ * Creates the claim status on the first claim, later calls only withdraw newly vested tokens.
+ * The vesting timestamps are only set for leaves with their own vesting schedule.
+ */
+export function claimAll(
+  amountUnlocked: BN,
+  amountLocked: BN,
+  proof: Array<Array<number>>,
+  distributor: PublicKey,
+  claimant: PublicKey,
+  mint: PublicKey,
+  startVestingTs: BN | null = null,
+  endVestingTs: BN | null = null,
+  payer: PublicKey = claimant,
+  programId: PublicKey = PROGRAM_ID,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
+  systemProgram: PublicKey = SystemProgram.programId,
+) {
+  const claimStatus = ClaimStatus.getAddress(claimant, distributor, programId)
+  const from = getAssociatedTokenAddressSync(mint, distributor, true, tokenProgram)
+  const to = getAssociatedTokenAddressSync(mint, claimant, true, tokenProgram)
+
+  return claimAllRaw(
+    { amountUnlocked, amountLocked, startVestingTs, endVestingTs, proof },
+    { distributor, claimStatus, from, to, claimant, payer, mint, tokenProgram, systemProgram },
+    programId
+  )
+}
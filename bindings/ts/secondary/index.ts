@@ -1,3 +1,4 @@
 export { newDistributor } from "./newDistrbutor"
 export { stakeCustodySol } from "./stakeCustodySol"
-export { newClaim } from "./newClaim"
\ No newline at end of file
+export { newClaim } from "./newClaim"
+export { claimAll } from "./claimAll"
\ No newline at end of file
@@ -3,11 +3,13 @@ import BN from "bn.js"
 import { PROGRAM_ID } from "../programId"
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token"
 import { getAssociatedTokenAddressSync } from "@solana/spl-token"
+import { ClaimStatus } from "../accounts/ClaimStatus"
 import { newClaimRaw, claimLockedRaw } from "../raw_instructions"
 
 /**
  * Creates claim instructions for both unlocked and locked tokens.
  * Returns both instructions if both amounts are > 0, otherwise returns the relevant one.
+ * The vesting timestamps are only set for leaves with their own vesting schedule.
  */
 export function newClaim(
   amountUnlocked: BN,
@@ -16,26 +18,22 @@ export function newClaim(
   distributor: PublicKey,
   claimant: PublicKey,
   mint: PublicKey,
+  startVestingTs: BN | null = null,
+  endVestingTs: BN | null = null,
+  payer: PublicKey = claimant,
   programId: PublicKey = PROGRAM_ID,
   tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
   systemProgram: PublicKey = SystemProgram.programId,
 ) {
 
   // Get claim status PDA
-  const claimStatus = PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("ClaimStatus"),
-      claimant.toBuffer(),
-      distributor.toBuffer(),
-    ],
-    programId
-  )[0]
+  const claimStatus = ClaimStatus.getAddress(claimant, distributor, programId)
 
   // Get distributor's token vault
-  const from = getAssociatedTokenAddressSync(mint, distributor, true)
+  const from = getAssociatedTokenAddressSync(mint, distributor, true, tokenProgram)
 
   // Get claimant's token account
-  const to = getAssociatedTokenAddressSync(mint, claimant, true)
+  const to = getAssociatedTokenAddressSync(mint, claimant, true, tokenProgram)
 
   const instructions: TransactionInstruction[] = []
 
@@ -46,7 +44,10 @@ export function newClaim(
         {
           amountUnlocked,
           amountLocked,
+          startVestingTs,
+          endVestingTs,
           proof,
+          extraAmounts: [],
         },
         {
           distributor,
@@ -54,6 +55,8 @@ export function newClaim(
           from,
           to,
           claimant,
+          payer,
+          mint,
           tokenProgram,
           systemProgram,
         },
@@ -72,6 +75,7 @@ export function newClaim(
           from,
           to,
           claimant: claimant,
+          mint,
           tokenProgram,
         },
         programId
@@ -80,4 +84,4 @@ export function newClaim(
   }
 
   return instructions
-}
\ No newline at end of file
+}
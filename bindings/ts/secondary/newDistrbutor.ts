@@ -6,10 +6,13 @@ import { TOKEN_PROGRAM_ID } from "@solana/spl-token"
 import { MerkleDistributor } from "../accounts/MerkleDistributor"
 import { getAssociatedTokenAddressSync } from "@solana/spl-token"
 import { newDistributorRaw } from "../raw_instructions"
+import * as types from "../types"
 
 /**
  * Creates a new MerkleDistributor.
- * After creating this MerkleDistributor, the token_vault should be seeded with max_total_claim tokens.
+ * After creating this MerkleDistributor, the token_vault should be seeded with max_total_claim tokens
+ * through fund_distributor, claims are rejected until then.
+ * The token vault, the distributor's associated token account, has to exist beforehand.
  */
 export function newDistributor(
     version: BN,
@@ -18,19 +21,43 @@ export function newDistributor(
     maxNumNodes: BN,
     startVestingTs: BN,
     endVestingTs: BN,
+    cliffVestingTs: BN,
+    cliffPercentage: number,
+    vestingMode: types.VestingModeKind,
+    clawbackStartTs: BN,
+    claimEndTs: BN,
     mint: PublicKey,
     creator: PublicKey,
+    clawbackReceiver: PublicKey,
+    claimBitmap: boolean = false,
+    revocable: boolean = false,
+    recoverable: boolean = false,
     programId: PublicKey = PROGRAM_ID,
     systemProgram: PublicKey = SystemProgram.programId,
     associatedTokenProgram: PublicKey = ASSOCIATED_TOKEN_PROGRAM_ID,
     tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
 ) {
     const distributor = MerkleDistributor.getAddress(creator, mint, version, programId)
-    const tokenVault = getAssociatedTokenAddressSync(mint, distributor, true)
+    const tokenVault = getAssociatedTokenAddressSync(mint, distributor, true, tokenProgram)
 
     return newDistributorRaw(
-        { version, root, maxTotalClaim, maxNumNodes, startVestingTs, endVestingTs },
-        { distributor, mint, tokenVault, creator, systemProgram, associatedTokenProgram, tokenProgram },
+        {
+            version,
+            root,
+            maxTotalClaim,
+            maxNumNodes,
+            startVestingTs,
+            endVestingTs,
+            cliffVestingTs,
+            cliffPercentage,
+            vestingMode,
+            clawbackStartTs,
+            claimBitmap,
+            claimEndTs,
+            revocable,
+            recoverable,
+        },
+        { distributor, mint, tokenVault, clawbackReceiver, creator, systemProgram, associatedTokenProgram, tokenProgram },
         programId
     )
 }
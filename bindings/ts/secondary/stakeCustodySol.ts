@@ -1,18 +1,27 @@
-import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
-import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { TransactionInstruction, PublicKey, SystemProgram } from "@solana/web3.js"
+import BN from "bn.js"
 import { PROGRAM_ID } from "../programId"
-import { SystemProgram } from "@solana/web3.js"
 import { SolCustody } from "../accounts/SolCustody"
+import { depositCustodySolRaw } from "../raw_instructions"
 
+/**
+ * Deposits lamports of the owner into its SOL custody through deposit_custody_sol, so they are
+ * tracked in the custody balance and can be withdrawn again with withdraw_custody_sol.
+ * Withdrawals are locked until lockUntilTs if set, an existing lock can only be extended.
+ */
 export function stakeCustodySol(
   amountLamports: BN,
   owner: PublicKey,
-  programId: PublicKey = PROGRAM_ID
+  lockUntilTs: BN | null = null,
+  programId: PublicKey = PROGRAM_ID,
+  systemProgram: PublicKey = SystemProgram.programId,
 ): TransactionInstruction {
   const solCustody = SolCustody.getAddress(owner, programId)
-  return SystemProgram.transfer({
-    fromPubkey: owner,
-    toPubkey: solCustody,
-    lamports: amountLamports.toNumber(),
-  })
+  const custodyVault = SolCustody.getVaultAddress(owner, programId)
+
+  return depositCustodySolRaw(
+    { amountLamports, lockUntilTs },
+    { solCustody, custodyVault, owner, systemProgram },
+    programId
+  )
 }
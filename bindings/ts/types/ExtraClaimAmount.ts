@@ -0,0 +1,89 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ExtraClaimAmountFields {
+  /** Locked amount */
+  lockedAmount: BN
+  /** Locked amount withdrawn */
+  lockedAmountWithdrawn: BN
+  /** Unlocked amount */
+  unlockedAmount: BN
+}
+
+export interface ExtraClaimAmountJSON {
+  /** Locked amount */
+  lockedAmount: string
+  /** Locked amount withdrawn */
+  lockedAmountWithdrawn: string
+  /** Unlocked amount */
+  unlockedAmount: string
+}
+
+/**
+ * Amounts of an extra mint in a [crate::state::claim_status::ClaimStatus], vesting on the
+ * claim's schedule.
+ */
+export class ExtraClaimAmount {
+  /** Locked amount */
+  readonly lockedAmount: BN
+  /** Locked amount withdrawn */
+  readonly lockedAmountWithdrawn: BN
+  /** Unlocked amount */
+  readonly unlockedAmount: BN
+
+  constructor(fields: ExtraClaimAmountFields) {
+    this.lockedAmount = fields.lockedAmount
+    this.lockedAmountWithdrawn = fields.lockedAmountWithdrawn
+    this.unlockedAmount = fields.unlockedAmount
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("lockedAmount"),
+        borsh.u64("lockedAmountWithdrawn"),
+        borsh.u64("unlockedAmount"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ExtraClaimAmount({
+      lockedAmount: obj.lockedAmount,
+      lockedAmountWithdrawn: obj.lockedAmountWithdrawn,
+      unlockedAmount: obj.unlockedAmount,
+    })
+  }
+
+  static toEncodable(fields: ExtraClaimAmountFields) {
+    return {
+      lockedAmount: fields.lockedAmount,
+      lockedAmountWithdrawn: fields.lockedAmountWithdrawn,
+      unlockedAmount: fields.unlockedAmount,
+    }
+  }
+
+  toJSON(): ExtraClaimAmountJSON {
+    return {
+      lockedAmount: this.lockedAmount.toString(),
+      lockedAmountWithdrawn: this.lockedAmountWithdrawn.toString(),
+      unlockedAmount: this.unlockedAmount.toString(),
+    }
+  }
+
+  static fromJSON(obj: ExtraClaimAmountJSON): ExtraClaimAmount {
+    return new ExtraClaimAmount({
+      lockedAmount: new BN(obj.lockedAmount),
+      lockedAmountWithdrawn: new BN(obj.lockedAmountWithdrawn),
+      unlockedAmount: new BN(obj.unlockedAmount),
+    })
+  }
+
+  toEncodable() {
+    return ExtraClaimAmount.toEncodable(this)
+  }
+}
@@ -0,0 +1,71 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ExtraLeafAmountFields {
+  /** Amount unlocked */
+  amountUnlocked: BN
+  /** Amount locked */
+  amountLocked: BN
+}
+
+export interface ExtraLeafAmountJSON {
+  /** Amount unlocked */
+  amountUnlocked: string
+  /** Amount locked */
+  amountLocked: string
+}
+
+/** Amounts of an extra mint in a merkle leaf. */
+export class ExtraLeafAmount {
+  /** Amount unlocked */
+  readonly amountUnlocked: BN
+  /** Amount locked */
+  readonly amountLocked: BN
+
+  constructor(fields: ExtraLeafAmountFields) {
+    this.amountUnlocked = fields.amountUnlocked
+    this.amountLocked = fields.amountLocked
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u64("amountUnlocked"), borsh.u64("amountLocked")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ExtraLeafAmount({
+      amountUnlocked: obj.amountUnlocked,
+      amountLocked: obj.amountLocked,
+    })
+  }
+
+  static toEncodable(fields: ExtraLeafAmountFields) {
+    return {
+      amountUnlocked: fields.amountUnlocked,
+      amountLocked: fields.amountLocked,
+    }
+  }
+
+  toJSON(): ExtraLeafAmountJSON {
+    return {
+      amountUnlocked: this.amountUnlocked.toString(),
+      amountLocked: this.amountLocked.toString(),
+    }
+  }
+
+  static fromJSON(obj: ExtraLeafAmountJSON): ExtraLeafAmount {
+    return new ExtraLeafAmount({
+      amountUnlocked: new BN(obj.amountUnlocked),
+      amountLocked: new BN(obj.amountLocked),
+    })
+  }
+
+  toEncodable() {
+    return ExtraLeafAmount.toEncodable(this)
+  }
+}
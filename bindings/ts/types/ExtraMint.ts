@@ -0,0 +1,98 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ExtraMintFields {
+  /** [Mint] of the token to be distributed. */
+  mint: PublicKey
+  /** Token Address of the vault, the distributor's associated token account */
+  tokenVault: PublicKey
+  /** Maximum number of tokens of this mint that can ever be claimed. */
+  maxTotalClaim: BN
+  /** Total amount of tokens of this mint that have been claimed. */
+  totalAmountClaimed: BN
+}
+
+export interface ExtraMintJSON {
+  /** [Mint] of the token to be distributed. */
+  mint: string
+  /** Token Address of the vault, the distributor's associated token account */
+  tokenVault: string
+  /** Maximum number of tokens of this mint that can ever be claimed. */
+  maxTotalClaim: string
+  /** Total amount of tokens of this mint that have been claimed. */
+  totalAmountClaimed: string
+}
+
+/** A mint distributed alongside the distributor's mint, every leaf carries an amount of it. */
+export class ExtraMint {
+  /** [Mint] of the token to be distributed. */
+  readonly mint: PublicKey
+  /** Token Address of the vault, the distributor's associated token account */
+  readonly tokenVault: PublicKey
+  /** Maximum number of tokens of this mint that can ever be claimed. */
+  readonly maxTotalClaim: BN
+  /** Total amount of tokens of this mint that have been claimed. */
+  readonly totalAmountClaimed: BN
+
+  constructor(fields: ExtraMintFields) {
+    this.mint = fields.mint
+    this.tokenVault = fields.tokenVault
+    this.maxTotalClaim = fields.maxTotalClaim
+    this.totalAmountClaimed = fields.totalAmountClaimed
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("mint"),
+        borsh.publicKey("tokenVault"),
+        borsh.u64("maxTotalClaim"),
+        borsh.u64("totalAmountClaimed"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ExtraMint({
+      mint: obj.mint,
+      tokenVault: obj.tokenVault,
+      maxTotalClaim: obj.maxTotalClaim,
+      totalAmountClaimed: obj.totalAmountClaimed,
+    })
+  }
+
+  static toEncodable(fields: ExtraMintFields) {
+    return {
+      mint: fields.mint,
+      tokenVault: fields.tokenVault,
+      maxTotalClaim: fields.maxTotalClaim,
+      totalAmountClaimed: fields.totalAmountClaimed,
+    }
+  }
+
+  toJSON(): ExtraMintJSON {
+    return {
+      mint: this.mint.toString(),
+      tokenVault: this.tokenVault.toString(),
+      maxTotalClaim: this.maxTotalClaim.toString(),
+      totalAmountClaimed: this.totalAmountClaimed.toString(),
+    }
+  }
+
+  static fromJSON(obj: ExtraMintJSON): ExtraMint {
+    return new ExtraMint({
+      mint: new PublicKey(obj.mint),
+      tokenVault: new PublicKey(obj.tokenVault),
+      maxTotalClaim: new BN(obj.maxTotalClaim),
+      totalAmountClaimed: new BN(obj.totalAmountClaimed),
+    })
+  }
+
+  toEncodable() {
+    return ExtraMint.toEncodable(this)
+  }
+}
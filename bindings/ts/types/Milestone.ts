@@ -0,0 +1,68 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface MilestoneFields {
+  /** Seconds after the lockup start at which this milestone is reached */
+  offset: BN
+  /** Cumulative percentage of the locked amount unlocked once this milestone is reached */
+  percentage: number
+}
+
+export interface MilestoneJSON {
+  /** Seconds after the lockup start at which this milestone is reached */
+  offset: string
+  /** Cumulative percentage of the locked amount unlocked once this milestone is reached */
+  percentage: number
+}
+
+/** A single entry of a milestone vesting table. */
+export class Milestone {
+  /** Seconds after the lockup start at which this milestone is reached */
+  readonly offset: BN
+  /** Cumulative percentage of the locked amount unlocked once this milestone is reached */
+  readonly percentage: number
+
+  constructor(fields: MilestoneFields) {
+    this.offset = fields.offset
+    this.percentage = fields.percentage
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.i64("offset"), borsh.u8("percentage")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new Milestone({
+      offset: obj.offset,
+      percentage: obj.percentage,
+    })
+  }
+
+  static toEncodable(fields: MilestoneFields) {
+    return {
+      offset: fields.offset,
+      percentage: fields.percentage,
+    }
+  }
+
+  toJSON(): MilestoneJSON {
+    return {
+      offset: this.offset.toString(),
+      percentage: this.percentage,
+    }
+  }
+
+  static fromJSON(obj: MilestoneJSON): Milestone {
+    return new Milestone({
+      offset: new BN(obj.offset),
+      percentage: obj.percentage,
+    })
+  }
+
+  toEncodable() {
+    return Milestone.toEncodable(this)
+  }
+}
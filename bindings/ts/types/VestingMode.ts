@@ -0,0 +1,205 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface LinearJSON {
+  kind: "Linear"
+}
+
+export class Linear {
+  static readonly discriminator = 0
+  static readonly kind = "Linear"
+  readonly discriminator = 0
+  readonly kind = "Linear"
+
+  toJSON(): LinearJSON {
+    return {
+      kind: "Linear",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Linear: {},
+    }
+  }
+}
+
+export type PeriodicFields = {
+  /** Period length in seconds */
+  period: BN
+}
+export type PeriodicValue = {
+  /** Period length in seconds */
+  period: BN
+}
+
+export interface PeriodicJSON {
+  kind: "Periodic"
+  value: {
+    /** Period length in seconds */
+    period: string
+  }
+}
+
+export class Periodic {
+  static readonly discriminator = 1
+  static readonly kind = "Periodic"
+  readonly discriminator = 1
+  readonly kind = "Periodic"
+  readonly value: PeriodicValue
+
+  constructor(value: PeriodicFields) {
+    this.value = {
+      period: value.period,
+    }
+  }
+
+  toJSON(): PeriodicJSON {
+    return {
+      kind: "Periodic",
+      value: {
+        period: this.value.period.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      Periodic: {
+        period: this.value.period,
+      },
+    }
+  }
+}
+
+export type MilestonesFields = {
+  /** Milestone table, only the first num_milestones entries are used */
+  milestones: Array<types.MilestoneFields>
+  /** Number of milestones in the table */
+  num_milestones: number
+}
+export type MilestonesValue = {
+  /** Milestone table, only the first num_milestones entries are used */
+  milestones: Array<types.Milestone>
+  /** Number of milestones in the table */
+  num_milestones: number
+}
+
+export interface MilestonesJSON {
+  kind: "Milestones"
+  value: {
+    /** Milestone table, only the first num_milestones entries are used */
+    milestones: Array<types.MilestoneJSON>
+    /** Number of milestones in the table */
+    num_milestones: number
+  }
+}
+
+export class Milestones {
+  static readonly discriminator = 2
+  static readonly kind = "Milestones"
+  readonly discriminator = 2
+  readonly kind = "Milestones"
+  readonly value: MilestonesValue
+
+  constructor(value: MilestonesFields) {
+    this.value = {
+      milestones: value.milestones.map(
+        (item) => new types.Milestone({ ...item })
+      ),
+      num_milestones: value.num_milestones,
+    }
+  }
+
+  toJSON(): MilestonesJSON {
+    return {
+      kind: "Milestones",
+      value: {
+        milestones: this.value.milestones.map((item) => item.toJSON()),
+        num_milestones: this.value.num_milestones,
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      Milestones: {
+        milestones: this.value.milestones.map(
+          (item) => types.Milestone.toEncodable(item)
+        ),
+        num_milestones: this.value.num_milestones,
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.VestingModeKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Linear" in obj) {
+    return new Linear()
+  }
+  if ("Periodic" in obj) {
+    const val = obj["Periodic"]
+    return new Periodic({
+      period: val["period"],
+    })
+  }
+  if ("Milestones" in obj) {
+    const val = obj["Milestones"]
+    return new Milestones({
+      milestones: val["milestones"].map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.Milestone.fromDecoded(item)
+      ),
+      num_milestones: val["num_milestones"],
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.VestingModeJSON): types.VestingModeKind {
+  switch (obj.kind) {
+    case "Linear": {
+      return new Linear()
+    }
+    case "Periodic": {
+      return new Periodic({
+        period: new BN(obj.value.period),
+      })
+    }
+    case "Milestones": {
+      return new Milestones({
+        milestones: obj.value.milestones.map(
+          (item) => types.Milestone.fromJSON(item)
+        ),
+        num_milestones: obj.value.num_milestones,
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Linear"),
+    borsh.struct([borsh.i64("period")], "Periodic"),
+    borsh.struct(
+      [
+        borsh.array(types.Milestone.layout(), 16, "milestones"),
+        borsh.u8("num_milestones"),
+      ],
+      "Milestones"
+    ),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
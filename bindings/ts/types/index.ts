@@ -0,0 +1,26 @@
+import * as VestingMode from "./VestingMode"
+
+export { ExtraMint } from "./ExtraMint"
+export type { ExtraMintFields, ExtraMintJSON } from "./ExtraMint"
+export { ExtraLeafAmount } from "./ExtraLeafAmount"
+export type {
+  ExtraLeafAmountFields,
+  ExtraLeafAmountJSON,
+} from "./ExtraLeafAmount"
+export { ExtraClaimAmount } from "./ExtraClaimAmount"
+export type {
+  ExtraClaimAmountFields,
+  ExtraClaimAmountJSON,
+} from "./ExtraClaimAmount"
+export { Milestone } from "./Milestone"
+export type { MilestoneFields, MilestoneJSON } from "./Milestone"
+export { VestingMode }
+
+export type VestingModeKind =
+  | VestingMode.Linear
+  | VestingMode.Periodic
+  | VestingMode.Milestones
+export type VestingModeJSON =
+  | VestingMode.LinearJSON
+  | VestingMode.PeriodicJSON
+  | VestingMode.MilestonesJSON
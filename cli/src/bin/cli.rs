@@ -5,22 +5,42 @@ use std::path::PathBuf;
 
 use access_merkle_tree::{
     airdrop_merkle_tree::AirdropMerkleTree,
-    utils::{get_claim_status_pda, get_merkle_distributor_pda},
+    tree_node::TreeNode,
+    utils::{
+        get_claim_bitmap_pda, get_claim_status_pda, get_custody_vault_pda,
+        get_merkle_distributor_pda, get_sol_custody_pda, get_sol_distributor_pda,
+        get_sol_vault_pda, get_stake_account_pda, get_stake_authority_pda,
+        get_staking_central_state_pda,
+    },
 };
-use anchor_lang::{prelude::Pubkey, AccountDeserialize, InstructionData, Key, ToAccountMetas};
-use anchor_spl::token;
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token::native_mint;
 use clap::{Parser, Subcommand};
-use merkle_distributor::state::merkle_distributor::MerkleDistributor;
-use solana_program::instruction::Instruction;
-use solana_program::system_instruction;
+use merkle_distributor::{
+    instructions::new_distributor::MIN_CLAWBACK_DELAY,
+    state::{
+        claim_bitmap::ClaimBitmap,
+        claim_status::ClaimStatus,
+        extra_mint::ExtraLeafAmount,
+        merkle_distributor::MerkleDistributor,
+        sol_custody::SolCustody,
+        vesting_mode::{Milestone, VestingMode, MAX_MILESTONES},
+    },
+};
+use solana_program::instruction::{AccountMeta, Instruction};
 use solana_rpc_client::rpc_client::RpcClient;
 use solana_sdk::{
-    account::Account, commitment_config::CommitmentConfig,
-    compute_budget::ComputeBudgetInstruction, signature::read_keypair_file, signer::Signer,
+    account::{from_account, Account},
+    clock::Clock,
+    commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    sysvar,
     transaction::Transaction,
 };
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account,
+    get_associated_token_address_with_program_id, instruction::create_associated_token_account,
 };
 
 #[derive(Parser, Debug)]
@@ -52,10 +72,6 @@ pub struct Args {
     /// Priority fee
     #[clap(long, env)]
     pub priority: Option<u64>,
-
-    /// Clawback receiver token account
-    #[clap(long, env)]
-    pub clawback_receiver_token_account: Option<Pubkey>,
 }
 
 // Subcommands
@@ -63,19 +79,59 @@ pub struct Args {
 pub enum Commands {
     /// Claim unlocked tokens
     Claim(ClaimArgs),
+    /// Refund the claim status rent once all locked tokens are withdrawn
+    CloseClaimStatus(CloseClaimStatusArgs),
+    /// Transfer a claim to a new wallet, signed by the current claimant
+    TransferClaim(TransferClaimArgs),
+    /// Move a claim to a new wallet of a claimant who lost theirs, signed by the admin
+    RecoverClaim(RecoverClaimArgs),
     /// Create a new instance of a merkle distributor
     NewDistributor(NewDistributorArgs),
+    /// Revoke the unvested locked tokens of a claim, returning them to the clawback receiver
+    RevokeClaim(RevokeClaimArgs),
+    /// Fund the distributor vault from the creator and report the funding shortfall
+    FundDistributor(FundDistributorArgs),
     /// Clawback tokens from merkle distributor
     Clawback(ClawbackArgs),
+    /// Add a mint paid out alongside the distributor's mint, before anyone claims
+    AddExtraMint(AddExtraMintArgs),
     /// Create a Merkle tree, given a CSV of recipients
     CreateMerkleTree(CreateMerkleTreeArgs),
-    SetAdmin(SetAdminArgs),
+    /// Propose a new admin, who has to accept to become the admin
+    ProposeAdmin(ProposeAdminArgs),
+    /// Accept a pending admin proposal, signed by the proposed admin
+    AcceptAdmin(AdminProposalArgs),
+    /// Cancel a pending admin proposal
+    CancelAdminProposal(AdminProposalArgs),
+    /// Set the token account unclaimed tokens are clawed back to
+    SetClawbackReceiver(SetClawbackReceiverArgs),
+    /// Set the staking program and stake pool claimed tokens can be staked into
+    SetStakingProgram(SetStakingProgramArgs),
+    /// Stake locked tokens of a claim through the distributor while they vest
+    StakeLocked(StakeLockedArgs),
+    /// Unstake locked tokens of a claim back into the distributor vault
+    UnstakeLocked(StakeLockedArgs),
+    /// Replace the merkle root with a tree that only adds recipients or increases allocations
+    UpdateRoot(UpdateRootArgs),
     /// Print the derived distributor PDA
-    DistributorPda,
+    DistributorPda(DistributorPdaArgs),
     /// Withdraw SOL from custody PDA
     WithdrawCustodySol(WithdrawCustodySolArgs),
-    /// Stake SOL to custody PDA
-    StakeCustodySol(StakeCustodySolArgs),
+    /// Deposit SOL to custody PDA, optionally locking withdrawals
+    #[clap(alias = "stake-custody-sol")]
+    DepositCustodySol(DepositCustodySolArgs),
+    /// Print the SOL custody balance of an owner
+    CustodySolBalance(CustodySolBalanceArgs),
+    /// Pause new claims and locked token withdrawals
+    Pause(PauseArgs),
+    /// Resume new claims and locked token withdrawals
+    Unpause(PauseArgs),
+    /// Let anyone claim to claimants' associated token accounts without their signature
+    SetPermissionlessClaims(SetPermissionlessClaimsArgs),
+    /// Migrate a distributor account to the current layout
+    MigrateDistributor(MigrateDistributorArgs),
+    /// Migrate a claim status account to the current layout, after its distributor
+    MigrateClaimStatus(MigrateClaimStatusArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -83,6 +139,83 @@ pub struct ClawbackArgs {
     /// Distributor PDA
     #[clap(long, env)]
     pub distributor: Pubkey,
+    /// Only claw back the unclaimed allocation, existing claims keep withdrawing locked tokens
+    #[clap(long, env)]
+    pub unclaimed_only: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct AddExtraMintArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Mint paid out alongside the distributor's mint
+    #[clap(long, env)]
+    pub extra_mint: Pubkey,
+    /// Merkle tree path, its leaves carry the extra mint amounts in the order mints are added
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct RevokeClaimArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Claimant whose claim is revoked
+    #[clap(long, env)]
+    pub claimant: Pubkey,
+}
+
+#[derive(Parser, Debug)]
+pub struct FundDistributorArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Amount to transfer from the creator, defaults to the funding shortfall
+    #[clap(long, env)]
+    pub amount: Option<u64>,
+}
+
+// Pause and Unpause subcommand args
+#[derive(Parser, Debug)]
+pub struct PauseArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetPermissionlessClaimsArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Require the claimant's signature again
+    #[clap(long, env)]
+    pub disable: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct MigrateDistributorArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Token account of the mint the unclaimed tokens are clawed back to
+    #[clap(long, env)]
+    pub clawback_receiver_token_account: Pubkey,
+    /// Claim window end (Unix Timestamp), claims stay open until clawed back by default
+    #[clap(long, env)]
+    pub claim_end_ts: Option<i64>,
+}
+
+#[derive(Parser, Debug)]
+pub struct MigrateClaimStatusArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Claimant of the claim status, defaults to the keypair
+    #[clap(long, env)]
+    pub claimant: Option<Pubkey>,
 }
 
 // NewClaim and Claim subcommand args
@@ -94,6 +227,101 @@ pub struct ClaimArgs {
     /// Distributor PDA
     #[clap(long, env)]
     pub distributor: Pubkey,
+    /// Fee payer keypair, pays the transaction fees and claim status rent instead of the claimant
+    #[clap(long, env)]
+    pub fee_payer: Option<PathBuf>,
+    /// Claim on behalf of this claimant without their signature, paid by the keypair.
+    /// The tokens go to the claimant's associated token account.
+    #[clap(long, env, conflicts_with = "fee-payer")]
+    pub claimant: Option<Pubkey>,
+    /// Claimant of the merkle leaf, for withdrawing a claim transferred to the claimant
+    #[clap(long, env)]
+    pub leaf_claimant: Option<Pubkey>,
+    /// Stake the claimed tokens into the claimant's stake account of the distributor's staking
+    /// program, the claimant has to sign
+    #[clap(long, env, conflicts_with = "claimant", requires_all = &["stake-vault", "fee-account"])]
+    pub stake: bool,
+    /// Vault of the distributor's stake pool
+    #[clap(long, env, requires = "stake")]
+    pub stake_vault: Option<Pubkey>,
+    /// Fee account of the distributor's staking program
+    #[clap(long, env, requires = "stake")]
+    pub fee_account: Option<Pubkey>,
+}
+
+#[derive(Parser, Debug)]
+pub struct CloseClaimStatusArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Claimant of the merkle leaf if the claim was transferred, defaults to the keypair
+    #[clap(long, env)]
+    pub leaf_claimant: Option<Pubkey>,
+}
+
+#[derive(Parser, Debug)]
+pub struct TransferClaimArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Wallet the claim is transferred to
+    #[clap(long, env)]
+    pub new_claimant: Pubkey,
+    /// Claimant of the merkle leaf if the claim was transferred before, defaults to the keypair
+    #[clap(long, env)]
+    pub leaf_claimant: Option<Pubkey>,
+}
+
+#[derive(Parser, Debug)]
+pub struct RecoverClaimArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+    /// Claimant of the merkle leaf
+    #[clap(long, env)]
+    pub leaf_claimant: Pubkey,
+    /// Keypair of the claimant's new wallet, co-signs the recovery
+    #[clap(long, env)]
+    pub new_claimant_keypair_path: PathBuf,
+}
+
+/// The parties of a claim transaction
+struct ClaimSigners {
+    claimant: Pubkey,
+    fee_payer: Pubkey,
+    keypairs: Vec<Keypair>,
+}
+
+impl ClaimArgs {
+    fn signers(&self, args: &Args) -> ClaimSigners {
+        let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+        if let Some(claimant) = self.claimant {
+            // the claimant doesn't sign, so the program only sends to their associated token account
+            return ClaimSigners {
+                claimant,
+                fee_payer: keypair.pubkey(),
+                keypairs: vec![keypair],
+            };
+        }
+
+        let claimant = keypair.pubkey();
+        match &self.fee_payer {
+            Some(fee_payer_path) => {
+                let fee_payer = read_keypair_file(fee_payer_path)
+                    .expect("Failed reading fee payer keypair file");
+                ClaimSigners {
+                    claimant,
+                    fee_payer: fee_payer.pubkey(),
+                    keypairs: vec![keypair, fee_payer],
+                }
+            }
+            None => ClaimSigners {
+                claimant,
+                fee_payer: claimant,
+                keypairs: vec![keypair],
+            },
+        }
+    }
 }
 
 // NewDistributor subcommand args
@@ -110,6 +338,94 @@ pub struct NewDistributorArgs {
     /// Lockup timestamp end (unix timestamp)
     #[clap(long, env)]
     pub end_vesting_ts: i64,
+
+    /// Clawback start timestamp (unix timestamp), at least one day after the lockup end and
+    /// after the end of every leaf's own vesting schedule
+    #[clap(long, env)]
+    pub clawback_start_ts: i64,
+
+    /// Token account the unclaimed tokens are clawed back to, a wallet for native SOL distributors
+    #[clap(long, env)]
+    pub clawback_receiver_token_account: Pubkey,
+
+    /// Distribute native SOL from a SOL vault instead of tokens, --mint has to be the native mint
+    #[clap(long, env, conflicts_with = "revocable")]
+    pub native_sol: bool,
+
+    /// Claim window end timestamp (unix timestamp), defaults to the clawback start timestamp.
+    /// Existing claims keep withdrawing locked tokens after it.
+    #[clap(long, env)]
+    pub claim_end_ts: Option<i64>,
+
+    /// Allow the admin to revoke the unvested locked tokens of a claim
+    #[clap(long, env)]
+    pub revocable: bool,
+
+    /// Allow the admin to move a claim to a new wallet of its claimant
+    #[clap(long, env)]
+    pub recoverable: bool,
+
+    /// Lockup cliff timestamp (unix timestamp), defaults to the start timestamp
+    #[clap(long, env)]
+    pub cliff_vesting_ts: Option<i64>,
+
+    /// Percentage of the locked amount unlocked at the cliff
+    #[clap(long, env, default_value_t = 0)]
+    pub cliff_percentage: u8,
+
+    /// Unlock locked tokens in tranches every this many seconds instead of every second
+    #[clap(long, env, conflicts_with = "vesting-milestones")]
+    pub vesting_period: Option<i64>,
+
+    /// Unlock locked tokens according to a comma separated milestone table of
+    /// <seconds after start>:<cumulative percentage> entries, e.g. 0:25,2592000:50,5184000:100
+    #[clap(long, env, use_value_delimiter = true, value_parser = parse_milestone)]
+    pub vesting_milestones: Vec<Milestone>,
+}
+
+impl NewDistributorArgs {
+    fn claim_end_ts(&self) -> i64 {
+        self.claim_end_ts.unwrap_or(self.clawback_start_ts)
+    }
+
+    fn cliff_vesting_ts(&self) -> i64 {
+        self.cliff_vesting_ts.unwrap_or(self.start_vesting_ts)
+    }
+
+    fn vesting_mode(&self) -> VestingMode {
+        if let Some(period) = self.vesting_period {
+            return VestingMode::Periodic { period };
+        }
+        if self.vesting_milestones.is_empty() {
+            return VestingMode::Linear;
+        }
+
+        assert!(
+            self.vesting_milestones.len() <= MAX_MILESTONES,
+            "at most {MAX_MILESTONES} vesting milestones are supported"
+        );
+        let mut milestones = [Milestone::default(); MAX_MILESTONES];
+        milestones[..self.vesting_milestones.len()].copy_from_slice(&self.vesting_milestones);
+        VestingMode::Milestones {
+            milestones,
+            num_milestones: self.vesting_milestones.len() as u8,
+        }
+    }
+}
+
+fn parse_milestone(milestone: &str) -> Result<Milestone, String> {
+    let (offset, percentage) = milestone
+        .split_once(':')
+        .ok_or_else(|| format!("milestone {milestone} is not in <offset>:<percentage> format"))?;
+
+    Ok(Milestone {
+        offset: offset
+            .parse()
+            .map_err(|e| format!("invalid offset {offset}: {e}"))?,
+        percentage: percentage
+            .parse()
+            .map_err(|e| format!("invalid percentage {percentage}: {e}"))?,
+    })
 }
 
 #[derive(Parser, Debug)]
@@ -121,10 +437,25 @@ pub struct CreateMerkleTreeArgs {
     /// Merkle tree out path
     #[clap(long, env)]
     pub merkle_tree_path: PathBuf,
+
+    /// Include leaf indices so the distributor tracks claims in a bitmap instead of
+    /// claim status accounts. Only for airdrops without locked tokens
+    #[clap(long, env)]
+    pub claim_bitmap: bool,
+
+    /// Clawback start timestamp of the distributor (unix timestamp), rejects leaves whose own
+    /// vesting schedule ends less than a day before it
+    #[clap(long, env)]
+    pub clawback_start_ts: Option<i64>,
+
+    /// Indexed merkle tree a bitmap distributor currently uses, to create its update for
+    /// update-root. Existing claimants keep their index and new ones get the next free indices
+    #[clap(long, env, requires = "claim-bitmap")]
+    pub base_merkle_tree_path: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
-pub struct SetAdminArgs {
+pub struct ProposeAdminArgs {
     /// Distributor PDA
     #[clap(long, env)]
     pub distributor: Pubkey,
@@ -134,6 +465,84 @@ pub struct SetAdminArgs {
     pub new_admin: Pubkey,
 }
 
+// AcceptAdmin and CancelAdminProposal subcommand args
+#[derive(Parser, Debug)]
+pub struct AdminProposalArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetClawbackReceiverArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+
+    /// New clawback receiver token account
+    #[clap(long, env)]
+    pub new_clawback_receiver_token_account: Pubkey,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetStakingProgramArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+
+    /// Staking program implementing the stake instruction
+    #[clap(long, env)]
+    pub staking_program: Pubkey,
+
+    /// Stake pool of the staking program
+    #[clap(long, env)]
+    pub stake_pool: Pubkey,
+}
+
+#[derive(Parser, Debug)]
+pub struct StakeLockedArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+
+    /// Amount of locked tokens
+    #[clap(long, env)]
+    pub amount: u64,
+
+    /// Vault of the distributor's stake pool
+    #[clap(long, env)]
+    pub stake_vault: Pubkey,
+
+    /// Fee account of the distributor's staking program
+    #[clap(long, env)]
+    pub fee_account: Pubkey,
+
+    /// Claimant of the merkle leaf, for a transferred claim or the admin unstaking a claim
+    #[clap(long, env)]
+    pub leaf_claimant: Option<Pubkey>,
+}
+
+#[derive(Parser, Debug)]
+pub struct UpdateRootArgs {
+    /// Distributor PDA
+    #[clap(long, env)]
+    pub distributor: Pubkey,
+
+    /// Path of the merkle tree the distributor currently uses
+    #[clap(long, env)]
+    pub old_merkle_tree_path: PathBuf,
+
+    /// Path of the updated merkle tree
+    #[clap(long, env)]
+    pub new_merkle_tree_path: PathBuf,
+
+    /// Also increase allocations or change vesting schedules of existing claimants. Only
+    /// claimants who have not claimed can be changed, which is checked right before sending, so
+    /// pause the distributor first to keep claims from landing in between
+    #[clap(long, env)]
+    pub allow_changes: bool,
+}
+
 #[derive(Parser, Debug)]
 pub struct WithdrawCustodySolArgs {
     /// Amount to withdraw (lamports)
@@ -142,10 +551,28 @@ pub struct WithdrawCustodySolArgs {
 }
 
 #[derive(Parser, Debug)]
-pub struct StakeCustodySolArgs {
-    /// Amount to stake (lamports)
+pub struct DepositCustodySolArgs {
+    /// Amount to deposit (lamports)
     #[clap(long, env)]
     pub amount: u64,
+
+    /// Lock withdrawals until this timestamp, an existing lock can only be extended
+    #[clap(long, env)]
+    pub lock_until_ts: Option<i64>,
+}
+
+#[derive(Parser, Debug)]
+pub struct DistributorPdaArgs {
+    /// Derive a native SOL distributor, which ignores --mint
+    #[clap(long, env)]
+    pub native_sol: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct CustodySolBalanceArgs {
+    /// Owner of the custody, defaults to the keypair
+    #[clap(long, env)]
+    pub owner: Option<Pubkey>,
 }
 
 fn main() {
@@ -158,42 +585,114 @@ fn main() {
         Commands::Claim(claim_args) => {
             process_claim(&args, claim_args);
         }
+        Commands::CloseClaimStatus(close_args) => {
+            process_close_claim_status(&args, close_args);
+        }
+        Commands::TransferClaim(transfer_args) => {
+            process_transfer_claim(&args, transfer_args);
+        }
+        Commands::RecoverClaim(recover_args) => {
+            process_recover_claim(&args, recover_args);
+        }
+        Commands::RevokeClaim(revoke_args) => {
+            process_revoke_claim(&args, revoke_args);
+        }
+        Commands::FundDistributor(fund_args) => {
+            process_fund_distributor(&args, fund_args);
+        }
         Commands::Clawback(clawback_args) => process_clawback(&args, clawback_args),
+        Commands::AddExtraMint(add_extra_mint_args) => {
+            process_add_extra_mint(&args, add_extra_mint_args);
+        }
         Commands::CreateMerkleTree(merkle_tree_args) => {
             process_create_merkle_tree(merkle_tree_args);
         }
-        Commands::SetAdmin(set_admin_args) => {
-            process_set_admin(&args, set_admin_args);
+        Commands::ProposeAdmin(propose_admin_args) => {
+            process_propose_admin(&args, propose_admin_args);
         }
-        Commands::DistributorPda => {
-            process_distributor_pda(&args);
+        Commands::AcceptAdmin(accept_admin_args) => {
+            process_accept_admin(&args, accept_admin_args);
+        }
+        Commands::CancelAdminProposal(cancel_args) => {
+            process_cancel_admin_proposal(&args, cancel_args);
+        }
+        Commands::SetClawbackReceiver(set_clawback_receiver_args) => {
+            process_set_clawback_receiver(&args, set_clawback_receiver_args);
+        }
+        Commands::SetStakingProgram(set_staking_program_args) => {
+            process_set_staking_program(&args, set_staking_program_args);
+        }
+        Commands::StakeLocked(stake_locked_args) => {
+            process_stake_locked(&args, stake_locked_args, false);
+        }
+        Commands::UnstakeLocked(stake_locked_args) => {
+            process_stake_locked(&args, stake_locked_args, true);
+        }
+        Commands::UpdateRoot(update_root_args) => {
+            process_update_root(&args, update_root_args);
+        }
+        Commands::DistributorPda(distributor_pda_args) => {
+            process_distributor_pda(&args, distributor_pda_args);
         }
         Commands::WithdrawCustodySol(withdraw_args) => {
             process_withdraw_custody_sol(&args, withdraw_args);
         }
-        Commands::StakeCustodySol(stake_args) => {
-            process_stake_custody_sol(&args, stake_args);
+        Commands::DepositCustodySol(deposit_args) => {
+            process_deposit_custody_sol(&args, deposit_args);
+        }
+        Commands::CustodySolBalance(balance_args) => {
+            process_custody_sol_balance(&args, balance_args);
+        }
+        Commands::Pause(pause_args) => {
+            process_set_paused(&args, pause_args, true);
+        }
+        Commands::Unpause(pause_args) => {
+            process_set_paused(&args, pause_args, false);
+        }
+        Commands::SetPermissionlessClaims(set_permissionless_claims_args) => {
+            process_set_permissionless_claims(&args, set_permissionless_claims_args);
+        }
+        Commands::MigrateDistributor(migrate_args) => {
+            process_migrate_distributor(&args, migrate_args);
+        }
+        Commands::MigrateClaimStatus(migrate_args) => {
+            process_migrate_claim_status(&args, migrate_args);
         }
     }
 }
 
-fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
-    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
-    let claimant = keypair.pubkey();
-    println!("Claiming tokens for user {}...", claimant);
-
+fn process_claim(args: &Args, claim_args: &ClaimArgs) {
     let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
         .expect("failed to load merkle tree from file");
+    if merkle_tree.is_indexed() {
+        // bitmap tracked claims have no locked tokens, so a single claim is enough
+        process_new_claim_bitmap(args, claim_args, &merkle_tree);
+        return;
+    }
 
-    // Get user's node in claim
-    let node = merkle_tree.get_node(&claimant);
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let distributor = fetch_distributor(&client, &claim_args.distributor);
+    if distributor.native_sol {
+        process_claim_sol(args, claim_args, &merkle_tree);
+        return;
+    }
+
+    let signers = claim_args.signers(args);
+    let claimant = signers.claimant;
+    println!("Claiming tokens for user {}...", claimant);
 
+    let priority_fee = args.priority.unwrap_or(0);
+
+    // A transferred claim is derived from the leaf claimant
+    let leaf_claimant = claim_args.leaf_claimant.unwrap_or(claimant);
     let (claim_status_pda, _bump) =
-        get_claim_status_pda(&args.program_id, &claimant, &claim_args.distributor);
+        get_claim_status_pda(&args.program_id, &leaf_claimant, &claim_args.distributor);
+    println!("claim pda: {claim_status_pda}");
 
-    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let token_program = get_token_program(&client, &args.mint);
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
 
     let mut ixs = vec![];
 
@@ -203,8 +702,12 @@ fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
             // TODO: directly pattern match on error kind
             if e.to_string().contains("AccountNotFound") {
                 println!("PDA does not exist. creating.");
-                let ix =
-                    create_associated_token_account(&claimant, &claimant, &args.mint, &token::ID);
+                let ix = create_associated_token_account(
+                    &signers.fee_payer,
+                    &claimant,
+                    &args.mint,
+                    &token_program,
+                );
                 ixs.push(ix);
             } else {
                 panic!("Error fetching PDA: {e}")
@@ -212,103 +715,473 @@ fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
         }
     }
 
-    let new_claim_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::NewClaim {
+    let from = get_associated_token_address_with_program_id(
+        &claim_args.distributor,
+        &args.mint,
+        &token_program,
+    );
+    let extra_mint_accounts = extra_mint_account_metas(
+        &client,
+        &distributor,
+        &claimant,
+        &signers.fee_payer,
+        &mut ixs,
+    );
+    let claim_status_exists = client
+        .get_account_with_commitment(&claim_status_pda, CommitmentConfig::confirmed())
+        .unwrap()
+        .value
+        .is_some();
+    // claim_all only pays out the distributor's mint without staking, so extra mints and staked
+    // claims are created with new_claim and withdrawn with claim_locked
+    let two_step = distributor.num_extra_mints > 0 || claim_args.stake;
+    let stake_accounts = || {
+        (
+            get_staking_central_state_pda(&distributor.staking_program).0,
+            get_stake_account_pda(
+                &distributor.staking_program,
+                &claimant,
+                &distributor.stake_pool,
+            )
+            .0,
+        )
+    };
+    let claim_locked_ix = || {
+        let accounts = merkle_distributor::accounts::ClaimLocked {
             distributor: claim_args.distributor,
             claim_status: claim_status_pda,
-            from: get_associated_token_address(&claim_args.distributor, &args.mint),
+            from,
             to: claimant_ata,
             claimant,
-            token_program: token::ID,
-            system_program: solana_program::system_program::ID,
+            mint: args.mint,
+            token_program,
+        };
+        match claim_args.stake {
+            true => {
+                let (central_state, stake_account) = stake_accounts();
+                Instruction {
+                    program_id: args.program_id,
+                    accounts: merkle_distributor::accounts::ClaimLockedAndStake {
+                        claim: accounts,
+                        central_state,
+                        stake_pool: distributor.stake_pool,
+                        stake_account,
+                        stake_vault: claim_args.stake_vault.unwrap(),
+                        fee_account: claim_args.fee_account.unwrap(),
+                        staking_program: distributor.staking_program,
+                    }
+                    .to_account_metas(None),
+                    data: merkle_distributor::instruction::ClaimLockedAndStake {}.data(),
+                }
+            }
+            false => Instruction {
+                program_id: args.program_id,
+                accounts: accounts.to_account_metas(None),
+                data: merkle_distributor::instruction::ClaimLocked {}.data(),
+            },
         }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::NewClaim {
-            amount_unlocked: node.amount_unlocked(),
-            amount_locked: node.amount_locked(),
-            proof: node.proof.expect("proof not found"),
+    };
+    let claim_ixs = if leaf_claimant != claimant || (two_step && claim_status_exists) {
+        // The claim already exists, only its vested locked tokens are left
+        vec![claim_locked_ix()]
+    } else if two_step {
+        let node = merkle_tree.get_node(&claimant);
+        let accounts = merkle_distributor::accounts::NewClaim {
+            distributor: claim_args.distributor,
+            claim_status: claim_status_pda,
+            from,
+            to: claimant_ata,
+            claimant,
+            payer: signers.fee_payer,
+            mint: args.mint,
+            token_program,
+            system_program: solana_program::system_program::ID,
+        };
+        let extra_amounts = node
+            .extra_amounts
+            .iter()
+            .map(|extra_amount| ExtraLeafAmount {
+                amount_unlocked: extra_amount.total_unlocked,
+                amount_locked: extra_amount.total_locked,
+            })
+            .collect();
+        let proof = node.proof.clone().expect("proof not found");
+        let new_claim_ix = match claim_args.stake {
+            true => {
+                let (central_state, stake_account) = stake_accounts();
+                Instruction {
+                    program_id: args.program_id,
+                    accounts: merkle_distributor::accounts::NewClaimAndStake {
+                        claim: accounts,
+                        central_state,
+                        stake_pool: distributor.stake_pool,
+                        stake_account,
+                        stake_vault: claim_args.stake_vault.unwrap(),
+                        fee_account: claim_args.fee_account.unwrap(),
+                        staking_program: distributor.staking_program,
+                    }
+                    .to_account_metas(None),
+                    data: merkle_distributor::instruction::NewClaimAndStake {
+                        amount_unlocked: node.amount_unlocked(),
+                        amount_locked: node.amount_locked(),
+                        start_vesting_ts: node.start_ts,
+                        end_vesting_ts: node.end_ts,
+                        proof,
+                        extra_amounts,
+                    }
+                    .data(),
+                }
+            }
+            false => Instruction {
+                program_id: args.program_id,
+                accounts: accounts.to_account_metas(None),
+                data: merkle_distributor::instruction::NewClaim {
+                    amount_unlocked: node.amount_unlocked(),
+                    amount_locked: node.amount_locked(),
+                    start_vesting_ts: node.start_ts,
+                    end_vesting_ts: node.end_ts,
+                    proof,
+                    extra_amounts,
+                }
+                .data(),
+            },
+        };
+        // Withdraw the locked tokens vested so far in the same transaction, like claim_all,
+        // so the first claim never leaves them behind
+        match new_claim_vested(&client, &distributor, &node) {
+            true => vec![new_claim_ix, claim_locked_ix()],
+            false => vec![new_claim_ix],
         }
-        .data(),
+    } else {
+        // Get user's node in claim
+        let node = merkle_tree.get_node(&claimant);
+
+        // Creates the claim status on the first claim and withdraws everything vested so far
+        vec![Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::ClaimAll {
+                distributor: claim_args.distributor,
+                claim_status: claim_status_pda,
+                from,
+                to: claimant_ata,
+                claimant,
+                payer: signers.fee_payer,
+                mint: args.mint,
+                token_program,
+                system_program: solana_program::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::ClaimAll {
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                start_vesting_ts: node.start_ts,
+                end_vesting_ts: node.end_ts,
+                proof: node.proof.expect("proof not found"),
+            }
+            .data(),
+        }]
     };
+    for mut claim_ix in claim_ixs {
+        claim_ix
+            .accounts
+            .extend(extra_mint_accounts.iter().cloned());
+        ixs.push(claim_ix);
+    }
 
-    ixs.push(new_claim_ix);
-
-    let blockhash = client.get_latest_blockhash().unwrap();
-    let tx =
-        Transaction::new_signed_with_payer(&ixs, Some(&claimant.key()), &[&keypair], blockhash);
-
-    let signature = client
-        .send_and_confirm_transaction_with_spinner(&tx)
-        .unwrap();
-    println!("successfully created new claim with signature {signature:#?}");
-}
+    if priority_fee > 0 {
+        let instruction = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
+        ixs.push(instruction);
+        println!(
+            "Added priority fee instruction of {} microlamports",
+            priority_fee
+        );
+    } else {
+        println!("No priority fee added. Add one with --priority <microlamports u64>");
+    }
 
-fn process_claim(args: &Args, claim_args: &ClaimArgs) {
-    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
-    let claimant = keypair.pubkey();
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let keypairs: Vec<&Keypair> = signers.keypairs.iter().collect();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&signers.fee_payer), &keypairs, blockhash);
 
-    let priority_fee = args.priority.unwrap_or(0);
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed tokens with signature {signature:#?}",);
+}
 
-    println!("args: {:?}", args);
+fn process_claim_sol(args: &Args, claim_args: &ClaimArgs, merkle_tree: &AirdropMerkleTree) {
+    let signers = claim_args.signers(args);
+    let claimant = signers.claimant;
+    println!("Claiming SOL for user {}...", claimant);
 
+    // A transferred claim is derived from the leaf claimant
+    let leaf_claimant = claim_args.leaf_claimant.unwrap_or(claimant);
     let (claim_status_pda, _bump) =
-        get_claim_status_pda(&args.program_id, &claimant, &claim_args.distributor);
-    println!("claim pda: {claim_status_pda}");
+        get_claim_status_pda(&args.program_id, &leaf_claimant, &claim_args.distributor);
+    let (sol_vault, _bump) = get_sol_vault_pda(&args.program_id, &claim_args.distributor);
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    match client.get_account(&claim_status_pda) {
-        Ok(_) => {}
-        Err(e) => {
-            // TODO: match on the error kind
-            if e.to_string().contains("AccountNotFound") {
-                println!("PDA does not exist. creating.");
-                process_new_claim(args, claim_args);
-            } else {
-                panic!("error getting PDA: {e}")
+    let claim_locked_sol_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::ClaimLockedSol {
+            distributor: claim_args.distributor,
+            claim_status: claim_status_pda,
+            sol_vault,
+            claimant,
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::ClaimLockedSol {}.data(),
+    };
+
+    let mut ixs = vec![];
+    if client
+        .get_account_with_commitment(&claim_status_pda, CommitmentConfig::confirmed())
+        .unwrap()
+        .value
+        .is_some()
+    {
+        // the lamports go straight to the claimant, so only the vested locked lamports are left
+        ixs.push(claim_locked_sol_ix);
+    } else {
+        let node = merkle_tree.get_node(&claimant);
+        let distributor = fetch_distributor(&client, &claim_args.distributor);
+        let vested = new_claim_vested(&client, &distributor, &node);
+        ixs.push(Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::NewClaimSol {
+                distributor: claim_args.distributor,
+                claim_status: claim_status_pda,
+                sol_vault,
+                claimant,
+                payer: signers.fee_payer,
+                system_program: solana_program::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::NewClaimSol {
+                amount_unlocked: node.amount_unlocked(),
+                amount_locked: node.amount_locked(),
+                start_vesting_ts: node.start_ts,
+                end_vesting_ts: node.end_ts,
+                proof: node.proof.expect("proof not found"),
             }
+            .data(),
+        });
+        // Withdraw the locked lamports vested so far in the same transaction, so the first
+        // claim never leaves them behind
+        if vested {
+            ixs.push(claim_locked_sol_ix);
         }
     }
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    if let Some(priority_fee) = args.priority {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
 
-    let mut ixs = vec![];
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let keypairs: Vec<&Keypair> = signers.keypairs.iter().collect();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&signers.fee_payer), &keypairs, blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed SOL with signature {signature:#?}");
+}
+
+fn process_close_claim_status(args: &Args, close_args: &CloseClaimStatusArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    // the claimant signs and receives the rent refund
+    let claimant = keypair.pubkey();
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let claim_ix = Instruction {
+    let (claim_status_pda, _bump) = get_claim_status_pda(
+        &args.program_id,
+        &close_args.leaf_claimant.unwrap_or(claimant),
+        &close_args.distributor,
+    );
+
+    let close_ix = Instruction {
         program_id: args.program_id,
-        accounts: merkle_distributor::accounts::ClaimLocked {
-            distributor: claim_args.distributor,
+        accounts: merkle_distributor::accounts::CloseClaimStatus {
+            distributor: close_args.distributor,
             claim_status: claim_status_pda,
-            from: get_associated_token_address(&claim_args.distributor, &args.mint),
-            to: claimant_ata,
             claimant,
-            token_program: token::ID,
         }
         .to_account_metas(None),
-        data: merkle_distributor::instruction::ClaimLocked {}.data(),
+        data: merkle_distributor::instruction::CloseClaimStatus {}.data(),
     };
-    ixs.push(claim_ix);
 
-    if priority_fee > 0 {
-        let instruction = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
-        ixs.push(instruction);
-        println!(
-            "Added priority fee instruction of {} microlamports",
-            priority_fee
-        );
-    } else {
-        println!("No priority fee added. Add one with --priority <microlamports u64>");
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully closed claim status! signature: {signature:#?}");
+}
+
+fn process_transfer_claim(args: &Args, transfer_args: &TransferClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let (claim_status_pda, _bump) = get_claim_status_pda(
+        &args.program_id,
+        &transfer_args.leaf_claimant.unwrap_or(keypair.pubkey()),
+        &transfer_args.distributor,
+    );
+
+    let transfer_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::TransferClaim {
+            distributor: transfer_args.distributor,
+            claim_status: claim_status_pda,
+            claimant: keypair.pubkey(),
+            new_claimant: transfer_args.new_claimant,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::TransferClaim {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!(
+        "Successfully transferred claim to {}! signature: {signature:#?}",
+        transfer_args.new_claimant
+    );
+}
+
+fn process_recover_claim(args: &Args, recover_args: &RecoverClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let new_claimant = read_keypair_file(&recover_args.new_claimant_keypair_path)
+        .expect("Failed reading new claimant keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let (claim_status_pda, _bump) = get_claim_status_pda(
+        &args.program_id,
+        &recover_args.leaf_claimant,
+        &recover_args.distributor,
+    );
+
+    let recover_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::RecoverClaim {
+            distributor: recover_args.distributor,
+            claim_status: claim_status_pda,
+            admin: keypair.pubkey(),
+            new_claimant: new_claimant.pubkey(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::RecoverClaim {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[recover_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair, &new_claimant],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!(
+        "Successfully recovered claim to {}! signature: {signature:#?}",
+        new_claimant.pubkey()
+    );
+}
+
+fn process_new_claim_bitmap(args: &Args, claim_args: &ClaimArgs, merkle_tree: &AirdropMerkleTree) {
+    let signers = claim_args.signers(args);
+    let claimant = signers.claimant;
+    println!("Claiming tokens for user {}...", claimant);
+
+    let node = merkle_tree.get_node(&claimant);
+    let index = node.index.expect("index not found");
+    let (claim_bitmap_pda, _bump) = get_claim_bitmap_pda(
+        &args.program_id,
+        &claim_args.distributor,
+        ClaimBitmap::page_of(index),
+    );
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let token_program = get_token_program(&client, &args.mint);
+
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+
+    let mut ixs = vec![];
+
+    if client
+        .get_account_with_commitment(&claimant_ata, CommitmentConfig::confirmed())
+        .unwrap()
+        .value
+        .is_none()
+    {
+        println!("ATA does not exist. creating.");
+        ixs.push(create_associated_token_account(
+            &signers.fee_payer,
+            &claimant,
+            &args.mint,
+            &token_program,
+        ));
     }
 
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::NewClaimBitmap {
+            distributor: claim_args.distributor,
+            claim_bitmap: claim_bitmap_pda,
+            from: get_associated_token_address_with_program_id(
+                &claim_args.distributor,
+                &args.mint,
+                &token_program,
+            ),
+            to: claimant_ata,
+            claimant,
+            mint: args.mint,
+            token_program,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::NewClaimBitmap {
+            index,
+            amount_unlocked: node.amount_unlocked(),
+            proof: node.proof.expect("proof not found"),
+        }
+        .data(),
+    });
+
     let blockhash = client.get_latest_blockhash().unwrap();
+    let keypairs: Vec<&Keypair> = signers.keypairs.iter().collect();
     let tx =
-        Transaction::new_signed_with_payer(&ixs, Some(&claimant.key()), &[&keypair], blockhash);
+        Transaction::new_signed_with_payer(&ixs, Some(&signers.fee_payer), &keypairs, blockhash);
 
     let signature = client
         .send_and_confirm_transaction_with_spinner(&tx)
         .unwrap();
-    println!("successfully claimed tokens with signature {signature:#?}",);
+    println!("successfully claimed tokens with signature {signature:#?}");
 }
 
 fn check_distributor_onchain_matches(
@@ -317,7 +1190,7 @@ fn check_distributor_onchain_matches(
     new_distributor_args: &NewDistributorArgs,
     pubkey: Pubkey,
 ) -> Result<(), &'static str> {
-    if let Ok(distributor) = MerkleDistributor::try_deserialize(&mut account.data.as_slice()) {
+    if let Ok(distributor) = MerkleDistributor::try_deserialize_any_layout(&account.data) {
         if distributor.root != merkle_tree.merkle_root {
             return Err("root mismatch");
         }
@@ -334,6 +1207,36 @@ fn check_distributor_onchain_matches(
         if distributor.end_ts != new_distributor_args.end_vesting_ts {
             return Err("end_ts mismatch");
         }
+        if distributor.clawback_start_ts != new_distributor_args.clawback_start_ts {
+            return Err("clawback_start_ts mismatch");
+        }
+        if distributor.claim_end_ts != new_distributor_args.claim_end_ts() {
+            return Err("claim_end_ts mismatch");
+        }
+        if distributor.cliff_ts != new_distributor_args.cliff_vesting_ts() {
+            return Err("cliff_ts mismatch");
+        }
+        if distributor.cliff_percentage != new_distributor_args.cliff_percentage {
+            return Err("cliff_percentage mismatch");
+        }
+        if distributor.vesting_mode != new_distributor_args.vesting_mode() {
+            return Err("vesting_mode mismatch");
+        }
+        if distributor.clawback_receiver != new_distributor_args.clawback_receiver_token_account {
+            return Err("clawback_receiver mismatch");
+        }
+        if distributor.revocable != new_distributor_args.revocable {
+            return Err("revocable mismatch");
+        }
+        if distributor.recoverable != new_distributor_args.recoverable {
+            return Err("recoverable mismatch");
+        }
+        if distributor.claim_bitmap != merkle_tree.is_indexed() {
+            return Err("claim_bitmap mismatch");
+        }
+        if distributor.native_sol != new_distributor_args.native_sol {
+            return Err("native_sol mismatch");
+        }
         if distributor.admin != pubkey {
             return Err("admin mismatch");
         }
@@ -347,13 +1250,31 @@ fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributorArg
     let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
     let merkle_tree = AirdropMerkleTree::new_from_file(&new_distributor_args.merkle_tree_path)
         .expect("failed to read");
-    let (distributor_pubkey, _bump) = get_merkle_distributor_pda(
-        &args.program_id,
-        &args.mint,
-        &keypair.pubkey(),
-        args.airdrop_version,
-    );
-    let token_vault = get_associated_token_address(&distributor_pubkey, &args.mint);
+    let (distributor_pubkey, _bump) = match new_distributor_args.native_sol {
+        true => get_sol_distributor_pda(&args.program_id, &keypair.pubkey(), args.airdrop_version),
+        false => get_merkle_distributor_pda(
+            &args.program_id,
+            &args.mint,
+            &keypair.pubkey(),
+            args.airdrop_version,
+        ),
+    };
+    // leaves vesting past the clawback start would lose their remaining locked tokens
+    merkle_tree
+        .validate_vesting_ends_by(new_distributor_args.clawback_start_ts - MIN_CLAWBACK_DELAY)
+        .expect("leaf vesting ends too close to the clawback start");
+
+    if new_distributor_args.native_sol {
+        assert_eq!(
+            args.mint,
+            native_mint::ID,
+            "native SOL distributors distribute the native mint"
+        );
+        assert!(
+            !merkle_tree.is_indexed(),
+            "native SOL distributors track claims in claim status accounts"
+        );
+    }
 
     if let Some(account) = client
         .get_account_with_commitment(&distributor_pubkey, CommitmentConfig::confirmed())
@@ -374,27 +1295,72 @@ fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributorArg
         distributor_pubkey
     );
 
-    let new_distributor_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::NewDistributor {
-            distributor: distributor_pubkey,
-            mint: args.mint,
-            token_vault,
-            creator: keypair.pubkey(),
-            system_program: solana_program::system_program::id(),
-            associated_token_program: spl_associated_token_account::ID,
-            token_program: token::ID,
+    let new_distributor_ix = if new_distributor_args.native_sol {
+        Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::NewSolDistributor {
+                distributor: distributor_pubkey,
+                sol_vault: get_sol_vault_pda(&args.program_id, &distributor_pubkey).0,
+                clawback_receiver: new_distributor_args.clawback_receiver_token_account,
+                creator: keypair.pubkey(),
+                system_program: solana_program::system_program::id(),
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::NewSolDistributor {
+                version: args.airdrop_version,
+                root: merkle_tree.merkle_root,
+                max_total_claim: merkle_tree.max_total_claim,
+                max_num_nodes: merkle_tree.max_num_nodes,
+                start_vesting_ts: new_distributor_args.start_vesting_ts,
+                end_vesting_ts: new_distributor_args.end_vesting_ts,
+                cliff_vesting_ts: new_distributor_args.cliff_vesting_ts(),
+                cliff_percentage: new_distributor_args.cliff_percentage,
+                vesting_mode: new_distributor_args.vesting_mode(),
+                clawback_start_ts: new_distributor_args.clawback_start_ts,
+                claim_end_ts: new_distributor_args.claim_end_ts(),
+                recoverable: new_distributor_args.recoverable,
+            }
+            .data(),
         }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::NewDistributor {
-            version: args.airdrop_version,
-            root: merkle_tree.merkle_root,
-            max_total_claim: merkle_tree.max_total_claim,
-            max_num_nodes: merkle_tree.max_num_nodes,
-            start_vesting_ts: new_distributor_args.start_vesting_ts,
-            end_vesting_ts: new_distributor_args.end_vesting_ts,
+    } else {
+        let token_program = get_token_program(&client, &args.mint);
+        let token_vault = get_associated_token_address_with_program_id(
+            &distributor_pubkey,
+            &args.mint,
+            &token_program,
+        );
+
+        Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::NewDistributor {
+                distributor: distributor_pubkey,
+                mint: args.mint,
+                token_vault,
+                clawback_receiver: new_distributor_args.clawback_receiver_token_account,
+                creator: keypair.pubkey(),
+                system_program: solana_program::system_program::id(),
+                associated_token_program: spl_associated_token_account::ID,
+                token_program,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::NewDistributor {
+                version: args.airdrop_version,
+                root: merkle_tree.merkle_root,
+                max_total_claim: merkle_tree.max_total_claim,
+                max_num_nodes: merkle_tree.max_num_nodes,
+                start_vesting_ts: new_distributor_args.start_vesting_ts,
+                end_vesting_ts: new_distributor_args.end_vesting_ts,
+                cliff_vesting_ts: new_distributor_args.cliff_vesting_ts(),
+                cliff_percentage: new_distributor_args.cliff_percentage,
+                vesting_mode: new_distributor_args.vesting_mode(),
+                clawback_start_ts: new_distributor_args.clawback_start_ts,
+                claim_bitmap: merkle_tree.is_indexed(),
+                claim_end_ts: new_distributor_args.claim_end_ts(),
+                revocable: new_distributor_args.revocable,
+                recoverable: new_distributor_args.recoverable,
+            }
+            .data(),
         }
-        .data(),
     };
 
     let blockhash = client.get_latest_blockhash().unwrap();
@@ -428,42 +1394,95 @@ fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributorArg
             }
         }
     }
+
+    if merkle_tree.is_indexed() {
+        init_claim_bitmaps(
+            &client,
+            args,
+            &keypair,
+            &distributor_pubkey,
+            merkle_tree.max_num_nodes,
+        );
+    }
 }
 
-fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
-    let payer_keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
-    let clawback_receiver_token_account = args
-        .clawback_receiver_token_account
-        .unwrap_or(payer_keypair.pubkey());
-    let clawback_ata = get_associated_token_address(&clawback_receiver_token_account, &args.mint);
+/// Initializes the claim bitmap pages of a distributor that don't exist yet
+fn init_claim_bitmaps(
+    client: &RpcClient,
+    args: &Args,
+    keypair: &Keypair,
+    distributor: &Pubkey,
+    max_num_nodes: u64,
+) {
+    for page in 0..ClaimBitmap::num_pages(max_num_nodes) {
+        let (claim_bitmap, _bump) = get_claim_bitmap_pda(&args.program_id, distributor, page);
+        if client
+            .get_account_with_commitment(&claim_bitmap, CommitmentConfig::confirmed())
+            .unwrap()
+            .value
+            .is_some()
+        {
+            continue;
+        }
+
+        let init_claim_bitmap_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::InitClaimBitmap {
+                distributor: *distributor,
+                claim_bitmap,
+                payer: keypair.pubkey(),
+                system_program: solana_program::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::InitClaimBitmap { page }.data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[init_claim_bitmap_ix],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        let signature = client
+            .send_and_confirm_transaction_with_spinner(&tx)
+            .unwrap();
+        println!("initialized claim bitmap page {page} with signature {signature:#?}");
+    }
+}
+
+fn process_revoke_claim(args: &Args, revoke_args: &RevokeClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let token_program = get_token_program(&client, &args.mint);
 
-    let from = get_associated_token_address(&clawback_args.distributor, &args.mint);
-    println!("from: {from}");
-    println!("distributor: {}", clawback_args.distributor);
-    println!("from: {}", from);
-    println!("to: {}", clawback_ata);
-    println!("payer: {}", payer_keypair.pubkey());
+    let distributor = fetch_distributor(&client, &revoke_args.distributor);
+    let (claim_status, _bump) = get_claim_status_pda(
+        &args.program_id,
+        &revoke_args.claimant,
+        &revoke_args.distributor,
+    );
 
-    let clawback_ix = Instruction {
+    let revoke_ix = Instruction {
         program_id: args.program_id,
-        accounts: merkle_distributor::accounts::Clawback {
-            distributor: clawback_args.distributor,
-            from,
-            to: clawback_ata,
-            admin: payer_keypair.pubkey(),
-            system_program: solana_program::system_program::ID,
-            token_program: token::ID,
+        accounts: merkle_distributor::accounts::RevokeClaim {
+            distributor: revoke_args.distributor,
+            claim_status,
+            from: distributor.token_vault,
+            to: distributor.clawback_receiver,
+            admin: keypair.pubkey(),
+            mint: args.mint,
+            token_program,
         }
         .to_account_metas(None),
-        data: merkle_distributor::instruction::Clawback {}.data(),
+        data: merkle_distributor::instruction::RevokeClaim {}.data(),
     };
 
     let tx = Transaction::new_signed_with_payer(
-        &[clawback_ix],
-        Some(&payer_keypair.pubkey()),
-        &[&payer_keypair],
+        &[revoke_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
         client.get_latest_blockhash().unwrap(),
     );
 
@@ -471,39 +1490,65 @@ fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
         .send_and_confirm_transaction_with_spinner(&tx)
         .unwrap();
 
-    println!("Successfully clawed back funds! signature: {signature:#?}");
-}
-
-fn process_create_merkle_tree(merkle_tree_args: &CreateMerkleTreeArgs) {
-    let merkle_tree = AirdropMerkleTree::new_from_csv(&merkle_tree_args.csv_path).unwrap();
-    merkle_tree.write_to_file(&merkle_tree_args.merkle_tree_path);
+    println!("Successfully revoked claim! signature: {signature:#?}");
 }
 
-fn process_set_admin(args: &Args, set_admin_args: &SetAdminArgs) {
+fn process_fund_distributor(args: &Args, fund_args: &FundDistributorArgs) {
     let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let (distributor, _bump) = get_merkle_distributor_pda(
-        &args.program_id,
-        &args.mint,
-        &set_admin_args.distributor,
-        args.airdrop_version,
+    let distributor = fetch_distributor(&client, &fund_args.distributor);
+    let shortfall = distributor.funding_shortfall(get_vault_balance(&client, &distributor));
+    println!(
+        "Distributor total funded: {}, funding shortfall: {}",
+        distributor.total_funded, shortfall
     );
 
-    let set_admin_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::SetAdmin {
-            distributor,
-            admin: keypair.pubkey(),
-            new_admin: set_admin_args.new_admin,
+    let amount = fund_args.amount.unwrap_or(shortfall);
+    let fund_ix = if distributor.native_sol {
+        Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::FundSolDistributor {
+                distributor: fund_args.distributor,
+                sol_vault: distributor.token_vault,
+                creator: keypair.pubkey(),
+                system_program: solana_program::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::FundSolDistributor { amount }.data(),
+        }
+    } else {
+        let token_program = get_token_program(&client, &args.mint);
+        let mut accounts = merkle_distributor::accounts::FundDistributor {
+            distributor: fund_args.distributor,
+            token_vault: distributor.token_vault,
+            from: get_associated_token_address_with_program_id(
+                &keypair.pubkey(),
+                &args.mint,
+                &token_program,
+            ),
+            creator: keypair.pubkey(),
+            mint: args.mint,
+            token_program,
+        }
+        .to_account_metas(None);
+        // the distributor is only funded once the extra mint vaults hold their totals too
+        accounts.extend(
+            distributor
+                .extra_mints()
+                .iter()
+                .map(|extra_mint| AccountMeta::new_readonly(extra_mint.token_vault, false)),
+        );
+        Instruction {
+            program_id: args.program_id,
+            accounts,
+            data: merkle_distributor::instruction::FundDistributor { amount }.data(),
         }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::SetAdmin {}.data(),
     };
 
     let tx = Transaction::new_signed_with_payer(
-        &[set_admin_ix],
+        &[fund_ix],
         Some(&keypair.pubkey()),
         &[&keypair],
         client.get_latest_blockhash().unwrap(),
@@ -512,38 +1557,839 @@ fn process_set_admin(args: &Args, set_admin_args: &SetAdminArgs) {
     let signature = client
         .send_and_confirm_transaction_with_spinner(&tx)
         .unwrap();
+    println!("Successfully funded distributor with {amount}! signature: {signature:#?}");
 
-    println!("Successfully set admin! signature: {signature:#?}");
-}
-
-fn process_distributor_pda(args: &Args) {
-    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
-    let (distributor_pubkey, _bump) = get_merkle_distributor_pda(
-        &args.program_id,
-        &args.mint,
-        &keypair.pubkey(),
-        args.airdrop_version,
+    let distributor = fetch_distributor(&client, &fund_args.distributor);
+    println!(
+        "Distributor funded: {}, total funded: {}, funding shortfall: {}",
+        distributor.funded,
+        distributor.total_funded,
+        distributor.funding_shortfall(get_vault_balance(&client, &distributor))
     );
-    println!("{distributor_pubkey}");
 }
 
-fn process_withdraw_custody_sol(args: &Args, withdraw_args: &WithdrawCustodySolArgs) {
-    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
-    let owner = keypair.pubkey();
-    let program_id = args.program_id;
-
-    let (sol_custody, _bump) =
-        Pubkey::find_program_address(&[b"SolCustody", owner.as_ref()], &program_id);
+fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
+    let payer_keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
 
-    let accounts = vec![
-        solana_sdk::instruction::AccountMeta::new(sol_custody, false),
-        solana_sdk::instruction::AccountMeta::new(owner, true),
-        solana_sdk::instruction::AccountMeta::new(solana_program::system_program::ID, false),
-    ];
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let ix = Instruction {
-        program_id,
+    // the tokens can only be clawed back to the receiver stored in the distributor
+    let distributor = fetch_distributor(&client, &clawback_args.distributor);
+    let from = distributor.token_vault;
+    let to = distributor.clawback_receiver;
+    println!("distributor: {}", clawback_args.distributor);
+    println!("from: {}", from);
+    println!("to: {}", to);
+    println!("payer: {}", payer_keypair.pubkey());
+    if clawback_args.unclaimed_only {
+        println!(
+            "unclaimed: {}, locked outstanding: {}",
+            distributor.amount_unclaimed(),
+            distributor.total_locked_outstanding
+        );
+    }
+
+    let mut ixs = vec![];
+    let clawback_ix = if distributor.native_sol {
+        let data = match clawback_args.unclaimed_only {
+            true => merkle_distributor::instruction::ClawbackUnclaimedSol {}.data(),
+            false => merkle_distributor::instruction::ClawbackSol {}.data(),
+        };
+        Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::ClawbackSol {
+                distributor: clawback_args.distributor,
+                sol_vault: from,
+                to,
+                admin: payer_keypair.pubkey(),
+                system_program: solana_program::system_program::ID,
+            }
+            .to_account_metas(None),
+            data,
+        }
+    } else {
+        let data = match clawback_args.unclaimed_only {
+            true => merkle_distributor::instruction::ClawbackUnclaimed {}.data(),
+            false => merkle_distributor::instruction::Clawback {}.data(),
+        };
+        // the extra mints go to the associated token accounts of the clawback receiver's owner
+        let receiver_owner = client
+            .get_token_account(&to)
+            .expect("Failed fetching clawback receiver")
+            .expect("Clawback receiver not found")
+            .owner
+            .parse()
+            .expect("Failed parsing clawback receiver owner");
+        let extra_mint_accounts = extra_mint_account_metas(
+            &client,
+            &distributor,
+            &receiver_owner,
+            &payer_keypair.pubkey(),
+            &mut ixs,
+        );
+        let mut clawback_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::Clawback {
+                distributor: clawback_args.distributor,
+                from,
+                to,
+                admin: payer_keypair.pubkey(),
+                system_program: solana_program::system_program::ID,
+                mint: args.mint,
+                token_program: get_token_program(&client, &args.mint),
+            }
+            .to_account_metas(None),
+            data,
+        };
+        clawback_ix.accounts.extend(extra_mint_accounts);
+        clawback_ix
+    };
+    ixs.push(clawback_ix);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&payer_keypair.pubkey()),
+        &[&payer_keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully clawed back funds! signature: {signature:#?}");
+}
+
+fn process_add_extra_mint(args: &Args, add_extra_mint_args: &AddExtraMintArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    // extra mints are added in the order of the amounts in the merkle tree leaves, the tree's
+    // root replaces the distributor's as leaves hash their extra amounts
+    let distributor = fetch_distributor(&client, &add_extra_mint_args.distributor);
+    let merkle_tree = AirdropMerkleTree::new_from_file(&add_extra_mint_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+    let index = distributor.num_extra_mints as usize;
+    assert!(
+        index < merkle_tree.num_extra_mints(),
+        "merkle tree has no amounts for extra mint {index}"
+    );
+    let max_total_claim = merkle_tree.extra_max_total_claim(index);
+
+    let mint = add_extra_mint_args.extra_mint;
+    let token_program = get_token_program(&client, &mint);
+    let token_vault = get_associated_token_address_with_program_id(
+        &add_extra_mint_args.distributor,
+        &mint,
+        &token_program,
+    );
+
+    let mut ixs = vec![];
+    if client
+        .get_account_with_commitment(&token_vault, CommitmentConfig::confirmed())
+        .unwrap()
+        .value
+        .is_none()
+    {
+        ixs.push(create_associated_token_account(
+            &keypair.pubkey(),
+            &add_extra_mint_args.distributor,
+            &mint,
+            &token_program,
+        ));
+    }
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::AddExtraMint {
+            distributor: add_extra_mint_args.distributor,
+            mint,
+            token_vault,
+            admin: keypair.pubkey(),
+            token_program,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::AddExtraMint {
+            root: merkle_tree.merkle_root,
+            max_total_claim,
+        }
+        .data(),
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!(
+        "Successfully added extra mint {mint} with max total claim {max_total_claim}, fund {token_vault} to pay it out! signature: {signature:#?}"
+    );
+}
+
+/// Returns the remaining accounts paying out the distributor's extra mints to the owner's
+/// associated token accounts, pushing instructions creating the missing ones.
+fn extra_mint_account_metas(
+    client: &RpcClient,
+    distributor: &MerkleDistributor,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    ixs: &mut Vec<Instruction>,
+) -> Vec<AccountMeta> {
+    let mut accounts = vec![];
+    for extra_mint in distributor.extra_mints() {
+        let token_program = get_token_program(client, &extra_mint.mint);
+        let to =
+            get_associated_token_address_with_program_id(owner, &extra_mint.mint, &token_program);
+        if client
+            .get_account_with_commitment(&to, CommitmentConfig::confirmed())
+            .unwrap()
+            .value
+            .is_none()
+        {
+            ixs.push(create_associated_token_account(
+                payer,
+                owner,
+                &extra_mint.mint,
+                &token_program,
+            ));
+        }
+        println!("extra mint {} to {to}", extra_mint.mint);
+        accounts.extend([
+            AccountMeta::new(extra_mint.token_vault, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(extra_mint.mint, false),
+            AccountMeta::new_readonly(token_program, false),
+        ]);
+    }
+    accounts
+}
+
+/// Whether a new claim of node would already have vested locked tokens, of the distributor's mint
+/// or an extra mint, at the cluster's clock. Claim_locked fails without any, and once vested the
+/// amount never shrinks before the transaction lands.
+fn new_claim_vested(client: &RpcClient, distributor: &MerkleDistributor, node: &TreeNode) -> bool {
+    let clock_account = client
+        .get_account(&sysvar::clock::ID)
+        .expect("failed to fetch the clock");
+    let clock: Clock = from_account(&clock_account).expect("failed to parse the clock");
+
+    let claim_status = ClaimStatus {
+        start_ts: node.start_ts.unwrap_or(distributor.start_ts),
+        end_ts: node.end_ts.unwrap_or(distributor.end_ts),
+        ..ClaimStatus::default()
+    };
+    let cliff_ts = distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts);
+    std::iter::once(node.amount_locked())
+        .chain(node.extra_amounts.iter().map(|extra| extra.total_locked))
+        .any(|locked_amount| {
+            ClaimStatus {
+                locked_amount,
+                ..claim_status.clone()
+            }
+            .amount_withdrawable(
+                clock.unix_timestamp,
+                claim_status.start_ts,
+                claim_status.end_ts,
+                cliff_ts,
+                distributor.cliff_percentage,
+                &distributor.vesting_mode,
+            )
+            .expect("failed to compute the vested amount")
+                > 0
+        })
+}
+
+fn fetch_distributor(client: &RpcClient, distributor: &Pubkey) -> MerkleDistributor {
+    let account = client
+        .get_account(distributor)
+        .expect("Failed fetching distributor account");
+    MerkleDistributor::try_deserialize_any_layout(&account.data)
+        .expect("Failed deserializing distributor account")
+}
+
+/// Returns the raw token amount held by a token account.
+fn get_token_balance(client: &RpcClient, token_account: &Pubkey) -> u64 {
+    client
+        .get_token_account_balance(token_account)
+        .expect("Failed fetching token account balance")
+        .amount
+        .parse()
+        .expect("Failed parsing token account balance")
+}
+
+/// Returns the amount the distributor vault holds for distribution, the lamports above the
+/// rent-exempt reserve for native SOL distributors.
+fn get_vault_balance(client: &RpcClient, distributor: &MerkleDistributor) -> u64 {
+    if !distributor.native_sol {
+        return get_token_balance(client, &distributor.token_vault);
+    }
+
+    let lamports = client
+        .get_balance(&distributor.token_vault)
+        .expect("Failed fetching SOL vault balance");
+    let rent_exempt_reserve = client
+        .get_minimum_balance_for_rent_exemption(0)
+        .expect("Failed fetching rent-exempt minimum");
+    lamports.saturating_sub(rent_exempt_reserve)
+}
+
+/// Returns the token program owning the mint, either SPL Token or Token-2022.
+fn get_token_program(client: &RpcClient, mint: &Pubkey) -> Pubkey {
+    client
+        .get_account(mint)
+        .expect("Failed fetching mint account")
+        .owner
+}
+
+fn process_create_merkle_tree(merkle_tree_args: &CreateMerkleTreeArgs) {
+    let merkle_tree = if let Some(base_merkle_tree_path) = &merkle_tree_args.base_merkle_tree_path {
+        let base = AirdropMerkleTree::new_from_file(base_merkle_tree_path)
+            .expect("failed to load base merkle tree from file");
+        AirdropMerkleTree::new_indexed_appended_from_csv(&base, &merkle_tree_args.csv_path).unwrap()
+    } else if merkle_tree_args.claim_bitmap {
+        AirdropMerkleTree::new_indexed_from_csv(&merkle_tree_args.csv_path).unwrap()
+    } else {
+        AirdropMerkleTree::new_from_csv(&merkle_tree_args.csv_path).unwrap()
+    };
+    if let Some(clawback_start_ts) = merkle_tree_args.clawback_start_ts {
+        merkle_tree
+            .validate_vesting_ends_by(clawback_start_ts - MIN_CLAWBACK_DELAY)
+            .expect("leaf vesting ends too close to the clawback start");
+    }
+    merkle_tree.write_to_file(&merkle_tree_args.merkle_tree_path);
+}
+
+fn process_propose_admin(args: &Args, propose_admin_args: &ProposeAdminArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let propose_admin_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::ProposeAdmin {
+            distributor: propose_admin_args.distributor,
+            admin: keypair.pubkey(),
+            new_admin: propose_admin_args.new_admin,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::ProposeAdmin {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_admin_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!(
+        "Successfully proposed admin {}! signature: {signature:#?}",
+        propose_admin_args.new_admin
+    );
+}
+
+fn process_accept_admin(args: &Args, accept_admin_args: &AdminProposalArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let accept_admin_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::AcceptAdmin {
+            distributor: accept_admin_args.distributor,
+            new_admin: keypair.pubkey(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::AcceptAdmin {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accept_admin_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully accepted admin! signature: {signature:#?}");
+}
+
+fn process_cancel_admin_proposal(args: &Args, cancel_args: &AdminProposalArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let cancel_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::CancelAdminProposal {
+            distributor: cancel_args.distributor,
+            admin: keypair.pubkey(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::CancelAdminProposal {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully cancelled admin proposal! signature: {signature:#?}");
+}
+
+fn process_migrate_distributor(args: &Args, migrate_args: &MigrateDistributorArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor = fetch_distributor(&client, &migrate_args.distributor);
+    if !distributor.needs_migration() {
+        println!(
+            "Distributor already has layout version {}",
+            distributor.layout_version
+        );
+        return;
+    }
+
+    let migrate_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::MigrateDistributor {
+            distributor: migrate_args.distributor,
+            token_vault: distributor.token_vault,
+            clawback_receiver: migrate_args.clawback_receiver_token_account,
+            admin: keypair.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::MigrateDistributor {
+            claim_end_ts: migrate_args.claim_end_ts,
+        }
+        .data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!(
+        "Successfully migrated distributor from layout version {}! signature: {signature:#?}",
+        distributor.layout_version
+    );
+}
+
+fn process_migrate_claim_status(args: &Args, migrate_args: &MigrateClaimStatusArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor = fetch_distributor(&client, &migrate_args.distributor);
+    assert!(
+        !distributor.needs_migration(),
+        "Distributor has to be migrated first"
+    );
+    let claimant = migrate_args.claimant.unwrap_or(keypair.pubkey());
+    let (claim_status_pda, _bump) =
+        get_claim_status_pda(&args.program_id, &claimant, &migrate_args.distributor);
+    let account = client
+        .get_account(&claim_status_pda)
+        .expect("Failed fetching claim status account");
+    let claim_status = ClaimStatus::try_deserialize_any_layout(&account.data, &distributor)
+        .expect("Failed deserializing claim status account");
+    if !claim_status.needs_migration() {
+        println!(
+            "Claim status already has layout version {}",
+            claim_status.layout_version
+        );
+        return;
+    }
+
+    let migrate_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::MigrateClaimStatus {
+            distributor: migrate_args.distributor,
+            claim_status: claim_status_pda,
+            payer: keypair.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::MigrateClaimStatus {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!(
+        "Successfully migrated claim status from layout version {}! signature: {signature:#?}",
+        claim_status.layout_version
+    );
+}
+
+fn process_set_paused(args: &Args, pause_args: &PauseArgs, paused: bool) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let accounts = merkle_distributor::accounts::SetPaused {
+        distributor: pause_args.distributor,
+        admin: keypair.pubkey(),
+    }
+    .to_account_metas(None);
+    let data = if paused {
+        merkle_distributor::instruction::Pause {}.data()
+    } else {
+        merkle_distributor::instruction::Unpause {}.data()
+    };
+    let set_paused_ix = Instruction {
+        program_id: args.program_id,
         accounts,
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_paused_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    if paused {
+        println!("Successfully paused distributor! signature: {signature:#?}");
+    } else {
+        println!("Successfully unpaused distributor! signature: {signature:#?}");
+    }
+}
+
+fn process_set_permissionless_claims(
+    args: &Args,
+    set_permissionless_claims_args: &SetPermissionlessClaimsArgs,
+) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let permissionless_claims = !set_permissionless_claims_args.disable;
+    let set_permissionless_claims_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::SetPermissionlessClaims {
+            distributor: set_permissionless_claims_args.distributor,
+            admin: keypair.pubkey(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::SetPermissionlessClaims {
+            permissionless_claims,
+        }
+        .data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_permissionless_claims_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!(
+        "Successfully set permissionless claims to {permissionless_claims}! signature: {signature:#?}"
+    );
+}
+
+fn process_set_clawback_receiver(
+    args: &Args,
+    set_clawback_receiver_args: &SetClawbackReceiverArgs,
+) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let set_clawback_receiver_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::SetClawbackReceiver {
+            distributor: set_clawback_receiver_args.distributor,
+            new_clawback_account: set_clawback_receiver_args.new_clawback_receiver_token_account,
+            admin: keypair.pubkey(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::SetClawbackReceiver {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_clawback_receiver_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully set clawback receiver! signature: {signature:#?}");
+}
+
+fn process_set_staking_program(args: &Args, set_staking_program_args: &SetStakingProgramArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let set_staking_program_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::SetStakingProgram {
+            distributor: set_staking_program_args.distributor,
+            staking_program: set_staking_program_args.staking_program,
+            stake_pool: set_staking_program_args.stake_pool,
+            admin: keypair.pubkey(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::SetStakingProgram {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_staking_program_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("Successfully set staking program! signature: {signature:#?}");
+}
+
+fn process_stake_locked(args: &Args, stake_locked_args: &StakeLockedArgs, unstake: bool) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let distributor = fetch_distributor(&client, &stake_locked_args.distributor);
+    let leaf_claimant = stake_locked_args.leaf_claimant.unwrap_or(keypair.pubkey());
+    let (claim_status, _bump) = get_claim_status_pda(
+        &args.program_id,
+        &leaf_claimant,
+        &stake_locked_args.distributor,
+    );
+    let (stake_authority, _bump) = get_stake_authority_pda(&args.program_id, &claim_status);
+    let accounts = merkle_distributor::accounts::StakeLocked {
+        distributor: stake_locked_args.distributor,
+        claim_status,
+        token_vault: distributor.token_vault,
+        authority: keypair.pubkey(),
+        token_program: get_token_program(&client, &args.mint),
+        central_state: get_staking_central_state_pda(&distributor.staking_program).0,
+        stake_pool: distributor.stake_pool,
+        stake_authority,
+        stake_account: get_stake_account_pda(
+            &distributor.staking_program,
+            &stake_authority,
+            &distributor.stake_pool,
+        )
+        .0,
+        stake_vault: stake_locked_args.stake_vault,
+        fee_account: stake_locked_args.fee_account,
+        staking_program: distributor.staking_program,
+    }
+    .to_account_metas(None);
+    let amount = stake_locked_args.amount;
+    let data = match unstake {
+        true => merkle_distributor::instruction::UnstakeLocked { amount }.data(),
+        false => merkle_distributor::instruction::StakeLocked { amount }.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: args.program_id,
+            accounts,
+            data,
+        }],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    match unstake {
+        true => println!("Successfully unstaked {amount} locked tokens! signature: {signature:#?}"),
+        false => println!("Successfully staked {amount} locked tokens! signature: {signature:#?}"),
+    }
+}
+
+fn process_update_root(args: &Args, update_root_args: &UpdateRootArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+
+    let old_tree = AirdropMerkleTree::new_from_file(&update_root_args.old_merkle_tree_path)
+        .expect("failed to load old merkle tree from file");
+    let new_tree = AirdropMerkleTree::new_from_file(&update_root_args.new_merkle_tree_path)
+        .expect("failed to load new merkle tree from file");
+    let diff = old_tree
+        .diff(&new_tree, update_root_args.allow_changes)
+        .expect("New merkle tree is not append-only");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let distributor = fetch_distributor(&client, &update_root_args.distributor);
+    assert_eq!(
+        distributor.root, old_tree.merkle_root,
+        "Old merkle tree does not match the distributor's root"
+    );
+
+    // Claim status accounts keep the amounts and schedule they were created with,
+    // so an increase or new schedule would never reach claimants who already claimed.
+    // Only a paused distributor keeps a claim from landing between this check and the update.
+    if (!diff.increased.is_empty() || !diff.rescheduled.is_empty()) && !distributor.paused {
+        println!("Warning: the distributor is not paused, a claim may land before the update");
+    }
+    for claimant in diff.increased.iter().chain(diff.rescheduled.iter()) {
+        let claimed = match old_tree.get_node(claimant).index {
+            Some(index) => {
+                let (claim_bitmap, _bump) = get_claim_bitmap_pda(
+                    &args.program_id,
+                    &update_root_args.distributor,
+                    ClaimBitmap::page_of(index),
+                );
+                let account = client
+                    .get_account(&claim_bitmap)
+                    .expect("Failed fetching claim bitmap account");
+                ClaimBitmap::try_deserialize(&mut account.data.as_slice())
+                    .expect("Failed deserializing claim bitmap account")
+                    .is_claimed(index)
+            }
+            None => {
+                let (claim_status, _bump) =
+                    get_claim_status_pda(&args.program_id, claimant, &update_root_args.distributor);
+                client
+                    .get_account_with_commitment(&claim_status, CommitmentConfig::confirmed())
+                    .unwrap()
+                    .value
+                    .is_some()
+            }
+        };
+        assert!(
+            !claimed,
+            "Claimant {claimant} already claimed, their allocation or schedule can't be changed"
+        );
+    }
+
+    println!(
+        "Adding {} claimants, increasing {} allocations, rescheduling {} claimants, max total claim {} -> {}",
+        diff.added.len(),
+        diff.increased.len(),
+        diff.rescheduled.len(),
+        distributor.max_total_claim,
+        new_tree.max_total_claim
+    );
+
+    let update_root_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::UpdateRoot {
+            distributor: update_root_args.distributor,
+            admin: keypair.pubkey(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::UpdateRoot {
+            root: new_tree.merkle_root,
+            max_total_claim: new_tree.max_total_claim,
+            max_num_nodes: new_tree.max_num_nodes,
+        }
+        .data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_root_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+
+    println!("Successfully updated root! signature: {signature:#?}");
+
+    if distributor.claim_bitmap {
+        init_claim_bitmaps(
+            &client,
+            args,
+            &keypair,
+            &update_root_args.distributor,
+            new_tree.max_num_nodes,
+        );
+    }
+
+    println!(
+        "Fund the distributor with {} more tokens using fund-distributor",
+        new_tree.max_total_claim - distributor.max_total_claim
+    );
+}
+
+fn process_distributor_pda(args: &Args, distributor_pda_args: &DistributorPdaArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let (distributor_pubkey, _bump) = match distributor_pda_args.native_sol {
+        true => get_sol_distributor_pda(&args.program_id, &keypair.pubkey(), args.airdrop_version),
+        false => get_merkle_distributor_pda(
+            &args.program_id,
+            &args.mint,
+            &keypair.pubkey(),
+            args.airdrop_version,
+        ),
+    };
+    println!("{distributor_pubkey}");
+}
+
+fn process_withdraw_custody_sol(args: &Args, withdraw_args: &WithdrawCustodySolArgs) {
+    let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
+    let owner = keypair.pubkey();
+    let program_id = args.program_id;
+
+    let ix = Instruction {
+        program_id,
+        accounts: merkle_distributor::accounts::WithdrawCustodySol {
+            custody_vault: get_custody_vault_pda(&program_id, &owner).0,
+            owner,
+            system_program: solana_program::system_program::ID,
+            sol_custody: get_sol_custody_pda(&program_id, &owner).0,
+        }
+        .to_account_metas(None),
         data: merkle_distributor::instruction::WithdrawCustodySol {
             amount_lamports: withdraw_args.amount,
         }
@@ -559,15 +2405,26 @@ fn process_withdraw_custody_sol(args: &Args, withdraw_args: &WithdrawCustodySolA
     println!("Successfully withdrew SOL! signature: {sig:#?}");
 }
 
-fn process_stake_custody_sol(args: &Args, stake_args: &StakeCustodySolArgs) {
+fn process_deposit_custody_sol(args: &Args, deposit_args: &DepositCustodySolArgs) {
     let keypair = read_keypair_file(&args.keypair_path).expect("Failed reading keypair file");
     let owner = keypair.pubkey();
     let program_id = args.program_id;
 
-    let (sol_custody, _bump) =
-        Pubkey::find_program_address(&[b"SolCustody", owner.as_ref()], &program_id);
-
-    let ix = system_instruction::transfer(&owner, &sol_custody, stake_args.amount);
+    let ix = Instruction {
+        program_id,
+        accounts: merkle_distributor::accounts::DepositCustodySol {
+            sol_custody: get_sol_custody_pda(&program_id, &owner).0,
+            custody_vault: get_custody_vault_pda(&program_id, &owner).0,
+            owner,
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::DepositCustodySol {
+            amount_lamports: deposit_args.amount,
+            lock_until_ts: deposit_args.lock_until_ts,
+        }
+        .data(),
+    };
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
     let blockhash = client.get_latest_blockhash().unwrap();
@@ -575,5 +2432,39 @@ fn process_stake_custody_sol(args: &Args, stake_args: &StakeCustodySolArgs) {
     let sig = client
         .send_and_confirm_transaction_with_spinner(&tx)
         .unwrap();
-    println!("Successfully staked SOL! signature: {sig:#?}");
+    println!("Successfully deposited SOL! signature: {sig:#?}");
+}
+
+fn process_custody_sol_balance(args: &Args, balance_args: &CustodySolBalanceArgs) {
+    let owner = balance_args.owner.unwrap_or_else(|| {
+        read_keypair_file(&args.keypair_path)
+            .expect("Failed reading keypair file")
+            .pubkey()
+    });
+    let (sol_custody, _bump) = get_sol_custody_pda(&args.program_id, &owner);
+    let (custody_vault, _bump) = get_custody_vault_pda(&args.program_id, &owner);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let vault_lamports = client
+        .get_balance(&custody_vault)
+        .expect("Failed fetching custody vault balance");
+    println!("custody vault {custody_vault}: {vault_lamports} lamports");
+
+    // lamports sent to the vault directly are counted on the next deposit or withdrawal, the whole
+    // vault is withdrawable
+    println!("balance: {vault_lamports} lamports");
+    match client
+        .get_account_with_commitment(&sol_custody, CommitmentConfig::confirmed())
+        .expect("Failed fetching sol custody account")
+        .value
+    {
+        Some(account) => {
+            let custody = SolCustody::try_deserialize(&mut account.data.as_slice())
+                .expect("Failed deserializing sol custody account");
+            println!("total deposited: {}", custody.total_deposited);
+            println!("total withdrawn: {}", custody.total_withdrawn);
+            println!("locked until: {}", custody.lock_until_ts);
+        }
+        None => println!("no sol custody account {sol_custody}"),
+    }
 }
@@ -0,0 +1,201 @@
+//! Decodes the events emitted by the merkle distributor program from transaction logs.
+//!
+//! Anchor logs each event as `Program data: <base64>`, the base64 encoded event
+//! discriminator followed by the borsh serialized event.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use merkle_distributor::state::events::*;
+use solana_program::pubkey::Pubkey;
+
+macro_rules! distributor_events {
+    ($($variant:ident($event:ty)),* $(,)?) => {
+        /// An event emitted by the merkle distributor program.
+        #[derive(Debug)]
+        pub enum DistributorEvent {
+            $($variant($event),)*
+        }
+
+        impl DistributorEvent {
+            /// Decodes an event from its discriminator and borsh serialized fields,
+            /// None for data of other events.
+            pub fn decode(data: &[u8]) -> Option<Self> {
+                let (discriminator, mut fields) = (data.get(..8)?, data.get(8..)?);
+                $(
+                    if discriminator == <$event>::DISCRIMINATOR {
+                        return <$event>::deserialize(&mut fields).ok().map(Self::$variant);
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+distributor_events! {
+    NewDistributor(NewDistributorEvent),
+    Funded(FundedEvent),
+    NewClaim(NewClaimEvent),
+    Claimed(ClaimedEvent),
+    Staked(StakedEvent),
+    Unstaked(UnstakedEvent),
+    ClaimBitmapInitialized(ClaimBitmapInitializedEvent),
+    ClaimStatusClosed(ClaimStatusClosedEvent),
+    Clawback(ClawbackEvent),
+    ClaimRevoked(ClaimRevokedEvent),
+    ClaimTransferred(ClaimTransferredEvent),
+    AdminProposed(AdminProposedEvent),
+    AdminAccepted(AdminAcceptedEvent),
+    AdminProposalCancelled(AdminProposalCancelledEvent),
+    ClawbackReceiverSet(ClawbackReceiverSetEvent),
+    StakingProgramSet(StakingProgramSetEvent),
+    RootUpdated(RootUpdatedEvent),
+    ExtraMintAdded(ExtraMintAddedEvent),
+    Paused(PausedEvent),
+    PermissionlessClaimsSet(PermissionlessClaimsSetEvent),
+    DistributorMigrated(DistributorMigratedEvent),
+    ClaimStatusMigrated(ClaimStatusMigratedEvent),
+    CustodyDeposited(CustodyDepositedEvent),
+    CustodyWithdrawn(CustodyWithdrawnEvent),
+}
+
+/// Parses the events the program emitted from the log messages of a transaction.
+///
+/// Only data logged while the program itself is executing is decoded, so events of
+/// other programs, including programs invoked by the distributor, are skipped.
+pub fn parse_events(program_id: &Pubkey, logs: &[String]) -> Vec<DistributorEvent> {
+    let program_id = program_id.to_string();
+    let mut invocations: Vec<&str> = vec![];
+    let mut events = vec![];
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            if invocations.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            if let Some(event) = STANDARD
+                .decode(data)
+                .ok()
+                .and_then(|data| DistributorEvent::decode(&data))
+            {
+                events.push(event);
+            }
+        } else if let Some(invocation) = log.strip_prefix("Program ") {
+            let mut words = invocation.split(' ');
+            match (words.next(), words.next()) {
+                (Some(program), Some("invoke")) => invocations.push(program),
+                (Some(_), Some("success" | "failed:")) => {
+                    invocations.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Event;
+    use merkle_distributor::state::extra_mint::ExtraLeafAmount;
+
+    use super::*;
+
+    fn data_log(event: &impl Event) -> String {
+        format!("Program data: {}", STANDARD.encode(event.data()))
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let program_id = merkle_distributor::ID;
+        let staking_program = Pubkey::new_unique();
+        let distributor = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            "Program log: Instruction: NewClaimAndStake".to_string(),
+            data_log(&NewClaimEvent {
+                claimant,
+                timestamp: 100,
+                distributor,
+                amount_unlocked: 1_000,
+                amount_locked: 500,
+                extra_amounts: vec![ExtraLeafAmount {
+                    amount_unlocked: 20,
+                    amount_locked: 10,
+                }],
+            }),
+            format!("Program {staking_program} invoke [2]"),
+            // the staking program's own data is skipped, even if it looks like an event
+            data_log(&PausedEvent {
+                distributor,
+                paused: true,
+                timestamp: 100,
+            }),
+            format!("Program {staking_program} consumed 1000 of 190000 compute units"),
+            format!("Program {staking_program} success"),
+            data_log(&StakedEvent {
+                claimant,
+                stake_account: Pubkey::new_unique(),
+                amount: 1_000,
+                distributor,
+                timestamp: 100,
+            }),
+            "Program data: bm90IGFuIGV2ZW50".to_string(),
+            format!("Program {program_id} consumed 20000 of 200000 compute units"),
+            format!("Program {program_id} success"),
+            format!("Program {program_id} invoke [1]"),
+            "Program log: Instruction: ClaimLocked".to_string(),
+            data_log(&ClaimedEvent {
+                claimant,
+                amount: 250,
+                distributor,
+                timestamp: 200,
+                extra_amounts: vec![5],
+            }),
+            format!("Program {program_id} success"),
+        ];
+
+        let events = parse_events(&program_id, &logs);
+        assert_eq!(events.len(), 3);
+        match &events[0] {
+            DistributorEvent::NewClaim(event) => {
+                assert_eq!(event.claimant, claimant);
+                assert_eq!(event.distributor, distributor);
+                assert_eq!(event.amount_unlocked, 1_000);
+                assert_eq!(event.amount_locked, 500);
+                assert_eq!(
+                    event.extra_amounts,
+                    vec![ExtraLeafAmount {
+                        amount_unlocked: 20,
+                        amount_locked: 10,
+                    }]
+                );
+                assert_eq!(event.timestamp, 100);
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+        match &events[1] {
+            DistributorEvent::Staked(event) => {
+                assert_eq!(event.distributor, distributor);
+                assert_eq!(event.amount, 1_000);
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+        match &events[2] {
+            DistributorEvent::Claimed(event) => {
+                assert_eq!(event.amount, 250);
+                assert_eq!(event.extra_amounts, vec![5]);
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_unknown_data() {
+        assert!(DistributorEvent::decode(&[]).is_none());
+        assert!(DistributorEvent::decode(&[0; 16]).is_none());
+    }
+}
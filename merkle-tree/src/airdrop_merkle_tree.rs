@@ -15,7 +15,7 @@ use crate::{
     csv_entry::CsvEntry,
     error::{MerkleTreeError, MerkleTreeError::MerkleValidationError},
     merkle_tree::MerkleTree,
-    tree_node::TreeNode,
+    tree_node::{ExtraAmount, TreeNode},
     utils::{get_max_total_claim, get_proof},
 };
 
@@ -37,30 +37,109 @@ pub struct AirdropMerkleTree {
 
 pub type Result<T> = result::Result<T, MerkleTreeError>;
 
+/// Claimants added, with a larger allocation or with a different vesting schedule in an
+/// updated merkle tree.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MerkleTreeDiff {
+    pub added: Vec<Pubkey>,
+    /// Claimants with a larger allocation of the mint or any extra mint
+    pub increased: Vec<Pubkey>,
+    /// Claimants whose leaf vesting schedule changed
+    pub rescheduled: Vec<Pubkey>,
+}
+
 impl AirdropMerkleTree {
     pub fn new(tree_nodes: Vec<TreeNode>) -> Result<Self> {
+        Self::build(tree_nodes, false, &HashMap::new())
+    }
+
+    /// Create a merkle tree whose leaves include their index, for distributors tracking
+    /// claims in a bitmap. Only unlocked amounts without vesting schedules are supported.
+    pub fn new_indexed(tree_nodes: Vec<TreeNode>) -> Result<Self> {
+        Self::build(tree_nodes, true, &HashMap::new())
+    }
+
+    /// Create an indexed merkle tree updating base, the tree a bitmap distributor uses.
+    /// Claimants of base keep their index and new claimants get the next free indices, in
+    /// claimant order, so claims already recorded in the bitmap stay with their claimant.
+    pub fn new_indexed_appended(
+        base: &AirdropMerkleTree,
+        tree_nodes: Vec<TreeNode>,
+    ) -> Result<Self> {
+        if !base.is_indexed() {
+            return Err(MerkleValidationError(
+                "Base merkle tree is not indexed".to_string(),
+            ));
+        }
+        let base_indices = base
+            .tree_nodes
+            .iter()
+            .filter_map(|n| Some((n.claimant, n.index?)))
+            .collect();
+        Self::build(tree_nodes, true, &base_indices)
+    }
+
+    /// Build a tree with leaves in claimant order, or in the order of base_indices for claimants
+    /// it has, followed by the others in claimant order.
+    fn build(
+        tree_nodes: Vec<TreeNode>,
+        indexed: bool,
+        base_indices: &HashMap<Pubkey, u64>,
+    ) -> Result<Self> {
         // Combine tree nodes with the same claimant, while retaining original order
         let mut tree_nodes_map: IndexMap<Pubkey, TreeNode> = IndexMap::new();
         for tree_node in tree_nodes {
-            let claimant = tree_node.claimant;
-            tree_nodes_map
-                .entry(claimant)
-                .and_modify(|n| {
+            match tree_nodes_map.get_mut(&tree_node.claimant) {
+                Some(n) => {
+                    // A claimant only has one claim status, so it can only follow one schedule
+                    if n.vesting_schedule() != tree_node.vesting_schedule() {
+                        return Err(MerkleValidationError(format!(
+                            "duplicate claimant {} found with different vesting schedules",
+                            n.claimant
+                        )));
+                    }
+                    if n.extra_amounts.len() != tree_node.extra_amounts.len() {
+                        return Err(MerkleValidationError(format!(
+                            "duplicate claimant {} found with different numbers of extra mints",
+                            n.claimant
+                        )));
+                    }
                     println!("duplicate claimant {} found, combining", n.claimant);
                     n.total_unlocked = n
                         .total_unlocked
                         .checked_add(tree_node.total_unlocked)
                         .unwrap();
                     n.total_locked = n.total_locked.checked_add(tree_node.total_locked).unwrap();
-                })
-                .or_insert_with(|| tree_node); // If not exists, insert a new entry
+                    for (amount, other) in n.extra_amounts.iter_mut().zip(&tree_node.extra_amounts)
+                    {
+                        amount.total_unlocked = amount
+                            .total_unlocked
+                            .checked_add(other.total_unlocked)
+                            .unwrap();
+                        amount.total_locked =
+                            amount.total_locked.checked_add(other.total_locked).unwrap();
+                    }
+                }
+                None => {
+                    tree_nodes_map.insert(tree_node.claimant, tree_node);
+                }
+            }
         }
 
         // Convert IndexMap back to Vec while preserving the order
         let mut tree_nodes: Vec<TreeNode> = tree_nodes_map.values().cloned().collect();
 
-        // Sort by claimant pubkey for deterministic order
-        tree_nodes.sort_by_key(|n| n.claimant);
+        // Sort by claimant pubkey for deterministic order, keeping the base tree's leaves first
+        tree_nodes.sort_by_key(|n| {
+            (
+                base_indices.get(&n.claimant).copied().unwrap_or(u64::MAX),
+                n.claimant,
+            )
+        });
+
+        for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
+            tree_node.index = indexed.then_some(i as u64);
+        }
 
         let hashed_nodes = tree_nodes
             .iter()
@@ -94,12 +173,82 @@ impl AirdropMerkleTree {
 
     /// Load a merkle tree from a csv path
     pub fn new_from_csv(path: &PathBuf) -> Result<Self> {
-        let csv_entries = CsvEntry::new_from_file(path)?;
-        let tree_nodes: Vec<TreeNode> = csv_entries.into_iter().map(TreeNode::from).collect();
-        let tree = Self::new(tree_nodes)?;
+        let tree = Self::new(Self::tree_nodes_from_csv(path)?)?;
+        Ok(tree)
+    }
+
+    /// Load an indexed merkle tree from a csv path
+    pub fn new_indexed_from_csv(path: &PathBuf) -> Result<Self> {
+        let tree = Self::new_indexed(Self::tree_nodes_from_csv(path)?)?;
+        Ok(tree)
+    }
+
+    /// Load an indexed merkle tree updating base from a csv path, see [Self::new_indexed_appended]
+    pub fn new_indexed_appended_from_csv(base: &AirdropMerkleTree, path: &PathBuf) -> Result<Self> {
+        let tree = Self::new_indexed_appended(base, Self::tree_nodes_from_csv(path)?)?;
         Ok(tree)
     }
 
+    /// Read the tree nodes of a csv, rows leaving the last extra mint columns empty get 0
+    /// amounts of those mints
+    fn tree_nodes_from_csv(path: &PathBuf) -> Result<Vec<TreeNode>> {
+        let csv_entries = CsvEntry::new_from_file(path)?;
+        let mut tree_nodes: Vec<TreeNode> = csv_entries.into_iter().map(TreeNode::from).collect();
+
+        let num_extra_mints = tree_nodes
+            .iter()
+            .map(|n| n.extra_amounts.len())
+            .max()
+            .unwrap_or(0);
+        for tree_node in tree_nodes.iter_mut() {
+            tree_node
+                .extra_amounts
+                .resize(num_extra_mints, ExtraAmount::default());
+        }
+
+        Ok(tree_nodes)
+    }
+
+    /// Whether the leaves include their index, i.e. claims are tracked in a bitmap
+    pub fn is_indexed(&self) -> bool {
+        self.tree_nodes.iter().any(|n| n.index.is_some())
+    }
+
+    /// Number of extra mints every leaf carries an amount of
+    pub fn num_extra_mints(&self) -> usize {
+        self.tree_nodes.first().map_or(0, |n| n.extra_amounts.len())
+    }
+
+    /// Validate that every leaf's own vesting schedule ends by latest_end_ts, i.e. the
+    /// distributor's clawback start minus the minimum clawback delay. A full clawback sweeps the
+    /// vault regardless of per-leaf schedules, so a leaf still vesting would lose its tokens.
+    pub fn validate_vesting_ends_by(&self, latest_end_ts: i64) -> Result<()> {
+        for node in self.tree_nodes.iter() {
+            if let Some((_, end_ts)) = node.vesting_schedule() {
+                if end_ts > latest_end_ts {
+                    return Err(MerkleValidationError(format!(
+                        "Claimant {} end_ts {} is after the latest vesting end {}",
+                        node.claimant, end_ts, latest_end_ts
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sum of the amounts of an extra mint over all leaves
+    pub fn extra_max_total_claim(&self, extra_mint_index: usize) -> u64 {
+        self.tree_nodes
+            .iter()
+            .try_fold(0, |acc: u64, n| {
+                let amount = n.extra_amounts[extra_mint_index];
+                acc.checked_add(amount.total_unlocked)?
+                    .checked_add(amount.total_locked)
+            })
+            .unwrap()
+    }
+
     /// Load a serialized merkle tree from file path
     pub fn new_from_file(path: &PathBuf) -> Result<Self> {
         let file = File::open(path)?;
@@ -153,6 +302,61 @@ impl AirdropMerkleTree {
             ));
         }
 
+        // validate that per-leaf vesting schedules are complete and well ordered
+        for node in self.tree_nodes.iter() {
+            if node.start_ts.is_some() != node.end_ts.is_some() {
+                return Err(MerkleValidationError(format!(
+                    "Claimant {} has only one of start_ts and end_ts set",
+                    node.claimant
+                )));
+            }
+            if let Some((start_ts, end_ts)) = node.vesting_schedule() {
+                if start_ts >= end_ts {
+                    return Err(MerkleValidationError(format!(
+                        "Claimant {} start_ts {} is not before end_ts {}",
+                        node.claimant, start_ts, end_ts
+                    )));
+                }
+            }
+        }
+
+        // validate that every leaf carries an amount of each extra mint
+        let num_extra_mints = self.num_extra_mints();
+        for node in self.tree_nodes.iter() {
+            if node.extra_amounts.len() != num_extra_mints {
+                return Err(MerkleValidationError(format!(
+                    "Claimant {} has {} extra mint amounts, expected {}",
+                    node.claimant,
+                    node.extra_amounts.len(),
+                    num_extra_mints
+                )));
+            }
+        }
+
+        // validate that indexed leaves are numbered in order and unlocked only
+        if self.is_indexed() {
+            for (i, node) in self.tree_nodes.iter().enumerate() {
+                if node.index != Some(i as u64) {
+                    return Err(MerkleValidationError(format!(
+                        "Claimant {} has index {:?}, expected {}",
+                        node.claimant, node.index, i
+                    )));
+                }
+                if node.total_locked != 0 || node.vesting_schedule().is_some() {
+                    return Err(MerkleValidationError(format!(
+                        "Claimant {} has locked tokens, which indexed trees don't support",
+                        node.claimant
+                    )));
+                }
+                if !node.extra_amounts.is_empty() {
+                    return Err(MerkleValidationError(format!(
+                        "Claimant {} has extra mint amounts, which indexed trees don't support",
+                        node.claimant
+                    )));
+                }
+            }
+        }
+
         // validate that sum is equal to max_total_claim
         let sum = get_max_total_claim(&self.tree_nodes);
 
@@ -204,6 +408,93 @@ impl AirdropMerkleTree {
         Ok(())
     }
 
+    /// Diff an updated merkle tree against this one, refusing updates that are not append-only:
+    /// every claimant has to keep at least their allocation of every mint, and indexed leaves
+    /// their index.
+    /// Claims keep the amounts and schedule they were created with, so an increased allocation
+    /// or changed vesting schedule never reaches a claimant who already claimed. They are refused
+    /// unless allow_changes is set, in which case they are reported for the caller to check the
+    /// claimants have not claimed.
+    pub fn diff(
+        &self,
+        new_tree: &AirdropMerkleTree,
+        allow_changes: bool,
+    ) -> Result<MerkleTreeDiff> {
+        if self.is_indexed() != new_tree.is_indexed() {
+            return Err(MerkleValidationError(
+                "Indexed and non-indexed merkle trees can't be diffed".to_string(),
+            ));
+        }
+        if self.num_extra_mints() != new_tree.num_extra_mints() {
+            return Err(MerkleValidationError(
+                "Merkle trees with different extra mints can't be diffed".to_string(),
+            ));
+        }
+
+        let new_nodes = new_tree.convert_to_hashmap();
+        let mut diff = MerkleTreeDiff::default();
+        for old_node in self.tree_nodes.iter() {
+            let new_node = new_nodes.get(&old_node.claimant).ok_or_else(|| {
+                MerkleValidationError(format!("Claimant {} was removed", old_node.claimant))
+            })?;
+            // Leaves are padded to the tree's number of extra mints, so the extra amounts line up
+            let amounts = [
+                (old_node.amount_unlocked(), new_node.amount_unlocked()),
+                (old_node.amount_locked(), new_node.amount_locked()),
+            ]
+            .into_iter()
+            .chain(
+                old_node
+                    .extra_amounts
+                    .iter()
+                    .zip(new_node.extra_amounts.iter())
+                    .flat_map(|(old, new)| {
+                        [
+                            (old.total_unlocked, new.total_unlocked),
+                            (old.total_locked, new.total_locked),
+                        ]
+                    }),
+            )
+            .collect::<Vec<_>>();
+
+            if amounts.iter().any(|(old, new)| new < old) {
+                return Err(MerkleValidationError(format!(
+                    "Claimant {} allocation shrinks",
+                    old_node.claimant
+                )));
+            }
+            if new_node.index != old_node.index {
+                return Err(MerkleValidationError(format!(
+                    "Claimant {} index changes from {:?} to {:?}",
+                    old_node.claimant, old_node.index, new_node.index
+                )));
+            }
+            if amounts.iter().any(|(old, new)| new > old) {
+                diff.increased.push(old_node.claimant);
+            }
+            if new_node.vesting_schedule() != old_node.vesting_schedule() {
+                diff.rescheduled.push(old_node.claimant);
+            }
+        }
+        if !allow_changes {
+            if let Some(claimant) = diff.increased.iter().chain(&diff.rescheduled).next() {
+                return Err(MerkleValidationError(format!(
+                    "Claimant {claimant} allocation or vesting schedule changes"
+                )));
+            }
+        }
+
+        let old_nodes = self.convert_to_hashmap();
+        diff.added = new_tree
+            .tree_nodes
+            .iter()
+            .filter(|n| !old_nodes.contains_key(&n.claimant))
+            .map(|n| n.claimant)
+            .collect();
+
+        Ok(diff)
+    }
+
     // Converts Merkle Tree to a map for faster key access
     pub fn convert_to_hashmap(&self) -> HashMap<Pubkey, TreeNode> {
         self.tree_nodes
@@ -217,6 +508,7 @@ impl AirdropMerkleTree {
 mod tests {
     use std::path::PathBuf;
 
+    use access_merkle_verify::hash_leaf;
     use solana_program::{pubkey, pubkey::Pubkey};
     use solana_sdk::{
         signature::{EncodableKey, Keypair},
@@ -249,6 +541,10 @@ mod tests {
                 proof: None,
                 total_unlocked: rand_balance(),
                 total_locked: rand_balance(),
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
             });
         }
 
@@ -264,6 +560,10 @@ mod tests {
             proof: None,
             total_unlocked: 2,
             total_locked: 3,
+            start_ts: None,
+            end_ts: None,
+            index: None,
+            extra_amounts: vec![],
         }];
         let merkle_tree = AirdropMerkleTree::new(tree_nodes).unwrap();
         assert!(merkle_tree.verify_proof().is_ok(), "verify failed");
@@ -278,18 +578,30 @@ mod tests {
                 proof: None,
                 total_unlocked: (100 * u64::pow(10, 9)),
                 total_locked: (100 * u64::pow(10, 9)),
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqED"),
                 proof: None,
                 total_unlocked: 100 * u64::pow(10, 9),
                 total_locked: (100 * u64::pow(10, 9)),
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqEH"),
                 proof: None,
                 total_locked: (100 * u64::pow(10, 9)),
                 total_unlocked: (100 * u64::pow(10, 9)),
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
             },
         ];
 
@@ -320,18 +632,30 @@ mod tests {
                 proof: None,
                 total_unlocked: 10,
                 total_locked: 20,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
             },
             TreeNode {
                 claimant: duplicate_pubkey,
                 proof: None,
                 total_unlocked: 1,
                 total_locked: 2,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
             },
             TreeNode {
                 claimant: Pubkey::new_unique(),
                 proof: None,
                 total_unlocked: 0,
                 total_locked: 0,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
             },
         ];
 
@@ -341,4 +665,383 @@ mod tests {
         assert_eq!(tree.tree_nodes[0].total_unlocked, 11);
         assert_eq!(tree.tree_nodes[0].total_locked, 22);
     }
+
+    #[test]
+    fn test_new_merkle_tree_extra_mints() {
+        let extra_amounts = |total_unlocked, total_locked| {
+            vec![
+                ExtraAmount {
+                    total_unlocked,
+                    total_locked,
+                },
+                ExtraAmount::default(),
+            ]
+        };
+        let duplicate_pubkey = Pubkey::new_unique();
+        let tree_node = TreeNode {
+            claimant: duplicate_pubkey,
+            proof: None,
+            total_unlocked: 10,
+            total_locked: 20,
+            start_ts: None,
+            end_ts: None,
+            index: None,
+            extra_amounts: extra_amounts(1, 2),
+        };
+        let tree_nodes = vec![
+            tree_node.clone(),
+            tree_node.clone(),
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                extra_amounts: extra_amounts(5, 0),
+                ..tree_node.clone()
+            },
+        ];
+
+        let tree = AirdropMerkleTree::new(tree_nodes).unwrap();
+        assert_eq!(tree.num_extra_mints(), 2);
+        assert_eq!(tree.tree_nodes[0].extra_amounts, extra_amounts(2, 4));
+        assert_eq!(tree.extra_max_total_claim(0), 11);
+        assert_eq!(tree.extra_max_total_claim(1), 0);
+        // the primary mint totals don't include the extra mints
+        assert_eq!(tree.max_total_claim, 90);
+
+        // every leaf has to carry an amount of each extra mint
+        let tree_nodes = vec![
+            tree_node.clone(),
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                extra_amounts: vec![],
+                ..tree_node
+            },
+        ];
+        assert!(AirdropMerkleTree::new(tree_nodes).is_err());
+    }
+
+    #[test]
+    fn test_extra_amounts_proof_rejected_as_vesting_schedule() {
+        let claimant = Pubkey::new_unique();
+        let tree_nodes = vec![
+            TreeNode {
+                claimant,
+                proof: None,
+                total_unlocked: 0,
+                total_locked: 1_000,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![ExtraAmount {
+                    total_unlocked: 1,
+                    total_locked: 2,
+                }],
+            },
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 5,
+                total_locked: 0,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![ExtraAmount::default()],
+            },
+        ];
+        let tree = AirdropMerkleTree::new(tree_nodes).unwrap();
+        let node = tree.get_node(&claimant);
+        let proof = node.proof.clone().unwrap();
+
+        let leaf = |vesting_schedule, extra_amounts: &[(u64, u64)]| {
+            let node = hash_leaf(
+                &claimant.to_bytes(),
+                0,
+                1_000,
+                vesting_schedule,
+                extra_amounts,
+                None,
+            );
+            hashv(&[LEAF_PREFIX, &node.to_bytes()]).to_bytes()
+        };
+        assert!(verify(
+            proof.clone(),
+            tree.merkle_root,
+            leaf(None, &[(1, 2)])
+        ));
+        // the extra amounts can't be claimed as a vesting schedule unlocking everything at once
+        assert!(!verify(proof, tree.merkle_root, leaf(Some((1, 2)), &[])));
+    }
+
+    #[test]
+    fn test_new_merkle_tree_vesting_schedules() {
+        let tree_nodes = vec![
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 10,
+                total_locked: 20,
+                start_ts: Some(100),
+                end_ts: Some(200),
+                index: None,
+                extra_amounts: vec![],
+            },
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 1,
+                total_locked: 2,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
+            },
+        ];
+
+        let tree = AirdropMerkleTree::new(tree_nodes).unwrap();
+        assert!(tree.verify_proof().is_ok(), "verify failed");
+    }
+
+    #[test]
+    fn test_validate_vesting_ends_by() {
+        let tree_nodes = vec![
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 0,
+                total_locked: 10,
+                start_ts: Some(100),
+                end_ts: Some(200),
+                index: None,
+                extra_amounts: vec![],
+            },
+            TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 10,
+                total_locked: 0,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
+            },
+        ];
+        let tree = AirdropMerkleTree::new(tree_nodes).unwrap();
+
+        assert!(tree.validate_vesting_ends_by(200).is_ok());
+        assert!(tree.validate_vesting_ends_by(199).is_err());
+    }
+
+    #[test]
+    fn test_new_merkle_tree_invalid_vesting_schedules() {
+        let claimant = Pubkey::new_unique();
+        let node = TreeNode {
+            claimant,
+            proof: None,
+            total_unlocked: 10,
+            total_locked: 20,
+            start_ts: Some(100),
+            end_ts: Some(200),
+            index: None,
+            extra_amounts: vec![],
+        };
+
+        // same claimant in two cohorts
+        let tree_nodes = vec![
+            node.clone(),
+            TreeNode {
+                start_ts: Some(200),
+                end_ts: Some(300),
+                ..node.clone()
+            },
+        ];
+        assert!(AirdropMerkleTree::new(tree_nodes).is_err());
+
+        // end before start
+        let tree_nodes = vec![TreeNode {
+            start_ts: Some(300),
+            ..node.clone()
+        }];
+        assert!(AirdropMerkleTree::new(tree_nodes).is_err());
+
+        // missing end
+        let tree_nodes = vec![TreeNode {
+            end_ts: None,
+            ..node
+        }];
+        assert!(AirdropMerkleTree::new(tree_nodes).is_err());
+    }
+
+    #[test]
+    fn test_new_indexed_merkle_tree() {
+        let tree_nodes = (0..3)
+            .map(|_| TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 10,
+                total_locked: 0,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
+            })
+            .collect::<Vec<_>>();
+
+        let tree = AirdropMerkleTree::new_indexed(tree_nodes.clone()).unwrap();
+        assert!(tree.is_indexed());
+        for (i, node) in tree.tree_nodes.iter().enumerate() {
+            assert_eq!(node.index, Some(i as u64));
+        }
+        assert_ne!(
+            tree.merkle_root,
+            AirdropMerkleTree::new(tree_nodes.clone())
+                .unwrap()
+                .merkle_root
+        );
+
+        // indexed trees are unlocked only
+        let mut locked_nodes = tree_nodes;
+        locked_nodes[0].total_locked = 1;
+        assert!(AirdropMerkleTree::new_indexed(locked_nodes).is_err());
+    }
+
+    #[test]
+    fn test_merkle_tree_diff() {
+        let tree_nodes = (0..3)
+            .map(|_| TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 10,
+                total_locked: 20,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
+            })
+            .collect::<Vec<_>>();
+        let old_tree = AirdropMerkleTree::new(tree_nodes.clone()).unwrap();
+
+        let mut new_nodes = tree_nodes.clone();
+        new_nodes[1].total_locked = 30;
+        new_nodes.push(TreeNode {
+            claimant: Pubkey::new_unique(),
+            ..tree_nodes[0].clone()
+        });
+        let new_tree = AirdropMerkleTree::new(new_nodes.clone()).unwrap();
+        assert_eq!(
+            old_tree.diff(&new_tree, true).unwrap(),
+            MerkleTreeDiff {
+                added: vec![new_nodes[3].claimant],
+                increased: vec![tree_nodes[1].claimant],
+                rescheduled: vec![],
+            }
+        );
+
+        // changed vesting schedules are reported
+        let mut rescheduled_nodes = tree_nodes.clone();
+        rescheduled_nodes[2].start_ts = Some(100);
+        rescheduled_nodes[2].end_ts = Some(200);
+        let rescheduled_tree = AirdropMerkleTree::new(rescheduled_nodes).unwrap();
+        assert_eq!(
+            old_tree.diff(&rescheduled_tree, true).unwrap(),
+            MerkleTreeDiff {
+                rescheduled: vec![tree_nodes[2].claimant],
+                ..MerkleTreeDiff::default()
+            }
+        );
+
+        // extra mint allocations are compared too
+        let extra_nodes = tree_nodes
+            .iter()
+            .map(|n| TreeNode {
+                extra_amounts: vec![ExtraAmount {
+                    total_unlocked: 5,
+                    total_locked: 5,
+                }],
+                ..n.clone()
+            })
+            .collect::<Vec<_>>();
+        let old_extra_tree = AirdropMerkleTree::new(extra_nodes.clone()).unwrap();
+        let mut increased_extra_nodes = extra_nodes.clone();
+        increased_extra_nodes[0].extra_amounts[0].total_locked = 6;
+        let increased_extra_tree = AirdropMerkleTree::new(increased_extra_nodes).unwrap();
+        assert_eq!(
+            old_extra_tree.diff(&increased_extra_tree, true).unwrap(),
+            MerkleTreeDiff {
+                increased: vec![tree_nodes[0].claimant],
+                ..MerkleTreeDiff::default()
+            }
+        );
+        let mut shrunk_extra_nodes = extra_nodes;
+        shrunk_extra_nodes[0].extra_amounts[0].total_unlocked = 4;
+        let shrunk_extra_tree = AirdropMerkleTree::new(shrunk_extra_nodes).unwrap();
+        assert!(old_extra_tree.diff(&shrunk_extra_tree, true).is_err());
+        assert!(old_tree.diff(&old_extra_tree, true).is_err());
+
+        // allocations can't shrink
+        let mut shrunk_nodes = tree_nodes.clone();
+        shrunk_nodes[0].total_unlocked = 9;
+        let shrunk_tree = AirdropMerkleTree::new(shrunk_nodes).unwrap();
+        assert!(old_tree.diff(&shrunk_tree, true).is_err());
+
+        // claimants can't be removed
+        let removed_tree = AirdropMerkleTree::new(tree_nodes[1..].to_vec()).unwrap();
+        assert!(old_tree.diff(&removed_tree, true).is_err());
+
+        // indexed leaves keep their index, so a claimant sorted first can't be added
+        let unlocked_nodes = tree_nodes
+            .iter()
+            .map(|n| TreeNode {
+                total_locked: 0,
+                ..n.clone()
+            })
+            .collect::<Vec<_>>();
+        let old_tree = AirdropMerkleTree::new_indexed(unlocked_nodes.clone()).unwrap();
+        let mut shifted_nodes = unlocked_nodes;
+        shifted_nodes.push(TreeNode {
+            claimant: Pubkey::default(),
+            ..shifted_nodes[0].clone()
+        });
+        let shifted_tree = AirdropMerkleTree::new_indexed(shifted_nodes).unwrap();
+        assert!(old_tree.diff(&shifted_tree, true).is_err());
+
+        // unless appended after the existing leaves
+        let appended_tree =
+            AirdropMerkleTree::new_indexed_appended(&old_tree, shifted_tree.tree_nodes.clone())
+                .unwrap();
+        assert_eq!(
+            old_tree.diff(&appended_tree, false).unwrap(),
+            MerkleTreeDiff {
+                added: vec![Pubkey::default()],
+                ..MerkleTreeDiff::default()
+            }
+        );
+        assert_eq!(appended_tree.get_node(&Pubkey::default()).index, Some(3));
+        appended_tree.verify_proof().unwrap();
+    }
+
+    #[test]
+    fn test_merkle_tree_diff_rejects_changes() {
+        let tree_nodes = (0..2)
+            .map(|_| TreeNode {
+                claimant: Pubkey::new_unique(),
+                proof: None,
+                total_unlocked: 10,
+                total_locked: 20,
+                start_ts: None,
+                end_ts: None,
+                index: None,
+                extra_amounts: vec![],
+            })
+            .collect::<Vec<_>>();
+        let old_tree = AirdropMerkleTree::new(tree_nodes.clone()).unwrap();
+
+        let mut increased_nodes = tree_nodes.clone();
+        increased_nodes[0].total_unlocked = 11;
+        let increased_tree = AirdropMerkleTree::new(increased_nodes).unwrap();
+        assert!(old_tree.diff(&increased_tree, false).is_err());
+
+        let mut rescheduled_nodes = tree_nodes;
+        rescheduled_nodes[1].start_ts = Some(100);
+        rescheduled_nodes[1].end_ts = Some(200);
+        let rescheduled_tree = AirdropMerkleTree::new(rescheduled_nodes).unwrap();
+        assert!(old_tree.diff(&rescheduled_tree, false).is_err());
+    }
 }
@@ -2,7 +2,7 @@ use std::{fs::File, path::PathBuf, result};
 
 use serde::{Deserialize, Serialize};
 
-use crate::error::MerkleTreeError;
+use crate::{error::MerkleTreeError, tree_node::ExtraAmount};
 
 pub type Result<T> = result::Result<T, MerkleTreeError>;
 /// Represents a single entry in a CSV
@@ -14,6 +14,24 @@ pub struct CsvEntry {
     pub amount_unlocked: u64,
     /// amount locked, (ui amount)
     pub amount_locked: u64,
+    /// lockup start (unix timestamp), leave empty to use the distributor's schedule
+    #[serde(default)]
+    pub start_vesting_ts: Option<i64>,
+    /// lockup end (unix timestamp), leave empty to use the distributor's schedule
+    #[serde(default)]
+    pub end_vesting_ts: Option<i64>,
+    /// amount unlocked of the first extra mint, leave empty for single mint distributors
+    #[serde(default)]
+    pub extra_amount_unlocked_1: Option<u64>,
+    /// amount locked of the first extra mint
+    #[serde(default)]
+    pub extra_amount_locked_1: Option<u64>,
+    /// amount unlocked of the second extra mint
+    #[serde(default)]
+    pub extra_amount_unlocked_2: Option<u64>,
+    /// amount locked of the second extra mint
+    #[serde(default)]
+    pub extra_amount_locked_2: Option<u64>,
 }
 
 impl CsvEntry {
@@ -29,6 +47,26 @@ impl CsvEntry {
 
         Ok(entries)
     }
+
+    /// Amounts of the extra mints up to the last one set, a missing amount is 0
+    pub fn extra_amounts(&self) -> Vec<ExtraAmount> {
+        let amounts = [
+            (self.extra_amount_unlocked_1, self.extra_amount_locked_1),
+            (self.extra_amount_unlocked_2, self.extra_amount_locked_2),
+        ];
+        let num_extra_mints = amounts
+            .iter()
+            .rposition(|(unlocked, locked)| unlocked.is_some() || locked.is_some())
+            .map_or(0, |i| i + 1);
+
+        amounts[..num_extra_mints]
+            .iter()
+            .map(|(unlocked, locked)| ExtraAmount {
+                total_unlocked: unlocked.unwrap_or(0),
+                total_locked: locked.unwrap_or(0),
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -48,5 +86,20 @@ mod tests {
         );
         assert_eq!(entries[0].amount_unlocked, 1000000000);
         assert_eq!(entries[0].amount_locked, 500000000);
+        assert_eq!(entries[0].start_vesting_ts, None);
+        assert_eq!(entries[0].end_vesting_ts, None);
+    }
+
+    #[test]
+    fn test_csv_parsing_vesting_schedules() {
+        let path = PathBuf::from("./test_fixtures/test_csv_vesting.csv");
+        let entries = CsvEntry::new_from_file(&path).expect("Failed to parse CSV");
+
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].start_vesting_ts, Some(1700000000));
+        assert_eq!(entries[0].end_vesting_ts, Some(1731536000));
+        assert_eq!(entries[2].start_vesting_ts, None);
+        assert_eq!(entries[2].end_vesting_ts, None);
     }
 }
@@ -1,10 +1,21 @@
 use std::str::FromStr;
 
+use access_merkle_verify::hash_leaf;
 use serde::{Deserialize, Serialize};
-use solana_program::{hash::hashv, pubkey::Pubkey};
+use solana_program::pubkey::Pubkey;
 use solana_sdk::hash::Hash;
 
 use crate::csv_entry::CsvEntry;
+
+/// Amounts of one of the distributor's extra mints in a leaf.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ExtraAmount {
+    /// Total amount unlocked
+    pub total_unlocked: u64,
+    /// Total amount locked
+    pub total_locked: u64,
+}
+
 /// Represents the claim information for an account.
 #[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct TreeNode {
@@ -16,15 +27,47 @@ pub struct TreeNode {
     pub total_unlocked: u64,
     /// Total amount locked
     pub total_locked: u64,
+    /// Lockup time start (Unix Timestamp), overrides the distributor's schedule when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_ts: Option<i64>,
+    /// Lockup time end (Unix Timestamp), overrides the distributor's schedule when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_ts: Option<i64>,
+    /// Leaf index, set for distributors tracking claims in a bitmap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<u64>,
+    /// Amounts of the distributor's extra mints, in the order the mints were added
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_amounts: Vec<ExtraAmount>,
 }
 
 impl TreeNode {
+    /// Leaves without their own vesting schedule, extra amounts or index hash the same way as
+    /// before these existed, so previously generated roots stay valid. Other leaves are tagged
+    /// with the fields they carry, see [hash_leaf].
     pub fn hash(&self) -> Hash {
-        hashv(&[
+        let extra_amounts: Vec<(u64, u64)> = self
+            .extra_amounts
+            .iter()
+            .map(|amount| (amount.total_unlocked, amount.total_locked))
+            .collect();
+
+        hash_leaf(
             &self.claimant.to_bytes(),
-            &self.amount_unlocked().to_le_bytes(),
-            &self.amount_locked().to_le_bytes(),
-        ])
+            self.amount_unlocked(),
+            self.amount_locked(),
+            self.vesting_schedule(),
+            &extra_amounts,
+            self.index,
+        )
+    }
+
+    /// Get the leaf's own (start, end) vesting timestamps, if both are set
+    pub fn vesting_schedule(&self) -> Option<(i64, i64)> {
+        match (self.start_ts, self.end_ts) {
+            (Some(start_ts), Some(end_ts)) => Some((start_ts, end_ts)),
+            _ => None,
+        }
     }
 
     /// Return total amount of locked and unlocked amount for this claimant
@@ -52,6 +95,10 @@ impl From<CsvEntry> for TreeNode {
             proof: None,
             total_unlocked: 0,
             total_locked: 0,
+            start_ts: entry.start_vesting_ts,
+            end_ts: entry.end_vesting_ts,
+            index: None,
+            extra_amounts: entry.extra_amounts(),
         };
 
         // CSV entry uses UI amounts; we convert to native amounts here
@@ -64,6 +111,9 @@ impl From<CsvEntry> for TreeNode {
 
 #[cfg(test)]
 mod tests {
+    use access_merkle_verify::{LEAF_FLAG_INDEX, LEAF_FLAG_VESTING_SCHEDULE, LEAF_VERSION_TAGGED};
+    use solana_program::hash::hashv;
+
     use super::*;
 
     #[test]
@@ -73,9 +123,123 @@ mod tests {
             proof: None,
             total_unlocked: 0,
             total_locked: 0,
+            start_ts: None,
+            end_ts: None,
+            index: None,
+            extra_amounts: vec![],
         };
         let serialized = serde_json::to_string(&tree_node).unwrap();
         let deserialized: TreeNode = serde_json::from_str(&serialized).unwrap();
         assert_eq!(tree_node, deserialized);
     }
+
+    #[test]
+    fn test_hash_includes_vesting_schedule() {
+        let tree_node = TreeNode {
+            claimant: Pubkey::default(),
+            proof: None,
+            total_unlocked: 1,
+            total_locked: 2,
+            start_ts: None,
+            end_ts: None,
+            index: None,
+            extra_amounts: vec![],
+        };
+        let legacy_hash = hashv(&[
+            &Pubkey::default().to_bytes(),
+            &1u64.to_le_bytes(),
+            &2u64.to_le_bytes(),
+        ]);
+        assert_eq!(tree_node.hash(), legacy_hash);
+
+        let scheduled_node = TreeNode {
+            start_ts: Some(100),
+            end_ts: Some(200),
+            ..tree_node
+        };
+        assert_ne!(scheduled_node.hash(), legacy_hash);
+        assert_eq!(scheduled_node.vesting_schedule(), Some((100, 200)));
+    }
+
+    #[test]
+    fn test_hash_includes_index() {
+        let tree_node = TreeNode {
+            claimant: Pubkey::default(),
+            proof: None,
+            total_unlocked: 1,
+            total_locked: 0,
+            start_ts: None,
+            end_ts: None,
+            index: Some(7),
+            extra_amounts: vec![],
+        };
+        let indexed_hash = hashv(&[
+            &Pubkey::default().to_bytes(),
+            &1u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &[LEAF_VERSION_TAGGED, LEAF_FLAG_INDEX, 0],
+            &7u64.to_le_bytes(),
+        ]);
+        assert_eq!(tree_node.hash(), indexed_hash);
+    }
+
+    #[test]
+    fn test_hash_includes_extra_amounts() {
+        let tree_node = TreeNode {
+            claimant: Pubkey::default(),
+            proof: None,
+            total_unlocked: 1,
+            total_locked: 2,
+            start_ts: Some(100),
+            end_ts: Some(200),
+            index: None,
+            extra_amounts: vec![ExtraAmount {
+                total_unlocked: 3,
+                total_locked: 4,
+            }],
+        };
+        let extra_hash = hashv(&[
+            &Pubkey::default().to_bytes(),
+            &1u64.to_le_bytes(),
+            &2u64.to_le_bytes(),
+            &[LEAF_VERSION_TAGGED, LEAF_FLAG_VESTING_SCHEDULE, 1],
+            &100i64.to_le_bytes(),
+            &200i64.to_le_bytes(),
+            &3u64.to_le_bytes(),
+            &4u64.to_le_bytes(),
+        ]);
+        assert_eq!(tree_node.hash(), extra_hash);
+    }
+
+    #[test]
+    fn test_hash_distinguishes_optional_fields() {
+        // extra amounts must not verify as a vesting schedule chosen by the claimant
+        let extra_node = TreeNode {
+            claimant: Pubkey::default(),
+            proof: None,
+            total_unlocked: 1,
+            total_locked: 2,
+            start_ts: None,
+            end_ts: None,
+            index: None,
+            extra_amounts: vec![ExtraAmount {
+                total_unlocked: 100,
+                total_locked: 200,
+            }],
+        };
+        let scheduled_node = TreeNode {
+            start_ts: Some(100),
+            end_ts: Some(200),
+            extra_amounts: vec![],
+            ..extra_node.clone()
+        };
+        let indexed_node = TreeNode {
+            index: Some(100),
+            extra_amounts: vec![],
+            ..extra_node.clone()
+        };
+        assert_ne!(extra_node.hash(), scheduled_node.hash());
+        assert_ne!(extra_node.hash(), indexed_node.hash());
+        assert_ne!(scheduled_node.hash(), indexed_node.hash());
+    }
 }
@@ -42,6 +42,21 @@ pub fn get_merkle_distributor_pda(
     )
 }
 
+pub fn get_sol_distributor_pda(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    version: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"SolDistributor".as_ref(),
+            creator.as_ref(),
+            version.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
 pub fn get_claim_status_pda(
     program_id: &Pubkey,
     claimant: &Pubkey,
@@ -57,6 +72,66 @@ pub fn get_claim_status_pda(
     )
 }
 
+pub fn get_sol_vault_pda(program_id: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"SolVault".as_ref(), distributor.to_bytes().as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_stake_authority_pda(program_id: &Pubkey, claim_status: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"StakeAuthority".as_ref(), claim_status.to_bytes().as_ref()],
+        program_id,
+    )
+}
+
+/// Central state of the staking program, a PDA of its own program id.
+pub fn get_staking_central_state_pda(staking_program: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[staking_program.as_ref()], staking_program)
+}
+
+/// Stake account of an owner in a stake pool of the staking program.
+pub fn get_stake_account_pda(
+    staking_program: &Pubkey,
+    owner: &Pubkey,
+    stake_pool: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"stake_account".as_ref(),
+            owner.as_ref(),
+            stake_pool.as_ref(),
+        ],
+        staking_program,
+    )
+}
+
+pub fn get_claim_bitmap_pda(program_id: &Pubkey, distributor: &Pubkey, page: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimBitmap".as_ref(),
+            distributor.to_bytes().as_ref(),
+            page.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_sol_custody_pda(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"SolCustodyState".as_ref(), owner.to_bytes().as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_custody_vault_pda(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"SolCustody".as_ref(), owner.to_bytes().as_ref()],
+        program_id,
+    )
+}
+
 #[derive(Debug)]
 pub struct MerkleValidationError {
     pub msg: String,
@@ -73,6 +148,10 @@ mod tests {
             proof: None,
             total_unlocked,
             total_locked,
+            start_ts: None,
+            end_ts: None,
+            index: None,
+            extra_amounts: vec![],
         }
     }
 
@@ -29,4 +29,90 @@ pub enum ErrorCode {
     TimestampsNotInFuture,
     #[msg("Insufficient funds")]
     InsufficientFunds,
+    #[msg("Cliff timestamp must be between start and end timestamps")]
+    CliffOutsideVestingPeriod,
+    #[msg("Cliff percentage cannot exceed 100")]
+    InvalidCliffPercentage,
+    #[msg("Leaf vesting schedule requires both start and end timestamps")]
+    PartialVestingSchedule,
+    #[msg("Vesting period must be positive and milestones must be ordered and end at 100%")]
+    InvalidVestingMode,
+    #[msg("Claims are paused")]
+    DistributorPaused,
+    #[msg("Clawback start must be at least one day after end timestamp")]
+    InsufficientClawbackDelay,
+    #[msg("Clawback is not allowed before the clawback start timestamp")]
+    ClawbackBeforeStart,
+    #[msg("New and old clawback receivers are identical")]
+    SameClawbackReceiver,
+    #[msg("Clawback receiver does not match the distributor's clawback receiver")]
+    InvalidClawbackReceiver,
+    #[msg("No admin has been proposed")]
+    NoPendingAdmin,
+    #[msg("Claimant must sign unless permissionless claims go to their associated token account")]
+    ClaimantSignatureRequired,
+    #[msg("Claim still has locked tokens to withdraw")]
+    ClaimNotFullyWithdrawn,
+    #[msg("Claim does not match the distributor's claim tracking mode")]
+    InvalidClaimMode,
+    #[msg("Claim bitmap page does not cover the leaf index")]
+    InvalidClaimBitmap,
+    #[msg("Leaf has already been claimed")]
+    AlreadyClaimed,
+    #[msg("New max total claim and max num nodes cannot be less than the current ones")]
+    DistributorShrinks,
+    #[msg("Distributor vault does not cover the unclaimed tokens")]
+    DistributorNotFunded,
+    #[msg("Distributor claims are not revocable")]
+    NotRevocable,
+    #[msg("Claim has already been revoked")]
+    ClaimRevoked,
+    #[msg("Claim has been transferred to another claimant")]
+    ClaimTransferred,
+    #[msg("New claimant must differ from the current claimant")]
+    SameClaimant,
+    #[msg("Distributor claims are not recoverable by the admin")]
+    NotRecoverable,
+    #[msg("Instruction does not match whether the distributor pays out native SOL or tokens")]
+    DistributorKindMismatch,
+    #[msg("Distributor already distributes the maximum number of extra mints")]
+    TooManyExtraMints,
+    #[msg("Extra mints are not supported by the distributor or instruction")]
+    ExtraMintsUnsupported,
+    #[msg("Mint is already distributed by the distributor")]
+    DuplicateMint,
+    #[msg("Remaining accounts do not match the distributor's extra mints")]
+    InvalidExtraMintAccounts,
+    #[msg("Distributor has no staking program")]
+    StakingDisabled,
+    #[msg("Staking program or stake pool does not match the distributor's")]
+    InvalidStakingProgram,
+    #[msg("Claim does not have enough idle locked tokens")]
+    InsufficientLockedTokens,
+    #[msg("Claim does not have enough staked locked tokens")]
+    InsufficientStakedTokens,
+    #[msg("Locked tokens are staked, they have to be unstaked first")]
+    LockedTokensStaked,
+    #[msg("SOL custody is locked")]
+    CustodyLocked,
+    #[msg("SOL custody lock can only be extended")]
+    CustodyLockShortened,
+    #[msg("Distributor already has the current layout")]
+    DistributorAlreadyMigrated,
+    #[msg("SOL transfer would leave the recipient below the rent-exempt minimum")]
+    SolTransferBelowRentExempt,
+    #[msg("Distributor already has a staking program, it can't be changed")]
+    StakingProgramAlreadySet,
+    #[msg("Staking program did not move exactly the staked amount")]
+    InvalidStakeTransfer,
+    #[msg("Claim status already has the current layout")]
+    ClaimStatusAlreadyMigrated,
+    #[msg("Vault did not receive the funded amount net of the transfer fee")]
+    InvalidFundingTransfer,
+    #[msg("Distributor is already funded")]
+    DistributorFunded,
+    #[msg("New root is the distributor's current root")]
+    RootUnchanged,
+    #[msg("Stake pool charges a staking fee, only fee-free stake pools are supported")]
+    StakingFeeNotSupported,
 }
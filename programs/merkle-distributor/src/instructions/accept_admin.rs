@@ -0,0 +1,51 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::AdminAcceptedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::accept_admin] accounts.
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Pending admin signer
+    #[account(mut, address = distributor.pending_admin @ ErrorCode::Unauthorized)]
+    pub new_admin: Signer<'info>,
+}
+
+/// Makes the pending admin the new admin
+/// CHECK:
+///     1. An admin has been proposed
+#[allow(clippy::result_large_err)]
+pub fn handle_accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(
+        distributor.pending_admin != Pubkey::default(),
+        ErrorCode::NoPendingAdmin
+    );
+
+    let old_admin = distributor.admin;
+    distributor.admin = distributor.pending_admin;
+    distributor.pending_admin = Pubkey::default();
+
+    // Note: might get truncated, do not rely on
+    msg!("set new admin to {}", distributor.admin);
+    emit!(AdminAcceptedEvent {
+        distributor: distributor.key(),
+        old_admin,
+        new_admin: distributor.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        events::ExtraMintAddedEvent,
+        extra_mint::{ExtraMint, MAX_EXTRA_MINTS},
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::add_extra_mint] accounts.
+#[derive(Accounts)]
+pub struct AddExtraMint<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// The extra mint to distribute.
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token vault of the extra mint, the distributor's associated token account
+    #[account(
+        token::mint = mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+        address = get_associated_token_address_with_program_id(
+            &distributor.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The SPL Token or Token-2022 program of the extra mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Adds a mint distributed alongside the distributor's mint, every leaf of the merkle tree
+/// carries an amount of each extra mint in the order they were added.
+/// Leaves are hashed with the extra amounts they carry, so the root is replaced by one
+/// built for the extra mints before the distributor is funded and can be claimed from.
+/// The token_vault should be seeded with max_total_claim tokens with a plain token transfer,
+/// fund_distributor only marks the distributor as funded once every extra mint vault holds
+/// everything of its mint that can still be claimed.
+/// Distributors with extra mints don't support claim_all, clawback_unclaimed, update_root nor
+/// revoke_claim, they reject them, and their claims and fund_distributor take the extra mint
+/// accounts instead of Token-2022 transfer hook accounts.
+/// CHECK:
+///     1. The distributor pays out tokens
+///     2. The distributor is not revocable, tracks claims in claim status accounts,
+///        has no claims yet and is not funded
+///     3. The root differs from the current one
+///     4. The distributor has less than [MAX_EXTRA_MINTS] extra mints
+///     5. The mint is neither the distributor's mint nor one of its extra mints
+#[allow(clippy::result_large_err)]
+pub fn handle_add_extra_mint(
+    ctx: Context<AddExtraMint>,
+    root: [u8; 32],
+    max_total_claim: u64,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+    let mint = ctx.accounts.mint.key();
+
+    require!(!distributor.native_sol, ErrorCode::DistributorKindMismatch);
+    require!(
+        !distributor.revocable && !distributor.claim_bitmap && distributor.num_nodes_claimed == 0,
+        ErrorCode::ExtraMintsUnsupported
+    );
+    require!(!distributor.funded, ErrorCode::DistributorFunded);
+    require!(distributor.root != root, ErrorCode::RootUnchanged);
+    require!(
+        (distributor.num_extra_mints as usize) < MAX_EXTRA_MINTS,
+        ErrorCode::TooManyExtraMints
+    );
+    require!(
+        distributor.mint != mint
+            && distributor
+                .extra_mints()
+                .iter()
+                .all(|extra_mint| extra_mint.mint != mint),
+        ErrorCode::DuplicateMint
+    );
+
+    let index = distributor.num_extra_mints as usize;
+    distributor.extra_mints[index] = ExtraMint {
+        mint,
+        token_vault: ctx.accounts.token_vault.key(),
+        max_total_claim,
+        total_amount_claimed: 0,
+    };
+    distributor.num_extra_mints += 1;
+    distributor.root = root;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Added extra mint {} with max total claim {}",
+        mint,
+        max_total_claim
+    );
+    emit!(ExtraMintAddedEvent {
+        distributor: distributor.key(),
+        mint,
+        token_vault: ctx.accounts.token_vault.key(),
+        max_total_claim,
+        root,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
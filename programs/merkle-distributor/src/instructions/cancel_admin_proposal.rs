@@ -0,0 +1,48 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::AdminProposalCancelledEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::cancel_admin_proposal] accounts.
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Cancels the pending admin proposal
+/// CHECK:
+///     1. An admin has been proposed
+#[allow(clippy::result_large_err)]
+pub fn handle_cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(
+        distributor.pending_admin != Pubkey::default(),
+        ErrorCode::NoPendingAdmin
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!("cancelled admin proposal for {}", distributor.pending_admin);
+    emit!(AdminProposalCancelledEvent {
+        distributor: distributor.key(),
+        pending_admin: distributor.pending_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    distributor.pending_admin = Pubkey::default();
+
+    Ok(())
+}
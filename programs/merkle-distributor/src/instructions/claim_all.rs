@@ -0,0 +1,253 @@
+use anchor_lang::{
+    context::Context, prelude::*, system_program::System, Accounts, Key, Result, ToAccountInfo,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::verify_leaf,
+    state::{
+        claim_status::ClaimStatus,
+        events::{ClaimedEvent, NewClaimEvent},
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{
+        check_claimant_authorized, increment_num_nodes_claimed, init_claim_status,
+        record_locked_withdrawal, record_new_claim, transfer_fee, transfer_from_distributor,
+    },
+};
+
+/// [merkle_distributor::claim_all] accounts.
+#[derive(Accounts)]
+pub struct ClaimAll<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA, initialized on the first claim
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = payer
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    /// CHECK: has to sign unless the distributor allows permissionless claims and the tokens go
+    /// to their associated token account
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Pays for the claim status rent, can be the claimant or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Claims everything currently available to the claimant in a single transfer.
+/// Only for distributors without extra mints, their claims go through
+/// [merkle_distributor::new_claim] and [merkle_distributor::claim_locked].
+/// 1. On the first claim, verifies the merkle proof, increments num_nodes_claimed by 1
+///    and initializes claim_status like [merkle_distributor::new_claim]
+/// 2. Adds the currently vested locked amount to the payout
+/// 3. Transfers the unlocked amount, if this is the first claim, and the vested
+///    locked amount to the claimant
+/// 4. Increments total_amount_claimed by the transferred amount and updates
+///    total_locked_outstanding
+///
+/// CHECK:
+///     1. The distributor has not been clawed back
+///     2. The distributor is not paused, tracks claims in claim status accounts and
+///        has no extra mints
+///     3. The claimant is the owner of the to account and either signed or
+///        the distributor allows permissionless claims and the to account is their
+///        associated token account
+///     4. An existing claim has not been transferred to another claimant
+///     5. On the first claim, the distributor is funded, the claim window has not ended or
+///        been clawed back, num nodes claimed is less than max_num_nodes and the merkle proof
+///        is valid
+///     6. The amount transferred is greater than 0
+///     7. The locked amount withdrawn is ≤ than the locked amount
+///     8. The distributor amount claimed is ≤ than the max total claim
+///
+/// Remaining accounts are forwarded to the token transfer for Token-2022 transfer hooks.
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_all<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimAll<'info>>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    start_vesting_ts: Option<i64>,
+    end_vesting_ts: Option<i64>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    let clock = Clock::get()?;
+    let curr_ts = clock.unix_timestamp;
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(!distributor.claim_bitmap, ErrorCode::InvalidClaimMode);
+    require!(
+        distributor.num_extra_mints == 0,
+        ErrorCode::ExtraMintsUnsupported
+    );
+
+    let claimant_account = &ctx.accounts.claimant;
+    check_claimant_authorized(
+        distributor,
+        claimant_account,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    )?;
+
+    // A freshly initialized claim status has no claimant yet
+    let new_claim = ctx.accounts.claim_status.claimant == Pubkey::default();
+    let mut amount = 0;
+
+    // A transferred claim can only be withdrawn by its new claimant through claim_locked
+    require!(
+        new_claim || !ctx.accounts.claim_status.is_transferred(),
+        ErrorCode::ClaimTransferred
+    );
+
+    if new_claim {
+        require!(distributor.funded, ErrorCode::DistributorNotFunded);
+        require!(
+            distributor.claim_window_open(curr_ts),
+            ErrorCode::ClaimExpired
+        );
+
+        let (start_ts, end_ts) = verify_leaf(
+            distributor,
+            &claimant_account.key(),
+            amount_unlocked,
+            amount_locked,
+            start_vesting_ts,
+            end_vesting_ts,
+            &[],
+            proof,
+        )?;
+
+        let distributor = &mut ctx.accounts.distributor;
+        increment_num_nodes_claimed(distributor)?;
+        record_new_claim(distributor, amount_unlocked, amount_locked)?;
+
+        init_claim_status(
+            &mut ctx.accounts.claim_status,
+            claimant_account.key(),
+            amount_unlocked,
+            amount_locked,
+            start_ts,
+            end_ts,
+        );
+
+        amount = amount_unlocked;
+    }
+
+    let distributor = &mut ctx.accounts.distributor;
+    let claim_status = &mut ctx.accounts.claim_status;
+    let amount_vested = claim_status.amount_withdrawable(
+        curr_ts,
+        claim_status.start_ts,
+        claim_status.end_ts,
+        distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+        distributor.cliff_percentage,
+        &distributor.vesting_mode,
+    )?;
+    record_locked_withdrawal(distributor, claim_status, amount_vested)?;
+
+    amount = amount
+        .checked_add(amount_vested)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // A leaf with nothing unlocked can still be claimed before its locked tokens vest
+    require!(
+        amount > 0 || new_claim,
+        ErrorCode::InsufficientUnlockedTokens
+    );
+
+    let distributor = &ctx.accounts.distributor;
+    let mut fee = 0;
+    if amount > 0 {
+        transfer_from_distributor(
+            distributor,
+            &ctx.accounts.from,
+            &ctx.accounts.to,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            ctx.remaining_accounts,
+            amount,
+        )?;
+        fee = transfer_fee(
+            &ctx.accounts.mint.to_account_info().try_borrow_data()?,
+            clock.epoch,
+            amount,
+        )?;
+    }
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Claimed amount {} ({} transfer fee), {} of it vested, locked withdrawn {} of {}",
+        amount,
+        fee,
+        amount_vested,
+        claim_status.locked_amount_withdrawn,
+        claim_status.locked_amount,
+    );
+    if new_claim {
+        emit!(NewClaimEvent {
+            claimant: claimant_account.key(),
+            timestamp: curr_ts,
+            distributor: distributor.key(),
+            amount_unlocked: claim_status.unlocked_amount,
+            amount_locked: claim_status.locked_amount,
+            extra_amounts: vec![],
+        });
+    }
+    if amount_vested > 0 {
+        emit!(ClaimedEvent {
+            claimant: claimant_account.key(),
+            amount: amount_vested,
+            distributor: distributor.key(),
+            timestamp: curr_ts,
+            extra_amounts: vec![],
+        });
+    }
+
+    Ok(())
+}
@@ -1,17 +1,16 @@
 use anchor_lang::{
-    accounts::{account::Account, program::Program, signer::Signer},
-    context::{Context, CpiContext},
-    emit,
-    prelude::*,
-    Accounts, Result, ToAccountInfo,
+    accounts::account::Account, context::Context, emit, prelude::*, Accounts, Result, ToAccountInfo,
 };
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     error::ErrorCode,
     state::{
-        claim_status::ClaimStatus, claimed_event::ClaimedEvent,
-        merkle_distributor::MerkleDistributor,
+        claim_status::ClaimStatus, events::ClaimedEvent, merkle_distributor::MerkleDistributor,
+    },
+    utils::{
+        check_claimant_authorized, record_locked_withdrawal, transfer_extra_mints_to_claimant,
+        transfer_fee, transfer_from_distributor, transfer_hook_accounts,
     },
 };
 
@@ -22,12 +21,13 @@ pub struct ClaimLocked<'info> {
     #[account(mut)]
     pub distributor: Account<'info, MerkleDistributor>,
 
-    /// Claim Status PDA
+    /// Claim Status PDA, derived from the leaf claimant even if the claim was transferred
     #[account(
         mut,
+        has_one = claimant @ ErrorCode::OwnerMismatch,
         seeds = [
             b"ClaimStatus".as_ref(),
-            claimant.key().to_bytes().as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
             distributor.key().to_bytes().as_ref()
         ],
         bump,
@@ -37,89 +37,152 @@ pub struct ClaimLocked<'info> {
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault,
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
-    /// Claimant must sign the transaction and can only claim on behalf of themself
-    #[account(mut, token::authority = claimant.key())]
-    pub to: Account<'info, TokenAccount>,
+    /// Claimant must sign the transaction unless the distributor allows permissionless claims
+    /// and this is their associated token account
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Who is claiming the tokens.
-    #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
-    pub claimant: Signer<'info>,
+    /// CHECK: has to sign unless the distributor allows permissionless claims and the tokens go
+    /// to their associated token account
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: UncheckedAccount<'info>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-/// Claim locked tokens as they become unlocked.
+/// Claim locked tokens as they become unlocked, along with the locked tokens of the extra mints.
 /// Check:
-///     1. The claim window has not expired and the distributor has not been clawed back
-///     2. The withdraw-able amount is greater than 0
-///     3. The locked amount withdrawn is ≤ than the locked amount
-///     4. The distributor amount claimed is ≤ than the max total claim
+///     1. The distributor has not been clawed back
+///     2. The distributor is not paused
+///     3. The claimant either signed or the distributor allows permissionless claims and the to
+///        account is their associated token account, the same goes for the extra mint token
+///        accounts
+///     4. The withdraw-able amount of any mint is greater than 0
+///     5. The locked amount withdrawn is ≤ than the locked amount
+///     6. The distributor amount claimed is ≤ than the max total claim
+///
+/// Remaining accounts are forwarded to the token transfer for Token-2022 transfer hooks.
+/// Distributors with extra mints take the extra mint accounts instead, see [extra_mint_accounts].
+///
+/// [extra_mint_accounts]: crate::utils::extra_mint_accounts
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimLocked<'info>>,
+) -> Result<()> {
+    process_claim_locked(ctx, true).map(|_| ())
+}
+
+/// [handle_claim_locked], leaving the withdrawn amount in the vault unless transfer_unlocked is
+/// set, for [merkle_distributor::claim_locked_and_stake] to stake it from there.
+/// Returns the withdrawn amount of the distributor's mint.
 #[allow(clippy::result_large_err)]
-pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
+pub fn process_claim_locked<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimLocked<'info>>,
+    transfer_unlocked: bool,
+) -> Result<u64> {
     let distributor = &ctx.accounts.distributor;
 
     let claim_status = &mut ctx.accounts.claim_status;
-    let curr_ts = Clock::get()?.unix_timestamp;
+    let clock = Clock::get()?;
+    let curr_ts = clock.unix_timestamp;
 
     require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    check_claimant_authorized(
+        distributor,
+        &ctx.accounts.claimant,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    )?;
 
-    let amount =
-        claim_status.amount_withdrawable(curr_ts, distributor.start_ts, distributor.end_ts)?;
-
-    require!(amount > 0, ErrorCode::InsufficientUnlockedTokens);
-
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.creator.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
-        amount,
+    let amount = claim_status.amount_withdrawable(
+        curr_ts,
+        claim_status.start_ts,
+        claim_status.end_ts,
+        distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+        distributor.cliff_percentage,
+        &distributor.vesting_mode,
     )?;
 
-    claim_status.locked_amount_withdrawn = claim_status
-        .locked_amount_withdrawn
-        .checked_add(amount)
-        .ok_or(ErrorCode::ArithmeticError)?;
+    let extra_amounts = claim_status.extra_amounts[..distributor.extra_mints().len()]
+        .iter()
+        .map(|extra_amount| {
+            claim_status
+                .extra_claim_status(extra_amount)
+                .amount_withdrawable(
+                    curr_ts,
+                    claim_status.start_ts,
+                    claim_status.end_ts,
+                    distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+                    distributor.cliff_percentage,
+                    &distributor.vesting_mode,
+                )
+        })
+        .collect::<Result<Vec<u64>>>()?;
 
     require!(
-        claim_status.locked_amount_withdrawn <= claim_status.locked_amount,
-        ErrorCode::ExceededMaxClaim
+        amount > 0 || extra_amounts.iter().any(|extra_amount| *extra_amount > 0),
+        ErrorCode::InsufficientUnlockedTokens
     );
 
-    let distributor = &mut ctx.accounts.distributor;
-    distributor.total_amount_claimed = distributor
-        .total_amount_claimed
-        .checked_add(amount)
-        .ok_or(ErrorCode::ArithmeticError)?;
+    if amount > 0 && transfer_unlocked {
+        transfer_from_distributor(
+            distributor,
+            &ctx.accounts.from,
+            &ctx.accounts.to,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            transfer_hook_accounts(distributor, ctx.remaining_accounts),
+            amount,
+        )?;
+    }
+    let fee = transfer_fee(
+        &ctx.accounts.mint.to_account_info().try_borrow_data()?,
+        clock.epoch,
+        amount,
+    )?;
+
+    for (claim_amount, extra_amount) in claim_status.extra_amounts.iter_mut().zip(&extra_amounts) {
+        claim_amount.locked_amount_withdrawn = claim_amount
+            .locked_amount_withdrawn
+            .checked_add(*extra_amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        require!(
+            claim_amount.locked_amount_withdrawn <= claim_amount.locked_amount,
+            ErrorCode::ExceededMaxClaim
+        );
+    }
+    transfer_extra_mints_to_claimant(
+        &mut ctx.accounts.distributor,
+        &ctx.accounts.claimant,
+        ctx.remaining_accounts,
+        &extra_amounts,
+    )?;
 
-    require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
-        ErrorCode::ExceededMaxClaim
-    );
+    record_locked_withdrawal(&mut ctx.accounts.distributor, claim_status, amount)?;
 
-    let remaining_seconds = match curr_ts < distributor.end_ts {
-        true => distributor.end_ts - curr_ts,
+    let remaining_seconds = match curr_ts < claim_status.end_ts {
+        true => claim_status.end_ts - curr_ts,
         false => 0,
     };
 
@@ -128,14 +191,18 @@ pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
 
     // Note: might get truncated, do not rely on
     msg!(
-        "Withdrew amount {} with {} days and {} seconds left in lockup",
+        "Withdrew amount {} ({} transfer fee) with {} days and {} seconds left in lockup",
         amount,
+        fee,
         days,
         seconds_after_days,
     );
     emit!(ClaimedEvent {
         claimant: ctx.accounts.claimant.key(),
         amount,
+        distributor: ctx.accounts.distributor.key(),
+        timestamp: curr_ts,
+        extra_amounts,
     });
-    Ok(())
+    Ok(amount)
 }
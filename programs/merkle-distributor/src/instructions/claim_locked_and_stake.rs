@@ -0,0 +1,129 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result, ToAccountInfo};
+
+use crate::{
+    error::ErrorCode,
+    // the nested accounts need the modules generated for them in scope
+    instructions::claim_locked::*,
+    state::events::StakedEvent,
+    utils::stake_from_vault,
+};
+
+/// [merkle_distributor::claim_locked_and_stake] accounts.
+#[derive(Accounts)]
+pub struct ClaimLockedAndStake<'info> {
+    /// The [merkle_distributor::claim_locked] accounts, the claimant has to sign.
+    pub claim: ClaimLocked<'info>,
+
+    /// Central state of the staking program
+    /// CHECK: PDA of the staking program
+    #[account(
+        mut,
+        seeds = [staking_program.key().as_ref()],
+        bump,
+        seeds::program = staking_program.key(),
+    )]
+    pub central_state: UncheckedAccount<'info>,
+
+    /// Stake pool of the staking program
+    /// CHECK: the distributor's stake pool, validated by the staking program
+    #[account(mut, address = claim.distributor.stake_pool @ ErrorCode::InvalidStakingProgram)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// The claimant's stake account
+    /// CHECK: PDA of the staking program, validated by it
+    #[account(
+        mut,
+        seeds = [
+            b"stake_account".as_ref(),
+            claim.claimant.key().as_ref(),
+            stake_pool.key().as_ref()
+        ],
+        bump,
+        seeds::program = staking_program.key(),
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Vault of the stake pool
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub stake_vault: UncheckedAccount<'info>,
+
+    /// Fee account of the staking program
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub fee_account: UncheckedAccount<'info>,
+
+    /// The staking program
+    /// CHECK: the distributor's staking program
+    #[account(
+        executable,
+        address = claim.distributor.staking_program @ ErrorCode::InvalidStakingProgram
+    )]
+    pub staking_program: UncheckedAccount<'info>,
+}
+
+/// Claims locked tokens as they become unlocked like [merkle_distributor::claim_locked] and stakes
+/// them from the vault into the claimant's stake account of the distributor's staking program,
+/// they never pass through the claimant's token account. The claimant signs the stake as the owner
+/// of the stake account, the vault only delegates them the withdrawn amount for its duration.
+/// CHECK:
+///     1. The distributor has a staking program
+///     2. The claimant signed
+///     3. The stake account is the claimant's in the distributor's stake pool
+///     4. The checks of [merkle_distributor::claim_locked]
+///     5. The vault balance changed by exactly the withdrawn amount
+///     6. The stake pool vault received exactly the withdrawn amount, the stake pool charges no fee
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_and_stake<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimLockedAndStake<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.claim.distributor.staking_program != Pubkey::default(),
+        ErrorCode::StakingDisabled
+    );
+    require!(
+        ctx.accounts.claim.claimant.is_signer,
+        ErrorCode::ClaimantSignatureRequired
+    );
+
+    let amount = process_claim_locked(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.claim,
+            ctx.remaining_accounts,
+            ctx.bumps.clone(),
+        ),
+        false,
+    )?;
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let claim = &mut ctx.accounts.claim;
+    stake_from_vault(
+        &claim.distributor,
+        &mut claim.from,
+        &claim.token_program.to_account_info(),
+        &claim.claimant.to_account_info(),
+        &[],
+        &ctx.accounts.staking_program,
+        &ctx.accounts.central_state,
+        &ctx.accounts.stake_account,
+        &ctx.accounts.stake_pool,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.fee_account,
+        amount,
+    )?;
+
+    // Note: might get truncated, do not rely on
+    msg!("Staked {} unlocked tokens", amount);
+    emit!(StakedEvent {
+        claimant: claim.claimant.key(),
+        stake_account: ctx.accounts.stake_account.key(),
+        amount,
+        distributor: claim.distributor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use anchor_lang::{
+    accounts::account::Account, context::Context, emit, prelude::*, Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, events::ClaimedEvent, merkle_distributor::MerkleDistributor,
+    },
+    utils::{record_locked_withdrawal, transfer_from_sol_vault},
+};
+
+/// [merkle_distributor::claim_locked_sol] accounts.
+#[derive(Accounts)]
+pub struct ClaimLockedSol<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA, derived from the leaf claimant even if the claim was transferred
+    #[account(
+        mut,
+        has_one = claimant @ ErrorCode::OwnerMismatch,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// SOL vault PDA holding the lamports to distribute.
+    /// CHECK: the distributor's vault, a bucket for SOL owned by the system program
+    #[account(
+        mut,
+        seeds = [
+            b"SolVault".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+        ],
+        bump,
+        address = distributor.token_vault
+    )]
+    pub sol_vault: UncheckedAccount<'info>,
+
+    /// Who is claiming the lamports, receives them directly.
+    /// CHECK: only receives lamports, the claim status ties it to the claim. Has to sign unless
+    /// the distributor allows permissionless claims
+    #[account(mut)]
+    pub claimant: UncheckedAccount<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim locked lamports of a native SOL [MerkleDistributor] as they become unlocked.
+/// Check:
+///     1. The distributor pays out native SOL
+///     2. The distributor has not been clawed back
+///     3. The distributor is not paused
+///     4. The claimant signed or the distributor allows permissionless claims
+///     5. The withdraw-able amount is greater than 0
+///     6. The locked amount withdrawn is ≤ than the locked amount
+///     7. The distributor amount claimed is ≤ than the max total claim
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_sol(ctx: Context<ClaimLockedSol>) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    let claim_status = &mut ctx.accounts.claim_status;
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    require!(distributor.native_sol, ErrorCode::DistributorKindMismatch);
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(
+        ctx.accounts.claimant.is_signer || distributor.permissionless_claims,
+        ErrorCode::ClaimantSignatureRequired
+    );
+
+    let amount = claim_status.amount_withdrawable(
+        curr_ts,
+        claim_status.start_ts,
+        claim_status.end_ts,
+        distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+        distributor.cliff_percentage,
+        &distributor.vesting_mode,
+    )?;
+
+    require!(amount > 0, ErrorCode::InsufficientUnlockedTokens);
+
+    transfer_from_sol_vault(
+        distributor,
+        &ctx.accounts.sol_vault,
+        &ctx.accounts.claimant,
+        &ctx.accounts.system_program,
+        *ctx.bumps.get("sol_vault").unwrap(),
+        amount,
+    )?;
+
+    record_locked_withdrawal(&mut ctx.accounts.distributor, claim_status, amount)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Withdrew {} lamports, {} locked lamports left",
+        amount,
+        claim_status
+            .locked_amount
+            .saturating_sub(claim_status.locked_amount_withdrawn)
+            .saturating_sub(claim_status.locked_amount_revoked),
+    );
+    emit!(ClaimedEvent {
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+        distributor: ctx.accounts.distributor.key(),
+        timestamp: curr_ts,
+        extra_amounts: vec![],
+    });
+    Ok(())
+}
@@ -1,12 +1,16 @@
 // Instruction to clawback funds once they have expired
 
 use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
-use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{events::ClawbackEvent, merkle_distributor::MerkleDistributor},
+    utils::{
+        check_clawback_allowed, extra_mint_accounts, record_clawback, transfer_from_distributor,
+        transfer_hook_accounts,
+    },
+};
 
 /// [merkle_distributor::clawback] accounts.
 #[derive(Accounts)]
@@ -18,15 +22,21 @@ pub struct Clawback<'info> {
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// The Clawback token account.
-    #[account(mut)]
-    pub to: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = distributor.clawback_receiver @ ErrorCode::InvalidClawbackReceiver,
+        token::mint = distributor.mint,
+        token::token_program = token_program
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin account
     /// Only admin can claw back
@@ -36,43 +46,112 @@ pub struct Clawback<'info> {
     /// The [System] program.
     pub system_program: Program<'info, System>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-/// Claws back unclaimed tokens by:
-/// 1. Transferring remaining funds from the vault to the clawback receiver
-/// 2. Marking the distributor as clawed back
+/// Claws back tokens by:
+/// 1. Transferring remaining funds from the vault to the clawback receiver, or only the
+///    unclaimed allocation if unclaimed_only is set
+/// 2. Transferring remaining funds from the extra mint vaults to token accounts of the
+///    clawback receiver's owner, unless unclaimed_only is set
+/// 3. Marking the distributor as clawed back, or only its unclaimed allocation so existing
+///    claims keep withdrawing their locked tokens
+///
+/// CHECK:
+///     1. The distributor has not been clawed back already
+///     2. The unclaimed allocation has not been clawed back already if unclaimed_only is set
+///     3. The clawback start timestamp has passed
+///     4. Distributors with extra mints are clawed back in full
+///     5. The extra mint token accounts are owned by the clawback receiver's owner
+///     6. No locked tokens are staked unless unclaimed_only is set, the admin can unstake them first
+///
+/// Remaining accounts are forwarded to the token transfer for Token-2022 transfer hooks.
+/// Distributors with extra mints take the extra mint accounts instead, see [extra_mint_accounts].
 #[allow(clippy::result_large_err)]
-pub fn handle_clawback(ctx: Context<Clawback>) -> Result<()> {
+pub fn handle_clawback<'info>(
+    ctx: Context<'_, '_, '_, 'info, Clawback<'info>>,
+    unclaimed_only: bool,
+) -> Result<()> {
     let distributor = &ctx.accounts.distributor;
 
-    require!(!distributor.clawed_back, ErrorCode::ClawbackAlreadyClaimed);
-
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.creator.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
-        ctx.accounts.from.amount,
-    )?;
+    let curr_ts = Clock::get()?.unix_timestamp;
+    check_clawback_allowed(distributor, unclaimed_only, curr_ts)?;
+    require!(
+        !(unclaimed_only && distributor.num_extra_mints > 0),
+        ErrorCode::ExtraMintsUnsupported
+    );
+    require!(
+        unclaimed_only || distributor.total_locked_staked == 0,
+        ErrorCode::LockedTokensStaked
+    );
+
+    let amount = match unclaimed_only {
+        // the vault may not cover the whole unclaimed allocation, but never touch locked obligations,
+        // staked locked tokens cover their share of them
+        true => distributor.amount_unclaimed().min(
+            ctx.accounts
+                .from
+                .amount
+                .saturating_add(distributor.total_locked_staked)
+                .saturating_sub(distributor.total_locked_outstanding),
+        ),
+        false => ctx.accounts.from.amount,
+    };
+
+    if amount > 0 {
+        transfer_from_distributor(
+            distributor,
+            &ctx.accounts.from,
+            &ctx.accounts.to,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            transfer_hook_accounts(distributor, ctx.remaining_accounts),
+            amount,
+        )?;
+    }
+
+    for accounts in extra_mint_accounts(distributor, ctx.remaining_accounts)? {
+        require_keys_eq!(
+            accounts.to.owner,
+            ctx.accounts.to.owner,
+            ErrorCode::InvalidClawbackReceiver
+        );
+        if accounts.from.amount > 0 {
+            transfer_from_distributor(
+                distributor,
+                &accounts.from,
+                &accounts.to,
+                &accounts.mint,
+                &accounts.token_program,
+                &[],
+                accounts.from.amount,
+            )?;
+        }
+    }
 
     let distributor = &mut ctx.accounts.distributor;
 
-    distributor.clawed_back = true;
+    record_clawback(distributor, unclaimed_only, amount)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Clawed back {} with {} locked tokens outstanding",
+        amount,
+        distributor.total_locked_outstanding
+    );
+    emit!(ClawbackEvent {
+        distributor: distributor.key(),
+        receiver: ctx.accounts.to.key(),
+        amount,
+        unclaimed_only,
+        total_locked_outstanding: distributor.total_locked_outstanding,
+        timestamp: curr_ts,
+    });
 
     Ok(())
 }
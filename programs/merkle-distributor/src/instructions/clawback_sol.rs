@@ -0,0 +1,108 @@
+// Instruction to clawback lamports of a native SOL distributor once they have expired
+
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::ClawbackEvent, merkle_distributor::MerkleDistributor},
+    utils::{check_clawback_allowed, record_clawback, sol_vault_amount, transfer_from_sol_vault},
+};
+
+/// [merkle_distributor::clawback_sol] accounts.
+#[derive(Accounts)]
+pub struct ClawbackSol<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// SOL vault PDA holding the lamports to distribute.
+    /// CHECK: the distributor's vault, a bucket for SOL owned by the system program
+    #[account(
+        mut,
+        seeds = [
+            b"SolVault".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+        ],
+        bump,
+        address = distributor.token_vault
+    )]
+    pub sol_vault: UncheckedAccount<'info>,
+
+    /// The clawback receiver wallet.
+    /// CHECK: only receives lamports
+    #[account(
+        mut,
+        address = distributor.clawback_receiver @ ErrorCode::InvalidClawbackReceiver,
+    )]
+    pub to: UncheckedAccount<'info>,
+
+    /// Admin account
+    /// Only admin can claw back
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Claws back lamports by:
+/// 1. Transferring all lamports from the sol_vault to the clawback receiver, including its
+///    rent-exempt reserve, or only the unclaimed allocation if unclaimed_only is set
+/// 2. Marking the distributor as clawed back, or only its unclaimed allocation so existing
+///    claims keep withdrawing their locked lamports
+///
+/// CHECK:
+///     1. The distributor pays out native SOL
+///     2. The distributor has not been clawed back already
+///     3. The unclaimed allocation has not been clawed back already if unclaimed_only is set
+///     4. The clawback start timestamp has passed
+#[allow(clippy::result_large_err)]
+pub fn handle_clawback_sol(ctx: Context<ClawbackSol>, unclaimed_only: bool) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(distributor.native_sol, ErrorCode::DistributorKindMismatch);
+    let curr_ts = Clock::get()?.unix_timestamp;
+    check_clawback_allowed(distributor, unclaimed_only, curr_ts)?;
+
+    let amount = match unclaimed_only {
+        // the vault may not cover the whole unclaimed allocation, but never touch locked obligations
+        true => distributor.amount_unclaimed().min(
+            sol_vault_amount(&ctx.accounts.sol_vault)?
+                .saturating_sub(distributor.total_locked_outstanding),
+        ),
+        // nothing is left to pay out, so the vault is closed along with its reserve
+        false => ctx.accounts.sol_vault.lamports(),
+    };
+
+    if amount > 0 {
+        transfer_from_sol_vault(
+            distributor,
+            &ctx.accounts.sol_vault,
+            &ctx.accounts.to,
+            &ctx.accounts.system_program,
+            *ctx.bumps.get("sol_vault").unwrap(),
+            amount,
+        )?;
+    }
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    record_clawback(distributor, unclaimed_only, amount)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Clawed back {} lamports with {} locked lamports outstanding",
+        amount,
+        distributor.total_locked_outstanding
+    );
+    emit!(ClawbackEvent {
+        distributor: distributor.key(),
+        receiver: ctx.accounts.to.key(),
+        amount,
+        unclaimed_only,
+        total_locked_outstanding: distributor.total_locked_outstanding,
+        timestamp: curr_ts,
+    });
+
+    Ok(())
+}
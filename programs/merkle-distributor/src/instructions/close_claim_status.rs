@@ -0,0 +1,88 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, events::ClaimStatusClosedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::close_claim_status] accounts.
+#[derive(Accounts)]
+pub struct CloseClaimStatus<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA, left behind as an empty tombstone
+    /// CHECK: deserialized and its seeds checked in the handler, the account can't be closed by Anchor
+    #[account(mut, owner = crate::ID)]
+    pub claim_status: UncheckedAccount<'info>,
+
+    /// Claimant receiving the rent refund, only they can close their claim status.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+}
+
+/// Refunds the claim status rent to the claimant.
+/// The account is shrunk to an empty tombstone holding the rent-exempt minimum instead of
+/// being closed, so new_claim can't initialize it again and claim the same leaf twice.
+/// CHECK:
+///     1. The claim status belongs to the distributor and the claimant, who signed
+///     2. The locked tokens have all been withdrawn or the distributor has been clawed back
+#[allow(clippy::result_large_err)]
+pub fn handle_close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
+    let claim_status_info = ctx.accounts.claim_status.to_account_info();
+    let claim_status =
+        ClaimStatus::try_deserialize(&mut &claim_status_info.try_borrow_data()?[..])?;
+
+    // the claim status is derived from the leaf claimant, which differs from the claimant
+    // once the claim has been transferred
+    let (claim_status_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"ClaimStatus".as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
+            ctx.accounts.distributor.key().to_bytes().as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        claim_status_info.key(),
+        claim_status_pda,
+        ErrorCode::OwnerMismatch
+    );
+    require_keys_eq!(
+        claim_status.claimant,
+        ctx.accounts.claimant.key(),
+        ErrorCode::OwnerMismatch
+    );
+    require!(
+        claim_status.is_fully_withdrawn() || ctx.accounts.distributor.clawed_back,
+        ErrorCode::ClaimNotFullyWithdrawn
+    );
+
+    claim_status_info.realloc(0, false)?;
+
+    let tombstone_lamports = Rent::get()?.minimum_balance(0);
+    let refund = claim_status_info
+        .lamports()
+        .checked_sub(tombstone_lamports)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    **claim_status_info.try_borrow_mut_lamports()? = tombstone_lamports;
+    let claimant_info = ctx.accounts.claimant.to_account_info();
+    **claimant_info.try_borrow_mut_lamports()? = claimant_info
+        .lamports()
+        .checked_add(refund)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!("Closed claim status, refunded {} lamports", refund);
+    emit!(ClaimStatusClosedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        claimant: claim_status.claimant,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
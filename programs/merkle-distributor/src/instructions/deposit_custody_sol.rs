@@ -0,0 +1,116 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, Transfer},
+    Accounts, Key, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::CustodyDepositedEvent, sol_custody::SolCustody},
+};
+
+/// [merkle_distributor::deposit_custody_sol] accounts.
+#[derive(Accounts)]
+pub struct DepositCustodySol<'info> {
+    /// The [SolCustody] of the owner, initialized on the first deposit.
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"SolCustodyState".as_ref(),
+            owner.key().to_bytes().as_ref(),
+        ],
+        bump,
+        space = SolCustody::LEN,
+        payer = owner
+    )]
+    pub sol_custody: Account<'info, SolCustody>,
+
+    /// Custody vault PDA holding the lamports.
+    /// CHECK: The custody vault doesn't even need to exist, it's just a bucket for SOL.
+    #[account(
+        mut,
+        seeds = [
+            b"SolCustody".as_ref(),
+            owner.key().to_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub custody_vault: UncheckedAccount<'info>,
+
+    /// The owner of the sol custody.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits SOL into the owner's custody by:
+/// 1. Transferring amount_lamports from the owner to the custody vault
+/// 2. Incrementing total_deposited by amount_lamports
+/// 3. Locking withdrawals until lock_until_ts, if set
+///
+/// Lamports sent to the vault directly count as deposited.
+///
+/// CHECK:
+///     1. lock_until_ts does not shorten an existing lock
+#[allow(clippy::result_large_err)]
+pub fn handle_deposit_custody_sol(
+    ctx: Context<DepositCustodySol>,
+    amount_lamports: u64,
+    lock_until_ts: Option<i64>,
+) -> Result<()> {
+    let sol_custody = &mut ctx.accounts.sol_custody;
+    sol_custody.init_if_new(
+        ctx.accounts.owner.key(),
+        *ctx.bumps.get("sol_custody").unwrap(),
+        *ctx.bumps.get("custody_vault").unwrap(),
+    );
+    sol_custody.sync(ctx.accounts.custody_vault.lamports())?;
+
+    if let Some(lock_until_ts) = lock_until_ts {
+        require!(
+            lock_until_ts >= sol_custody.lock_until_ts,
+            ErrorCode::CustodyLockShortened
+        );
+        sol_custody.lock_until_ts = lock_until_ts;
+    }
+
+    if amount_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.custody_vault.to_account_info(),
+                },
+            ),
+            amount_lamports,
+        )?;
+    }
+
+    let sol_custody = &mut ctx.accounts.sol_custody;
+    sol_custody.total_deposited = sol_custody
+        .total_deposited
+        .checked_add(amount_lamports)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Deposited {} lamports into custody, balance {}, locked until {}",
+        amount_lamports,
+        sol_custody.balance(),
+        sol_custody.lock_until_ts
+    );
+
+    emit!(CustodyDepositedEvent {
+        owner: sol_custody.owner,
+        amount: amount_lamports,
+        balance: sol_custody.balance(),
+        lock_until_ts: sol_custody.lock_until_ts,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::FundedEvent, merkle_distributor::MerkleDistributor},
+    utils::{extra_mint_vault_amounts, transfer_fee, transfer_from_signer, transfer_hook_accounts},
+};
+
+/// [merkle_distributor::fund_distributor] accounts.
+#[derive(Accounts)]
+pub struct FundDistributor<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator token account the tokens are transferred from.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = creator.key(),
+        token::token_program = token_program
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator wallet
+    #[account(address = distributor.creator @ ErrorCode::Unauthorized)]
+    pub creator: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Funds the [MerkleDistributor] vault from the creator.
+/// 1. Transfers amount from the creator to the token_vault
+/// 2. Increments total_funded by the amount the vault received, net of transfer fees
+/// 3. Marks the distributor as funded once the vault covers all unclaimed tokens, and the extra
+///    mint vaults all unclaimed tokens of their mint
+///
+/// An amount of 0 only re-checks the vaults, e.g. after tokens were sent to them directly.
+///
+/// CHECK:
+///     1. The distributor has not been clawed back
+///     2. The vault received exactly amount minus the mint's transfer fee for the current epoch
+///
+/// Remaining accounts are forwarded to the token transfer for Token-2022 transfer hooks.
+/// Distributors with extra mints take the extra mint vaults instead, in the order of the extra
+/// mints.
+#[allow(clippy::result_large_err)]
+pub fn handle_fund_distributor<'info>(
+    ctx: Context<'_, '_, '_, 'info, FundDistributor<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.distributor.clawed_back,
+        ErrorCode::ClaimExpired
+    );
+
+    let vault_amount_before = ctx.accounts.token_vault.amount;
+    let mut fee = 0;
+    if amount > 0 {
+        transfer_from_signer(
+            &ctx.accounts.creator,
+            &ctx.accounts.from,
+            &ctx.accounts.token_vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            transfer_hook_accounts(&ctx.accounts.distributor, ctx.remaining_accounts),
+            amount,
+        )?;
+        ctx.accounts.token_vault.reload()?;
+        fee = transfer_fee(
+            &ctx.accounts.mint.to_account_info().try_borrow_data()?,
+            Clock::get()?.epoch,
+            amount,
+        )?;
+    }
+    let vault_amount = ctx.accounts.token_vault.amount;
+    let amount_received = vault_amount
+        .checked_sub(vault_amount_before)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    require!(
+        Some(amount_received) == amount.checked_sub(fee),
+        ErrorCode::InvalidFundingTransfer
+    );
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_funded = distributor
+        .total_funded
+        .checked_add(amount_received)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let shortfall = distributor.funding_shortfall(vault_amount);
+    let extra_vault_amounts = match distributor.num_extra_mints {
+        0 => vec![],
+        _ => extra_mint_vault_amounts(distributor, ctx.remaining_accounts)?,
+    };
+    distributor.funded = shortfall == 0 && distributor.extra_mints_funded(&extra_vault_amounts);
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Funded distributor with {} ({} transfer fee), total funded {}, shortfall {}",
+        amount,
+        fee,
+        distributor.total_funded,
+        shortfall,
+    );
+    emit!(FundedEvent {
+        distributor: distributor.key(),
+        amount: amount_received,
+        total_funded: distributor.total_funded,
+        shortfall,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
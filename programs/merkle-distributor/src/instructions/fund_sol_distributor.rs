@@ -0,0 +1,94 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, Transfer},
+    Accounts, Key, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::FundedEvent, merkle_distributor::MerkleDistributor},
+    utils::sol_vault_amount,
+};
+
+/// [merkle_distributor::fund_sol_distributor] accounts.
+#[derive(Accounts)]
+pub struct FundSolDistributor<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// SOL vault PDA holding the lamports to distribute.
+    /// CHECK: the distributor's vault, only receives lamports
+    #[account(mut, address = distributor.token_vault)]
+    pub sol_vault: UncheckedAccount<'info>,
+
+    /// Creator wallet the lamports are transferred from.
+    #[account(mut, address = distributor.creator @ ErrorCode::Unauthorized)]
+    pub creator: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Funds the native SOL [MerkleDistributor] vault from the creator.
+/// 1. Transfers amount lamports from the creator to the sol_vault
+/// 2. Increments total_funded by amount
+/// 3. Marks the distributor as funded once the vault covers all unclaimed lamports
+///
+/// An amount of 0 only re-checks the vault, e.g. after lamports were sent to it directly.
+///
+/// CHECK:
+///     1. The distributor pays out native SOL
+///     2. The distributor has not been clawed back
+#[allow(clippy::result_large_err)]
+pub fn handle_fund_sol_distributor(ctx: Context<FundSolDistributor>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.distributor.native_sol,
+        ErrorCode::DistributorKindMismatch
+    );
+    require!(
+        !ctx.accounts.distributor.clawed_back,
+        ErrorCode::ClaimExpired
+    );
+
+    if amount > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.sol_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+    let vault_amount = sol_vault_amount(&ctx.accounts.sol_vault)?;
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_funded = distributor
+        .total_funded
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let shortfall = distributor.funding_shortfall(vault_amount);
+    distributor.funded = shortfall == 0;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Funded distributor with {} lamports, total funded {}, shortfall {}",
+        amount,
+        distributor.total_funded,
+        shortfall,
+    );
+    emit!(FundedEvent {
+        distributor: distributor.key(),
+        amount,
+        total_funded: distributor.total_funded,
+        shortfall,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
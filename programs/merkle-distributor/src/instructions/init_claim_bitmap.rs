@@ -0,0 +1,67 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_bitmap::ClaimBitmap, events::ClaimBitmapInitializedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::init_claim_bitmap] accounts.
+#[derive(Accounts)]
+#[instruction(page: u32)]
+pub struct InitClaimBitmap<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim bitmap page PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimBitmap".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            page.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimBitmap::LEN,
+        payer = payer
+    )]
+    pub claim_bitmap: Box<Account<'info, ClaimBitmap>>,
+
+    /// Pays for the claim bitmap rent, usually the distributor creator.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Initializes a [ClaimBitmap] page of a bitmap tracked [MerkleDistributor].
+/// CHECK:
+///     1. The distributor tracks claims in a bitmap
+///     2. The page is needed to cover max_num_nodes
+#[allow(clippy::result_large_err)]
+pub fn handle_init_claim_bitmap(ctx: Context<InitClaimBitmap>, page: u32) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(distributor.claim_bitmap, ErrorCode::InvalidClaimMode);
+    require!(
+        page < ClaimBitmap::num_pages(distributor.max_num_nodes),
+        ErrorCode::InvalidClaimBitmap
+    );
+
+    let claim_bitmap = &mut ctx.accounts.claim_bitmap;
+    claim_bitmap.distributor = distributor.key();
+    claim_bitmap.page = page;
+
+    // Note: might get truncated, do not rely on
+    msg!("Initialized claim bitmap page {}", page);
+    emit!(ClaimBitmapInitializedEvent {
+        distributor: distributor.key(),
+        page,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, Transfer},
+    Accounts, Key, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::{ClaimStatus, LAYOUT_VERSION},
+        events::ClaimStatusMigratedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::migrate_claim_status] accounts.
+#[derive(Accounts)]
+pub struct MigrateClaimStatus<'info> {
+    /// The [MerkleDistributor], migrated to the current layout first.
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// The [ClaimStatus] in any layout.
+    /// CHECK: deserialized and its seeds checked in the handler, older layouts don't fit [ClaimStatus]
+    #[account(mut, owner = crate::ID)]
+    pub claim_status: UncheckedAccount<'info>,
+
+    /// Pays the rent of the grown account, can be anyone as the migration only fills in defaults
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a [ClaimStatus] to the current layout by:
+/// 1. Topping up the account to the rent exemption of [ClaimStatus::LEN]
+/// 2. Reallocating it to [ClaimStatus::LEN], the claim vests on the distributor's schedule, its
+///    leaf claimant is its claimant and the other fields it didn't have are zero
+/// 3. Setting its layout_version to [LAYOUT_VERSION]
+///
+/// CHECK:
+///     1. The account is a [ClaimStatus] of an older layout
+///     2. The claim status belongs to the distributor
+#[allow(clippy::result_large_err)]
+pub fn handle_migrate_claim_status(ctx: Context<MigrateClaimStatus>) -> Result<()> {
+    let claim_status_info = ctx.accounts.claim_status.to_account_info();
+    let mut claim_status = ClaimStatus::try_deserialize_any_layout(
+        &claim_status_info.try_borrow_data()?,
+        &ctx.accounts.distributor,
+    )?;
+
+    let distributor_key = ctx.accounts.distributor.key();
+    let (claim_status_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"ClaimStatus".as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
+            distributor_key.to_bytes().as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        claim_status_info.key(),
+        claim_status_pda,
+        ErrorCode::OwnerMismatch
+    );
+    require!(
+        claim_status.needs_migration(),
+        ErrorCode::ClaimStatusAlreadyMigrated
+    );
+
+    let rent_top_up = Rent::get()?
+        .minimum_balance(ClaimStatus::LEN)
+        .saturating_sub(claim_status_info.lamports());
+    if rent_top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: claim_status_info.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+
+    let old_layout_version = claim_status.layout_version;
+    claim_status.layout_version = LAYOUT_VERSION;
+    claim_status_info.realloc(ClaimStatus::LEN, true)?;
+    claim_status.try_serialize(&mut &mut claim_status_info.try_borrow_mut_data()?[..])?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Migrated claim status from layout {} to {}, topped up {} lamports",
+        old_layout_version,
+        LAYOUT_VERSION,
+        rent_top_up
+    );
+    emit!(ClaimStatusMigratedEvent {
+        distributor: distributor_key,
+        leaf_claimant: claim_status.leaf_claimant,
+        old_layout_version,
+        new_layout_version: LAYOUT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, Transfer},
+    Accounts, Key, Result,
+};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        events::DistributorMigratedEvent,
+        merkle_distributor::{MerkleDistributor, LAYOUT_VERSION},
+    },
+};
+
+/// [merkle_distributor::migrate_distributor] accounts.
+#[derive(Accounts)]
+pub struct MigrateDistributor<'info> {
+    /// The [MerkleDistributor] in any layout.
+    /// CHECK: deserialized by the handler, older layouts don't fit [MerkleDistributor]
+    #[account(mut, owner = crate::ID)]
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute, its balance decides whether the
+    /// distributor is funded.
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account of the distributor's mint the unclaimed tokens are clawed back to
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer, pays the rent of the grown account
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a [MerkleDistributor] to the current layout by:
+/// 1. Topping up the account to the rent exemption of [MerkleDistributor::LEN]
+/// 2. Reallocating it to [MerkleDistributor::LEN], the fields it didn't have keep the legacy
+///    behavior, see [MerkleDistributor::try_deserialize_any_layout]
+/// 3. Setting the claim window end to claim_end_ts, open forever by default like before
+/// 4. Setting the clawback receiver and whether the vault covers all unclaimed tokens
+/// 5. Setting its layout_version to [LAYOUT_VERSION]
+///
+/// CHECK:
+///     1. The account is a [MerkleDistributor] of an older layout
+///     2. The admin signed
+///     3. The token vault is the distributor's and the clawback receiver holds its mint
+#[allow(clippy::result_large_err)]
+pub fn handle_migrate_distributor(
+    ctx: Context<MigrateDistributor>,
+    claim_end_ts: Option<i64>,
+) -> Result<()> {
+    let distributor_info = ctx.accounts.distributor.to_account_info();
+    let mut distributor =
+        MerkleDistributor::try_deserialize_any_layout(&distributor_info.try_borrow_data()?)?;
+
+    require_keys_eq!(
+        distributor.admin,
+        ctx.accounts.admin.key(),
+        ErrorCode::Unauthorized
+    );
+    require!(
+        distributor.needs_migration(),
+        ErrorCode::DistributorAlreadyMigrated
+    );
+    require_keys_eq!(
+        ctx.accounts.token_vault.key(),
+        distributor.token_vault,
+        anchor_lang::error::ErrorCode::ConstraintAddress
+    );
+    require_keys_eq!(
+        ctx.accounts.clawback_receiver.mint,
+        distributor.mint,
+        ErrorCode::InvalidClawbackReceiver
+    );
+
+    if let Some(claim_end_ts) = claim_end_ts {
+        distributor.claim_end_ts = claim_end_ts;
+    }
+    distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
+    distributor.funded = distributor.funding_shortfall(ctx.accounts.token_vault.amount) == 0;
+
+    let rent_top_up = Rent::get()?
+        .minimum_balance(MerkleDistributor::LEN)
+        .saturating_sub(distributor_info.lamports());
+    if rent_top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: distributor_info.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+
+    let old_layout_version = distributor.layout_version;
+    distributor.layout_version = LAYOUT_VERSION;
+    distributor_info.realloc(MerkleDistributor::LEN, true)?;
+    distributor.try_serialize(&mut &mut distributor_info.try_borrow_mut_data()?[..])?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Migrated distributor from layout {} to {}, topped up {} lamports, claim_end_ts: {}, funded: {}",
+        old_layout_version,
+        LAYOUT_VERSION,
+        rent_top_up,
+        distributor.claim_end_ts,
+        distributor.funded
+    );
+    emit!(DistributorMigratedEvent {
+        distributor: distributor_info.key(),
+        old_layout_version,
+        new_layout_version: LAYOUT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,66 @@
+pub use accept_admin::*;
+pub use add_extra_mint::*;
+pub use cancel_admin_proposal::*;
+pub use claim_all::*;
 pub use claim_locked::*;
+pub use claim_locked_and_stake::*;
+pub use claim_locked_sol::*;
 pub use clawback::*;
+pub use clawback_sol::*;
+pub use close_claim_status::*;
+pub use deposit_custody_sol::*;
+pub use fund_distributor::*;
+pub use fund_sol_distributor::*;
+pub use init_claim_bitmap::*;
+pub use migrate_claim_status::*;
+pub use migrate_distributor::*;
 pub use new_claim::*;
+pub use new_claim_and_stake::*;
+pub use new_claim_bitmap::*;
+pub use new_claim_sol::*;
 pub use new_distributor::*;
-pub use set_admin::*;
+pub use new_sol_distributor::*;
+pub use propose_admin::*;
+pub use recover_claim::*;
+pub use revoke_claim::*;
+pub use set_clawback_receiver::*;
+pub use set_paused::*;
+pub use set_permissionless_claims::*;
+pub use set_staking_program::*;
+pub use stake_locked::*;
+pub use transfer_claim::*;
+pub use update_root::*;
 pub use withdraw_custody_sol::*;
+pub mod accept_admin;
+pub mod add_extra_mint;
+pub mod cancel_admin_proposal;
+pub mod claim_all;
 pub mod claim_locked;
+pub mod claim_locked_and_stake;
+pub mod claim_locked_sol;
 pub mod clawback;
+pub mod clawback_sol;
+pub mod close_claim_status;
+pub mod deposit_custody_sol;
+pub mod fund_distributor;
+pub mod fund_sol_distributor;
+pub mod init_claim_bitmap;
+pub mod migrate_claim_status;
+pub mod migrate_distributor;
 pub mod new_claim;
+pub mod new_claim_and_stake;
+pub mod new_claim_bitmap;
+pub mod new_claim_sol;
 pub mod new_distributor;
-pub mod set_admin;
+pub mod new_sol_distributor;
+pub mod propose_admin;
+pub mod recover_claim;
+pub mod revoke_claim;
+pub mod set_clawback_receiver;
+pub mod set_paused;
+pub mod set_permissionless_claims;
+pub mod set_staking_program;
+pub mod stake_locked;
+pub mod transfer_claim;
+pub mod update_root;
 pub mod withdraw_custody_sol;
@@ -1,25 +1,27 @@
-use access_merkle_verify::verify;
+use access_merkle_verify::{hash_leaf, verify};
 use anchor_lang::{
     context::Context, prelude::*, solana_program::hash::hashv, system_program::System, Accounts,
     Key, Result,
 };
-use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     error::ErrorCode,
     state::{
-        claim_status::ClaimStatus, claimed_event::NewClaimEvent,
+        claim_status::ClaimStatus, events::NewClaimEvent, extra_mint::ExtraLeafAmount,
         merkle_distributor::MerkleDistributor,
     },
+    utils::{
+        check_claimant_authorized, check_new_claim_allowed, increment_num_nodes_claimed,
+        init_claim_status, record_new_claim, transfer_extra_mints_to_claimant, transfer_fee,
+        transfer_from_distributor, transfer_hook_accounts,
+    },
 };
 
 // We need to discern between leaf and intermediate nodes to prevent trivial second
 // pre-image attacks.
 // https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
-const LEAF_PREFIX: &[u8] = &[0];
+pub const LEAF_PREFIX: &[u8] = &[0];
 
 /// [merkle_distributor::new_claim] accounts.
 #[derive(Accounts)]
@@ -38,33 +40,45 @@ pub struct NewClaim<'info> {
         ],
         bump,
         space = ClaimStatus::LEN,
-        payer = claimant
+        payer = payer
     )]
     pub claim_status: Account<'info, ClaimStatus>,
 
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
     #[account(
         mut,
         token::mint=distributor.mint,
-        token::authority = claimant.key()
+        token::authority = claimant.key(),
+        token::token_program = token_program
     )]
-    pub to: Account<'info, TokenAccount>,
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Who is claiming the tokens.
-    #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
-    pub claimant: Signer<'info>,
+    /// CHECK: has to sign unless the distributor allows permissionless claims and the tokens go
+    /// to their associated token account
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Pays for the claim status rent, can be the claimant or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The [System] program.
     pub system_program: Program<'info, System>,
@@ -72,105 +86,218 @@ pub struct NewClaim<'info> {
 
 /// Initializes a new claim from the [MerkleDistributor].
 /// 1. Increments num_nodes_claimed by 1
-/// 2. Initializes claim_status
+/// 2. Initializes claim_status, with the leaf's own vesting schedule if it has one
+///    and the distributor's schedule otherwise
 /// 3. Transfers claim_status.unlocked_amount to the claimant
-/// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+/// 4. Increments total_amount_claimed by claim_status.unlocked_amount and
+///    total_locked_outstanding by claim_status.locked_amount
+/// 5. Transfers the unlocked amounts of the extra mints, whose locked amounts vest on the
+///    claim's schedule
+///
 /// CHECK:
-///     1. The claim window has not expired and the distributor has not been clawed back
-///     2. The claimant is the owner of the to account
-///     3. Num nodes claimed is less than max_num_nodes
-///     4. The merkle proof is valid
+///     1. The claim window has not ended and the distributor, or its unclaimed allocation,
+///        has not been clawed back
+///     2. The distributor is funded, not paused and tracks claims in claim status accounts
+///     3. The claimant is the owner of the to account and either signed or
+///        the distributor allows permissionless claims and the to account is their
+///        associated token account
+///     4. Num nodes claimed is less than max_num_nodes
+///     5. The merkle proof is valid
+///     6. A leaf vesting schedule has both timestamps set and starts before it ends
+///     7. The leaf has an amount of every extra mint and the extra mint token accounts are
+///        the claimant's
+///
+/// Remaining accounts are forwarded to the token transfer for Token-2022 transfer hooks.
+/// Distributors with extra mints take the extra mint accounts instead, see [extra_mint_accounts].
+///
+/// [extra_mint_accounts]: crate::utils::extra_mint_accounts
 #[allow(clippy::result_large_err)]
-pub fn handle_new_claim(
-    ctx: Context<NewClaim>,
+pub fn handle_new_claim<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaim<'info>>,
     amount_unlocked: u64,
     amount_locked: u64,
+    start_vesting_ts: Option<i64>,
+    end_vesting_ts: Option<i64>,
     proof: Vec<[u8; 32]>,
+    extra_amounts: Vec<ExtraLeafAmount>,
 ) -> Result<()> {
-    let distributor = &mut ctx.accounts.distributor;
+    process_new_claim(
+        ctx,
+        amount_unlocked,
+        amount_locked,
+        start_vesting_ts,
+        end_vesting_ts,
+        proof,
+        extra_amounts,
+        true,
+    )
+}
 
-    let curr_ts = Clock::get()?.unix_timestamp;
-    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+/// [handle_new_claim], leaving the unlocked amount in the vault unless transfer_unlocked is set,
+/// for [merkle_distributor::new_claim_and_stake] to stake it from there.
+#[allow(clippy::result_large_err)]
+pub fn process_new_claim<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaim<'info>>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    start_vesting_ts: Option<i64>,
+    end_vesting_ts: Option<i64>,
+    proof: Vec<[u8; 32]>,
+    extra_amounts: Vec<ExtraLeafAmount>,
+    transfer_unlocked: bool,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
 
-    distributor.num_nodes_claimed = distributor
-        .num_nodes_claimed
-        .checked_add(1)
-        .ok_or(ErrorCode::ArithmeticError)?;
+    let clock = Clock::get()?;
+    let curr_ts = clock.unix_timestamp;
+    check_new_claim_allowed(distributor, curr_ts)?;
+    require!(!distributor.claim_bitmap, ErrorCode::InvalidClaimMode);
 
-    require!(
-        distributor.num_nodes_claimed <= distributor.max_num_nodes,
-        ErrorCode::MaxNodesExceeded
-    );
+    increment_num_nodes_claimed(distributor)?;
 
     let claimant_account = &ctx.accounts.claimant;
-
-    // Verify the merkle proof.
-    let node = hashv(&[
-        &claimant_account.key().to_bytes(),
-        &amount_unlocked.to_le_bytes(),
-        &amount_locked.to_le_bytes(),
-    ]);
+    check_claimant_authorized(
+        distributor,
+        claimant_account,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    )?;
 
     let distributor = &ctx.accounts.distributor;
-    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
 
-    require!(
-        verify(proof, distributor.root, node.to_bytes()),
-        ErrorCode::InvalidProof
-    );
+    let (start_ts, end_ts) = verify_leaf(
+        distributor,
+        &claimant_account.key(),
+        amount_unlocked,
+        amount_locked,
+        start_vesting_ts,
+        end_vesting_ts,
+        &extra_amounts,
+        proof,
+    )?;
 
     let claim_status = &mut ctx.accounts.claim_status;
 
-    // Seed initial values
-    claim_status.claimant = claimant_account.key();
-    claim_status.locked_amount = amount_locked;
-    claim_status.unlocked_amount = amount_unlocked;
-    claim_status.locked_amount_withdrawn = 0;
-
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.creator.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
+    init_claim_status(
+        claim_status,
+        claimant_account.key(),
+        amount_unlocked,
+        amount_locked,
+        start_ts,
+        end_ts,
+    );
+    for (claim_amount, leaf_amount) in claim_status.extra_amounts.iter_mut().zip(&extra_amounts) {
+        claim_amount.locked_amount = leaf_amount.amount_locked;
+        claim_amount.unlocked_amount = leaf_amount.amount_unlocked;
+        claim_amount.locked_amount_withdrawn = 0;
+    }
+
+    if transfer_unlocked {
+        transfer_from_distributor(
+            &ctx.accounts.distributor,
+            &ctx.accounts.from,
+            &ctx.accounts.to,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            transfer_hook_accounts(&ctx.accounts.distributor, ctx.remaining_accounts),
+            claim_status.unlocked_amount,
+        )?;
+    }
+    let extra_amounts_unlocked: Vec<u64> = extra_amounts
+        .iter()
+        .map(|leaf_amount| leaf_amount.amount_unlocked)
+        .collect();
+    transfer_extra_mints_to_claimant(
+        &mut ctx.accounts.distributor,
+        claimant_account,
+        ctx.remaining_accounts,
+        &extra_amounts_unlocked,
+    )?;
+    let fee = transfer_fee(
+        &ctx.accounts.mint.to_account_info().try_borrow_data()?,
+        clock.epoch,
         claim_status.unlocked_amount,
     )?;
 
     let distributor = &mut ctx.accounts.distributor;
-    distributor.total_amount_claimed = distributor
-        .total_amount_claimed
-        .checked_add(claim_status.unlocked_amount)
-        .ok_or(ErrorCode::ArithmeticError)?;
-
-    require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
-        ErrorCode::ExceededMaxClaim
-    );
+    record_new_claim(
+        distributor,
+        claim_status.unlocked_amount,
+        claim_status.locked_amount,
+    )?;
 
     // Note: might get truncated, do not rely on
     msg!(
-        "Created new claim with locked {} and {} unlocked with lockup start:{} end:{}",
+        "Created new claim with locked {} and {} unlocked ({} transfer fee) with lockup start:{} end:{} cliff:{}",
         claim_status.locked_amount,
         claim_status.unlocked_amount,
-        distributor.start_ts,
-        distributor.end_ts,
+        fee,
+        claim_status.start_ts,
+        claim_status.end_ts,
+        distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
     );
     emit!(NewClaimEvent {
         claimant: claimant_account.key(),
-        timestamp: curr_ts
+        timestamp: curr_ts,
+        distributor: distributor.key(),
+        amount_unlocked: claim_status.unlocked_amount,
+        amount_locked: claim_status.locked_amount,
+        extra_amounts,
     });
 
     Ok(())
 }
+
+/// Verifies the merkle proof of a claimant's leaf and returns its vesting schedule,
+/// the distributor's schedule if the leaf has none.
+/// Leaves without their own vesting schedule don't hash any timestamps, and leaves of
+/// distributors without extra mints don't hash any extra amounts. Leaves with either are
+/// tagged with the fields they carry, see [hash_leaf].
+#[allow(clippy::result_large_err)]
+pub fn verify_leaf(
+    distributor: &MerkleDistributor,
+    claimant: &Pubkey,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    start_vesting_ts: Option<i64>,
+    end_vesting_ts: Option<i64>,
+    extra_amounts: &[ExtraLeafAmount],
+    proof: Vec<[u8; 32]>,
+) -> Result<(i64, i64)> {
+    require!(
+        extra_amounts.len() == distributor.extra_mints().len(),
+        ErrorCode::InvalidProof
+    );
+
+    let (start_ts, end_ts) = match (start_vesting_ts, end_vesting_ts) {
+        (Some(start_ts), Some(end_ts)) => {
+            require!(start_ts < end_ts, ErrorCode::StartTimestampAfterEnd);
+            (start_ts, end_ts)
+        }
+        (None, None) => (distributor.start_ts, distributor.end_ts),
+        _ => return err!(ErrorCode::PartialVestingSchedule),
+    };
+
+    let extra_amounts: Vec<(u64, u64)> = extra_amounts
+        .iter()
+        .map(|amount| (amount.amount_unlocked, amount.amount_locked))
+        .collect();
+    let node = hash_leaf(
+        &claimant.to_bytes(),
+        amount_unlocked,
+        amount_locked,
+        start_vesting_ts.map(|_| (start_ts, end_ts)),
+        &extra_amounts,
+        None,
+    );
+
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
+    );
+
+    Ok((start_ts, end_ts))
+}
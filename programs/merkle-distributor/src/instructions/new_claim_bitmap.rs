@@ -0,0 +1,165 @@
+use access_merkle_verify::{hash_leaf, verify};
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::hash::hashv, Accounts, Key, Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::LEAF_PREFIX,
+    state::{
+        claim_bitmap::ClaimBitmap, events::NewClaimEvent, merkle_distributor::MerkleDistributor,
+    },
+    utils::{
+        check_claimant_authorized, check_new_claim_allowed, increment_num_nodes_claimed,
+        record_new_claim, transfer_fee, transfer_from_distributor,
+    },
+};
+
+/// [merkle_distributor::new_claim_bitmap] accounts.
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct NewClaimBitmap<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim bitmap page PDA holding the leaf's bit
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimBitmap".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            ClaimBitmap::page_of(index).to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_bitmap: Box<Account<'info, ClaimBitmap>>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = claimant.key(),
+        token::token_program = token_program
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    /// CHECK: has to sign unless the distributor allows permissionless claims and the tokens go
+    /// to their associated token account
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: UncheckedAccount<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claims a leaf of a bitmap tracked [MerkleDistributor] without creating a claim status.
+/// 1. Sets the leaf's bit in the claim bitmap
+/// 2. Increments num_nodes_claimed by 1
+/// 3. Transfers amount_unlocked to the claimant
+/// 4. Increments total_amount_claimed by amount_unlocked
+///
+/// CHECK:
+///     1. The distributor tracks claims in a bitmap, has not been clawed back, is funded and
+///        is not paused, and the claim window has not ended or been clawed back
+///     2. The claimant is the owner of the to account and either signed or
+///        the distributor allows permissionless claims and the to account is their
+///        associated token account
+///     3. The merkle proof of the indexed, unlocked only leaf is valid
+///     4. The leaf has not been claimed before
+///     5. Num nodes claimed is less than max_num_nodes
+///
+/// Remaining accounts are forwarded to the token transfer for Token-2022 transfer hooks.
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_bitmap<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaimBitmap<'info>>,
+    index: u64,
+    amount_unlocked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    let clock = Clock::get()?;
+    require!(distributor.claim_bitmap, ErrorCode::InvalidClaimMode);
+    check_new_claim_allowed(distributor, clock.unix_timestamp)?;
+
+    let claimant_account = &ctx.accounts.claimant;
+    check_claimant_authorized(
+        distributor,
+        claimant_account,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Verify the merkle proof, bitmap tracked leaves never have locked tokens.
+    let node = hash_leaf(
+        &claimant_account.key().to_bytes(),
+        amount_unlocked,
+        0,
+        None,
+        &[],
+        Some(index),
+    );
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
+    );
+
+    ctx.accounts.claim_bitmap.set_claimed(index)?;
+
+    transfer_from_distributor(
+        &ctx.accounts.distributor,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        amount_unlocked,
+    )?;
+    let fee = transfer_fee(
+        &ctx.accounts.mint.to_account_info().try_borrow_data()?,
+        clock.epoch,
+        amount_unlocked,
+    )?;
+
+    let distributor = &mut ctx.accounts.distributor;
+    increment_num_nodes_claimed(distributor)?;
+    record_new_claim(distributor, amount_unlocked, 0)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Claimed leaf {} with {} unlocked ({} transfer fee)",
+        index,
+        amount_unlocked,
+        fee,
+    );
+    emit!(NewClaimEvent {
+        claimant: claimant_account.key(),
+        timestamp: clock.unix_timestamp,
+        distributor: distributor.key(),
+        amount_unlocked,
+        amount_locked: 0,
+        extra_amounts: vec![],
+    });
+
+    Ok(())
+}
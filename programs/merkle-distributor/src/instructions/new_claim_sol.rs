@@ -0,0 +1,161 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::verify_leaf,
+    state::{
+        claim_status::ClaimStatus, events::NewClaimEvent, merkle_distributor::MerkleDistributor,
+    },
+    utils::{
+        check_new_claim_allowed, increment_num_nodes_claimed, init_claim_status, record_new_claim,
+        transfer_from_sol_vault,
+    },
+};
+
+/// [merkle_distributor::new_claim_sol] accounts.
+#[derive(Accounts)]
+pub struct NewClaimSol<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = payer
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// SOL vault PDA holding the lamports to distribute.
+    /// CHECK: the distributor's vault, a bucket for SOL owned by the system program
+    #[account(
+        mut,
+        seeds = [
+            b"SolVault".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+        ],
+        bump,
+        address = distributor.token_vault
+    )]
+    pub sol_vault: UncheckedAccount<'info>,
+
+    /// Who is claiming the lamports, receives them directly.
+    /// CHECK: only receives lamports, the merkle proof ties it to the leaf. Has to sign unless
+    /// the distributor allows permissionless claims
+    #[account(mut)]
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Pays for the claim status rent, can be the claimant or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Initializes a new claim from a native SOL [MerkleDistributor].
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Initializes claim_status, with the leaf's own vesting schedule if it has one
+///    and the distributor's schedule otherwise
+/// 3. Transfers claim_status.unlocked_amount lamports to the claimant
+/// 4. Increments total_amount_claimed by claim_status.unlocked_amount and
+///    total_locked_outstanding by claim_status.locked_amount
+///
+/// CHECK:
+///     1. The distributor pays out native SOL
+///     2. The claim window has not ended and the distributor, or its unclaimed allocation,
+///        has not been clawed back
+///     3. The distributor is funded and not paused
+///     4. The claimant signed or the distributor allows permissionless claims
+///     5. Num nodes claimed is less than max_num_nodes
+///     6. The merkle proof is valid
+///     7. A leaf vesting schedule has both timestamps set and starts before it ends
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_sol(
+    ctx: Context<NewClaimSol>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    start_vesting_ts: Option<i64>,
+    end_vesting_ts: Option<i64>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+    require!(distributor.native_sol, ErrorCode::DistributorKindMismatch);
+    check_new_claim_allowed(distributor, curr_ts)?;
+    require!(
+        ctx.accounts.claimant.is_signer || distributor.permissionless_claims,
+        ErrorCode::ClaimantSignatureRequired
+    );
+
+    increment_num_nodes_claimed(distributor)?;
+
+    let claimant_account = &ctx.accounts.claimant;
+    let (start_ts, end_ts) = verify_leaf(
+        distributor,
+        &claimant_account.key(),
+        amount_unlocked,
+        amount_locked,
+        start_vesting_ts,
+        end_vesting_ts,
+        &[],
+        proof,
+    )?;
+
+    let claim_status = &mut ctx.accounts.claim_status;
+
+    init_claim_status(
+        claim_status,
+        claimant_account.key(),
+        amount_unlocked,
+        amount_locked,
+        start_ts,
+        end_ts,
+    );
+
+    if claim_status.unlocked_amount > 0 {
+        transfer_from_sol_vault(
+            &ctx.accounts.distributor,
+            &ctx.accounts.sol_vault,
+            claimant_account,
+            &ctx.accounts.system_program,
+            *ctx.bumps.get("sol_vault").unwrap(),
+            claim_status.unlocked_amount,
+        )?;
+    }
+
+    let distributor = &mut ctx.accounts.distributor;
+    record_new_claim(
+        distributor,
+        claim_status.unlocked_amount,
+        claim_status.locked_amount,
+    )?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim with locked {} and {} unlocked lamports with lockup start:{} end:{} cliff:{}",
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        claim_status.start_ts,
+        claim_status.end_ts,
+        distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+    );
+    emit!(NewClaimEvent {
+        claimant: claimant_account.key(),
+        timestamp: curr_ts,
+        distributor: distributor.key(),
+        amount_unlocked: claim_status.unlocked_amount,
+        amount_locked: claim_status.locked_amount,
+        extra_amounts: vec![],
+    });
+
+    Ok(())
+}
@@ -1,10 +1,21 @@
 use anchor_lang::{account, context::Context, prelude::*, Accounts, Key, ToAccountInfo};
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_bitmap::MAX_BITMAP_NODES,
+        events::NewDistributorEvent,
+        merkle_distributor::{MerkleDistributor, LAYOUT_VERSION},
+        vesting_mode::VestingMode,
+    },
+};
+
+/// Minimum delay in seconds between the end of vesting and the clawback start.
+pub const MIN_CLAWBACK_DELAY: i64 = 24 * 3600;
 
 /// Accounts for [merkle_distributor::handle_new_distributor].
 #[derive(Accounts)]
@@ -26,14 +37,25 @@ pub struct NewDistributor<'info> {
     pub distributor: Account<'info, MerkleDistributor>,
 
     /// The mint to distribute.
-    pub mint: Account<'info, Mint>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Token vault
+    /// Token vault, the distributor's associated token account
     #[account(
-        associated_token::mint = mint,
-        associated_token::authority=distributor,
+        token::mint = mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+        address = get_associated_token_address_with_program_id(
+            &distributor.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Clawback receiver token account
+    #[account(token::mint = mint, token::token_program = token_program)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
 
     /// Creator wallet, responsible for creating the distributor and paying for the transaction.    
     #[account(mut)]
@@ -45,18 +67,25 @@ pub struct NewDistributor<'info> {
     /// The [Associated Token] program.
     pub associated_token_program: Program<'info, AssociatedToken>,
 
-    /// The [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Creates a new [MerkleDistributor].
 /// After creating this [MerkleDistributor],
-/// the token_vault should be seeded with max_total_claim tokens.
+/// the token_vault should be seeded with max_total_claim tokens through fund_distributor,
+/// claims are rejected until then.
+/// Distributors tracking claims in a bitmap also need their claim bitmap pages initialized.
+/// Leaves with their own vesting schedule have to end at least [MIN_CLAWBACK_DELAY] before the
+/// clawback start as well, which can't be checked on-chain and is enforced when building the tree.
 /// CHECK:
 ///     1. The start timestamp is before the end timestamp
-///     2. The clawback timestamp is after the end timestamp
-///     3. The start, end, and clawback_start timestamps are all in the future
-///     4. The clawback start is at least one day after end timestamp
+///     2. The start, end, claim_end and clawback_start timestamps are all in the future
+///     3. The clawback start is at least one day after end timestamp
+///     4. The cliff timestamp is between the start and end timestamps
+///     5. The cliff percentage is at most 100
+///     6. The vesting mode parameters are valid
+///     7. Bitmap tracked distributors have at most [MAX_BITMAP_NODES] nodes
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::result_large_err)]
 pub fn handle_new_distributor(
@@ -67,6 +96,87 @@ pub fn handle_new_distributor(
     max_num_nodes: u64,
     start_vesting_ts: i64,
     end_vesting_ts: i64,
+    cliff_vesting_ts: i64,
+    cliff_percentage: u8,
+    vesting_mode: VestingMode,
+    clawback_start_ts: i64,
+    claim_bitmap: bool,
+    claim_end_ts: i64,
+    revocable: bool,
+    recoverable: bool,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.bump = *ctx.bumps.get("distributor").unwrap();
+    distributor.mint = ctx.accounts.mint.key();
+    distributor.token_vault = ctx.accounts.token_vault.key();
+    distributor.creator = ctx.accounts.creator.key();
+    distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
+    distributor.native_sol = false;
+
+    init_distributor(
+        distributor,
+        version,
+        root,
+        max_total_claim,
+        max_num_nodes,
+        start_vesting_ts,
+        end_vesting_ts,
+        cliff_vesting_ts,
+        cliff_percentage,
+        vesting_mode,
+        clawback_start_ts,
+        claim_bitmap,
+        claim_end_ts,
+        revocable,
+        recoverable,
+    )?;
+
+    emit_new_distributor_event(&ctx.accounts.distributor)
+}
+
+/// Emits the [NewDistributorEvent] of an initialized [MerkleDistributor].
+#[allow(clippy::result_large_err)]
+pub fn emit_new_distributor_event(distributor: &Account<MerkleDistributor>) -> Result<()> {
+    emit!(NewDistributorEvent {
+        distributor: distributor.key(),
+        mint: distributor.mint,
+        token_vault: distributor.token_vault,
+        admin: distributor.admin,
+        version: distributor.version,
+        max_total_claim: distributor.max_total_claim,
+        max_num_nodes: distributor.max_num_nodes,
+        start_ts: distributor.start_ts,
+        end_ts: distributor.end_ts,
+        clawback_start_ts: distributor.clawback_start_ts,
+        claim_end_ts: distributor.claim_end_ts,
+        native_sol: distributor.native_sol,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Checks the distributor parameters and initializes the remaining fields of a
+/// [MerkleDistributor] whose accounts, creator and clawback receiver have been set.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::result_large_err)]
+pub fn init_distributor(
+    distributor: &mut MerkleDistributor,
+    version: u64,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+    start_vesting_ts: i64,
+    end_vesting_ts: i64,
+    cliff_vesting_ts: i64,
+    cliff_percentage: u8,
+    vesting_mode: VestingMode,
+    clawback_start_ts: i64,
+    claim_bitmap: bool,
+    claim_end_ts: i64,
+    revocable: bool,
+    recoverable: bool,
 ) -> Result<()> {
     let curr_ts = Clock::get()?.unix_timestamp;
 
@@ -76,37 +186,75 @@ pub fn handle_new_distributor(
     );
     // New distributor parameters must all be set in the future
     require!(
-        start_vesting_ts > curr_ts && end_vesting_ts > curr_ts,
+        start_vesting_ts > curr_ts && end_vesting_ts > curr_ts && claim_end_ts > curr_ts,
         ErrorCode::TimestampsNotInFuture
     );
+    // The clawback start is after the end timestamp, so it is in the future as well
+    require!(
+        clawback_start_ts
+            >= end_vesting_ts
+                .checked_add(MIN_CLAWBACK_DELAY)
+                .ok_or(ErrorCode::ArithmeticError)?,
+        ErrorCode::InsufficientClawbackDelay
+    );
+    require!(
+        start_vesting_ts <= cliff_vesting_ts && cliff_vesting_ts <= end_vesting_ts,
+        ErrorCode::CliffOutsideVestingPeriod
+    );
+    require!(cliff_percentage <= 100, ErrorCode::InvalidCliffPercentage);
+    require!(vesting_mode.is_valid(), ErrorCode::InvalidVestingMode);
+    require!(
+        !claim_bitmap || max_num_nodes <= MAX_BITMAP_NODES,
+        ErrorCode::MaxNodesExceeded
+    );
 
-    let distributor = &mut ctx.accounts.distributor;
-
-    distributor.bump = *ctx.bumps.get("distributor").unwrap();
     distributor.version = version;
     distributor.root = root;
-    distributor.mint = ctx.accounts.mint.key();
-    distributor.token_vault = ctx.accounts.token_vault.key();
     distributor.max_total_claim = max_total_claim;
     distributor.max_num_nodes = max_num_nodes;
     distributor.total_amount_claimed = 0;
     distributor.num_nodes_claimed = 0;
     distributor.start_ts = start_vesting_ts;
     distributor.end_ts = end_vesting_ts;
-    distributor.creator = ctx.accounts.creator.key();
-    distributor.admin = ctx.accounts.creator.key();
+    distributor.admin = distributor.creator;
+    distributor.pending_admin = Pubkey::default();
     distributor.clawed_back = false;
+    distributor.cliff_ts = cliff_vesting_ts;
+    distributor.cliff_percentage = cliff_percentage;
+    distributor.vesting_mode = vesting_mode;
+    distributor.paused = false;
+    distributor.clawback_start_ts = clawback_start_ts;
+    distributor.claim_bitmap = claim_bitmap;
+    distributor.total_funded = 0;
+    distributor.funded = false;
+    distributor.claim_end_ts = claim_end_ts;
+    distributor.total_locked_outstanding = 0;
+    distributor.amount_clawed_back = 0;
+    distributor.unclaimed_clawed_back = false;
+    distributor.revocable = revocable;
+    distributor.recoverable = recoverable;
+    distributor.layout_version = LAYOUT_VERSION;
 
     // Note: might get truncated, do not rely on
     msg! {
-        "New distributor created with version = {}, mint={}, vault={} max_total_claim={}, max_nodes: {}, start_ts: {}, end_ts: {}",
+        "New distributor created with version = {}, mint={}, vault={} max_total_claim={}, max_nodes: {}, start_ts: {}, end_ts: {}, cliff_ts: {}, cliff_percentage: {}, vesting_mode: {:?}, clawback_start_ts: {}, clawback_receiver: {}, claim_bitmap: {}, claim_end_ts: {}, revocable: {}, recoverable: {}, native_sol: {}",
             distributor.version,
             distributor.mint,
-            ctx.accounts.token_vault.key(),
+            distributor.token_vault,
             distributor.max_total_claim,
             distributor.max_num_nodes,
             distributor.start_ts,
             distributor.end_ts,
+            distributor.cliff_ts,
+            distributor.cliff_percentage,
+            distributor.vesting_mode,
+            distributor.clawback_start_ts,
+            distributor.clawback_receiver,
+            distributor.claim_bitmap,
+            distributor.claim_end_ts,
+            distributor.revocable,
+            distributor.recoverable,
+            distributor.native_sol,
     };
 
     Ok(())
@@ -0,0 +1,131 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, Transfer},
+    Accounts, Key, ToAccountInfo,
+};
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::{
+    instructions::new_distributor::{emit_new_distributor_event, init_distributor},
+    state::{merkle_distributor::MerkleDistributor, vesting_mode::VestingMode},
+};
+
+/// Accounts for [merkle_distributor::handle_new_sol_distributor].
+#[derive(Accounts)]
+#[instruction(version: u64)]
+pub struct NewSolDistributor<'info> {
+    /// [MerkleDistributor], derived with its own seed so it can't collide with a wrapped SOL
+    /// token distributor of the same creator and version.
+    #[account(
+        init,
+        seeds = [
+            b"SolDistributor".as_ref(),
+            creator.key().to_bytes().as_ref(),
+            version.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = MerkleDistributor::LEN,
+        payer = creator
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// SOL vault PDA holding the lamports to distribute.
+    /// CHECK: The sol_vault is just a bucket for SOL owned by the system program.
+    #[account(
+        mut,
+        seeds = [
+            b"SolVault".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub sol_vault: UncheckedAccount<'info>,
+
+    /// Clawback receiver wallet
+    /// CHECK: only receives lamports
+    pub clawback_receiver: UncheckedAccount<'info>,
+
+    /// Creator wallet, responsible for creating the distributor and paying for the transaction.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a new [MerkleDistributor] paying out native SOL.
+/// The creator tops the sol_vault up to the rent-exempt minimum, which is kept out of the
+/// distribution. After creating this [MerkleDistributor],
+/// the sol_vault should be seeded with max_total_claim lamports through fund_sol_distributor,
+/// claims are rejected until then.
+/// Native SOL distributors track claims in claim status accounts and can't revoke claims.
+/// CHECK:
+///     1. The start timestamp is before the end timestamp
+///     2. The start, end, claim_end and clawback_start timestamps are all in the future
+///     3. The clawback start is at least one day after end timestamp
+///     4. The cliff timestamp is between the start and end timestamps
+///     5. The cliff percentage is at most 100
+///     6. The vesting mode parameters are valid
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::result_large_err)]
+pub fn handle_new_sol_distributor(
+    ctx: Context<NewSolDistributor>,
+    version: u64,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+    start_vesting_ts: i64,
+    end_vesting_ts: i64,
+    cliff_vesting_ts: i64,
+    cliff_percentage: u8,
+    vesting_mode: VestingMode,
+    clawback_start_ts: i64,
+    claim_end_ts: i64,
+    recoverable: bool,
+) -> Result<()> {
+    let rent_exempt_reserve = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.sol_vault.lamports());
+    if rent_exempt_reserve > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.sol_vault.to_account_info(),
+                },
+            ),
+            rent_exempt_reserve,
+        )?;
+    }
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.bump = *ctx.bumps.get("distributor").unwrap();
+    distributor.mint = native_mint::ID;
+    distributor.token_vault = ctx.accounts.sol_vault.key();
+    distributor.creator = ctx.accounts.creator.key();
+    distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
+    distributor.native_sol = true;
+
+    init_distributor(
+        distributor,
+        version,
+        root,
+        max_total_claim,
+        max_num_nodes,
+        start_vesting_ts,
+        end_vesting_ts,
+        cliff_vesting_ts,
+        cliff_percentage,
+        vesting_mode,
+        clawback_start_ts,
+        false,
+        claim_end_ts,
+        false,
+        recoverable,
+    )?;
+
+    emit_new_distributor_event(&ctx.accounts.distributor)
+}
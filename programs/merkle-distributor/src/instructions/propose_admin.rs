@@ -0,0 +1,54 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::AdminProposedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::propose_admin] accounts.
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// Proposed new admin account
+    /// CHECK: this can be any new account, it has to sign accept_admin to become the admin
+    pub new_admin: AccountInfo<'info>,
+}
+
+/// Proposes a new admin account, which becomes the admin once it accepts.
+/// Replaces any previous pending proposal.
+/// CHECK:
+///     1. The new admin is not the same as the old one
+#[allow(clippy::result_large_err)]
+pub fn handle_propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(
+        ctx.accounts.admin.key != &ctx.accounts.new_admin.key(),
+        ErrorCode::SameAdmin
+    );
+
+    distributor.pending_admin = ctx.accounts.new_admin.key();
+
+    // Note: might get truncated, do not rely on
+    msg!("proposed new admin {}", ctx.accounts.new_admin.key());
+    emit!(AdminProposedEvent {
+        distributor: distributor.key(),
+        admin: distributor.admin,
+        pending_admin: distributor.pending_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    instructions::transfer_claim::set_claimant,
+    state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::recover_claim] accounts.
+#[derive(Accounts)]
+pub struct RecoverClaim<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA, derived from the leaf claimant even if the claim was transferred
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// New wallet of the claimant, signs to prove it controls the key
+    pub new_claimant: Signer<'info>,
+}
+
+/// Moves a claim to a new wallet of a claimant who lost access to theirs.
+/// The admin verifies the claimant off-chain and co-signs with the new wallet.
+/// CHECK:
+///     1. The distributor is recoverable
+///     2. The admin and the new claimant signed
+///     3. The new claimant differs from the current claimant
+#[allow(clippy::result_large_err)]
+pub fn handle_recover_claim(ctx: Context<RecoverClaim>) -> Result<()> {
+    require!(
+        ctx.accounts.distributor.recoverable,
+        ErrorCode::NotRecoverable
+    );
+
+    set_claimant(
+        &mut ctx.accounts.claim_status,
+        ctx.accounts.distributor.key(),
+        ctx.accounts.new_claimant.key(),
+        true,
+    )
+}
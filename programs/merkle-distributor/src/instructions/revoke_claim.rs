@@ -0,0 +1,148 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, events::ClaimRevokedEvent, merkle_distributor::MerkleDistributor,
+    },
+    utils::transfer_from_distributor,
+};
+
+/// [merkle_distributor::revoke_claim] accounts.
+#[derive(Accounts)]
+pub struct RevokeClaim<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA of the claim to revoke
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account.
+    #[account(
+        mut,
+        address = distributor.clawback_receiver @ ErrorCode::InvalidClawbackReceiver,
+        token::mint = distributor.mint,
+        token::token_program = token_program
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Revokes the unvested locked tokens of a claim.
+/// 1. Freezes the claim_status at its currently vested amount, which the claimant can still withdraw
+/// 2. Transfers the unvested locked amount to the clawback receiver
+/// 3. Decrements total_locked_outstanding and increments amount_clawed_back by that amount
+///
+/// CHECK:
+///     1. The distributor is revocable and has not been clawed back
+///     2. The claim has not been revoked already
+///     3. The claim has no staked locked tokens, the admin can unstake them first
+///     4. The claim has no extra mint amounts, which are not revoked
+///
+/// Remaining accounts are forwarded to the token transfer for Token-2022 transfer hooks.
+#[allow(clippy::result_large_err)]
+pub fn handle_revoke_claim<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevokeClaim<'info>>,
+) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(distributor.revocable, ErrorCode::NotRevocable);
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+
+    let claim_status = &mut ctx.accounts.claim_status;
+    require!(!claim_status.is_revoked(), ErrorCode::ClaimRevoked);
+    require!(
+        claim_status.locked_amount_staked == 0,
+        ErrorCode::LockedTokensStaked
+    );
+    require!(
+        !claim_status.has_extra_amounts(),
+        ErrorCode::ExtraMintsUnsupported
+    );
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+    let vested = claim_status.unlocked_amount(
+        curr_ts,
+        claim_status.start_ts,
+        claim_status.end_ts,
+        distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+        distributor.cliff_percentage,
+        &distributor.vesting_mode,
+    )?;
+    let amount = claim_status
+        .locked_amount
+        .checked_sub(vested)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    claim_status.revoked_ts = curr_ts;
+    claim_status.locked_amount_revoked = amount;
+
+    if amount > 0 {
+        transfer_from_distributor(
+            distributor,
+            &ctx.accounts.from,
+            &ctx.accounts.to,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            ctx.remaining_accounts,
+            amount,
+        )?;
+    }
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_locked_outstanding = distributor
+        .total_locked_outstanding
+        .checked_sub(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    distributor.amount_clawed_back = distributor
+        .amount_clawed_back
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Revoked claim of {}, returned {} unvested and left {} vested",
+        claim_status.claimant,
+        amount,
+        vested
+    );
+    emit!(ClaimRevokedEvent {
+        distributor: distributor.key(),
+        leaf_claimant: claim_status.leaf_claimant,
+        amount,
+        timestamp: curr_ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::ErrorCode,
+    state::{events::ClawbackReceiverSetEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::set_clawback_receiver] accounts.
+#[derive(Accounts)]
+pub struct SetClawbackReceiver<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// New clawback receiver token account
+    #[account(token::mint = distributor.mint)]
+    pub new_clawback_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Sets new clawback receiver token account
+/// CHECK:
+///     1. The distributor pays out tokens, native SOL distributors claw back to a wallet
+///     2. The new clawback receiver is not the same as the old one
+#[allow(clippy::result_large_err)]
+pub fn handle_set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(!distributor.native_sol, ErrorCode::DistributorKindMismatch);
+
+    require!(
+        distributor.clawback_receiver != ctx.accounts.new_clawback_account.key(),
+        ErrorCode::SameClawbackReceiver
+    );
+
+    let old_receiver = distributor.clawback_receiver;
+    distributor.clawback_receiver = ctx.accounts.new_clawback_account.key();
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "set new clawback receiver to {}",
+        ctx.accounts.new_clawback_account.key()
+    );
+    emit!(ClawbackReceiverSetEvent {
+        distributor: distributor.key(),
+        old_receiver,
+        new_receiver: distributor.clawback_receiver,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
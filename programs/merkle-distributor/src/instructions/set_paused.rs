@@ -0,0 +1,41 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::PausedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::pause] and [merkle_distributor::unpause] accounts.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Pauses or unpauses new_claim and claim_locked, without moving any funds
+#[allow(clippy::result_large_err)]
+pub fn handle_set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.paused = paused;
+
+    // Note: might get truncated, do not rely on
+    msg!("set distributor paused to {}", paused);
+    emit!(PausedEvent {
+        distributor: distributor.key(),
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::PermissionlessClaimsSetEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::set_permissionless_claims] accounts.
+#[derive(Accounts)]
+pub struct SetPermissionlessClaims<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Enables or disables claims without the claimant's signature, which can only pay the
+/// claimant's associated token account. Claims that also stake always need the signature.
+#[allow(clippy::result_large_err)]
+pub fn handle_set_permissionless_claims(
+    ctx: Context<SetPermissionlessClaims>,
+    permissionless_claims: bool,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.permissionless_claims = permissionless_claims;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "set distributor permissionless claims to {}",
+        permissionless_claims
+    );
+    emit!(PermissionlessClaimsSetEvent {
+        distributor: distributor.key(),
+        permissionless_claims,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{events::StakingProgramSetEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::set_staking_program] accounts.
+#[derive(Accounts)]
+pub struct SetStakingProgram<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Staking program claimed tokens are staked into
+    /// CHECK: any executable program implementing the stake instruction, chosen by the admin
+    #[account(executable)]
+    pub staking_program: UncheckedAccount<'info>,
+
+    /// Stake pool of the staking program
+    /// CHECK: owned by the staking program, which validates it when staking
+    #[account(owner = staking_program.key() @ ErrorCode::InvalidStakingProgram)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Sets the staking program and stake pool claimants can stake their claimed tokens into.
+/// They are pinned once set, so claimants know which program their staked tokens go to.
+/// The stake pool must not charge a staking fee, stakes into a pool that does are rejected.
+/// CHECK:
+///     1. The distributor pays out tokens
+///     2. No staking program has been set yet and no locked tokens are staked
+#[allow(clippy::result_large_err)]
+pub fn handle_set_staking_program(ctx: Context<SetStakingProgram>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(!distributor.native_sol, ErrorCode::DistributorKindMismatch);
+    require!(
+        distributor.staking_program == Pubkey::default(),
+        ErrorCode::StakingProgramAlreadySet
+    );
+    require!(
+        distributor.total_locked_staked == 0,
+        ErrorCode::LockedTokensStaked
+    );
+
+    distributor.staking_program = ctx.accounts.staking_program.key();
+    distributor.stake_pool = ctx.accounts.stake_pool.key();
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "set staking program to {} with stake pool {}",
+        distributor.staking_program,
+        distributor.stake_pool
+    );
+    emit!(StakingProgramSetEvent {
+        distributor: distributor.key(),
+        staking_program: distributor.staking_program,
+        stake_pool: distributor.stake_pool,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
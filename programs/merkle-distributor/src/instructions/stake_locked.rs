@@ -0,0 +1,261 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result, ToAccountInfo};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus,
+        events::{StakedEvent, UnstakedEvent},
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{stake_from_vault, unstake_tokens},
+};
+
+/// [merkle_distributor::stake_locked] and [merkle_distributor::unstake_locked] accounts.
+#[derive(Accounts)]
+pub struct StakeLocked<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA, derived from the leaf claimant even if the claim was transferred
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA holding the idle locked tokens.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The claimant, or the admin when unstaking
+    pub authority: Signer<'info>,
+
+    /// The SPL Token or Token-2022 program of the mint.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Central state of the staking program
+    /// CHECK: PDA of the staking program
+    #[account(
+        mut,
+        seeds = [staking_program.key().as_ref()],
+        bump,
+        seeds::program = staking_program.key(),
+    )]
+    pub central_state: UncheckedAccount<'info>,
+
+    /// Stake pool of the staking program
+    /// CHECK: the distributor's stake pool, validated by the staking program
+    #[account(mut, address = distributor.stake_pool @ ErrorCode::InvalidStakingProgram)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// Owner of the claim's stake account, only ever delegated the amount being staked
+    /// CHECK: PDA of the claim, signs the stake as the vault's delegate and the unstake as the
+    /// owner of the stake account
+    #[account(
+        seeds = [
+            b"StakeAuthority".as_ref(),
+            claim_status.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub stake_authority: UncheckedAccount<'info>,
+
+    /// The claim's stake account, the stake authority's in the distributor's stake pool
+    /// CHECK: PDA of the staking program, validated by it
+    #[account(
+        mut,
+        seeds = [
+            b"stake_account".as_ref(),
+            stake_authority.key().as_ref(),
+            stake_pool.key().as_ref()
+        ],
+        bump,
+        seeds::program = staking_program.key(),
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Vault of the stake pool
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub stake_vault: UncheckedAccount<'info>,
+
+    /// Fee account of the staking program
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub fee_account: UncheckedAccount<'info>,
+
+    /// The staking program
+    /// CHECK: the distributor's staking program
+    #[account(
+        executable,
+        address = distributor.staking_program @ ErrorCode::InvalidStakingProgram
+    )]
+    pub staking_program: UncheckedAccount<'info>,
+}
+
+/// Stakes idle locked tokens of a claim from the vault into the claim's own stake account, or
+/// unstakes them back into the vault if unstake is set. The claim's stake authority PDA owns
+/// the stake account, so every claim's stake stays apart, and the tokens are withdrawn through
+/// claim_locked once vested and unstaked. The distributor never claims the stake account's
+/// staking rewards, they are forfeited.
+/// The staking program never gets the distributor's signature, only the stake authority's.
+/// 1. Stakes amount from the vault through the stake authority, see [stake_from_vault], or
+///    unstakes it back into the vault, signed by the stake authority
+/// 2. Updates claim_status.locked_amount_staked and total_locked_staked
+///
+/// CHECK:
+///     1. The distributor has a staking program, and has not been clawed back when staking
+///     2. The distributor is not paused when staking
+///     3. The authority is the claimant, or the admin when unstaking
+///     4. The claim has not been revoked when staking
+///     5. The amount is greater than 0 and at most the claim's idle locked amount when staking,
+///        or its staked locked amount when unstaking
+///     6. The stake account is the stake authority's in the distributor's stake pool
+///     7. The vault balance changed by exactly the amount
+///     8. The stake pool vault received exactly the amount when staking, the stake pool charges
+///        no fee
+///
+/// [stake_from_vault]: crate::utils::stake_from_vault
+#[allow(clippy::result_large_err)]
+pub fn handle_stake_locked(ctx: Context<StakeLocked>, amount: u64, unstake: bool) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+    let claim_status = &ctx.accounts.claim_status;
+    let authority = ctx.accounts.authority.key();
+
+    require!(
+        distributor.staking_program != Pubkey::default(),
+        ErrorCode::StakingDisabled
+    );
+    require!(unstake || !distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(unstake || !distributor.paused, ErrorCode::DistributorPaused);
+    require!(
+        authority == claim_status.claimant || (unstake && authority == distributor.admin),
+        ErrorCode::Unauthorized
+    );
+    require!(
+        unstake || !claim_status.is_revoked(),
+        ErrorCode::ClaimRevoked
+    );
+    require!(amount > 0, ErrorCode::InsufficientLockedTokens);
+    match unstake {
+        true => require!(
+            amount <= claim_status.locked_amount_staked,
+            ErrorCode::InsufficientStakedTokens
+        ),
+        false => require!(
+            amount <= claim_status.locked_amount_idle(),
+            ErrorCode::InsufficientLockedTokens
+        ),
+    }
+
+    let claim_status_key = claim_status.key();
+    let stake_authority_seeds = [
+        b"StakeAuthority".as_ref(),
+        claim_status_key.as_ref(),
+        &[*ctx.bumps.get("stake_authority").unwrap()],
+    ];
+    if unstake {
+        let vault_amount_before = ctx.accounts.token_vault.amount;
+        unstake_tokens(
+            &ctx.accounts.staking_program,
+            &ctx.accounts.central_state,
+            &ctx.accounts.stake_account,
+            &ctx.accounts.stake_pool,
+            &ctx.accounts.stake_authority,
+            &ctx.accounts.token_vault.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.stake_vault,
+            amount,
+            &[&stake_authority_seeds[..]],
+        )?;
+
+        // The staking program has to move exactly the amount, the claim's accounting relies on it
+        ctx.accounts.token_vault.reload()?;
+        require!(
+            ctx.accounts
+                .token_vault
+                .amount
+                .checked_sub(vault_amount_before)
+                == Some(amount),
+            ErrorCode::InvalidStakeTransfer
+        );
+    } else {
+        stake_from_vault(
+            &ctx.accounts.distributor,
+            &mut ctx.accounts.token_vault,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.stake_authority,
+            &[&stake_authority_seeds[..]],
+            &ctx.accounts.staking_program,
+            &ctx.accounts.central_state,
+            &ctx.accounts.stake_account,
+            &ctx.accounts.stake_pool,
+            &ctx.accounts.stake_vault,
+            &ctx.accounts.fee_account,
+            amount,
+        )?;
+    }
+
+    let claim_status = &mut ctx.accounts.claim_status;
+    let distributor = &mut ctx.accounts.distributor;
+    if unstake {
+        claim_status.locked_amount_staked = claim_status
+            .locked_amount_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        distributor.total_locked_staked = distributor
+            .total_locked_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+    } else {
+        claim_status.locked_amount_staked = claim_status
+            .locked_amount_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        distributor.total_locked_staked = distributor
+            .total_locked_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+    }
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "{} {} locked tokens, {} of {} staked",
+        if unstake { "Unstaked" } else { "Staked" },
+        amount,
+        claim_status.locked_amount_staked,
+        claim_status.locked_amount
+    );
+    if unstake {
+        emit!(UnstakedEvent {
+            claimant: claim_status.claimant,
+            stake_account: ctx.accounts.stake_account.key(),
+            amount,
+            distributor: distributor.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    } else {
+        emit!(StakedEvent {
+            claimant: claim_status.claimant,
+            stake_account: ctx.accounts.stake_account.key(),
+            amount,
+            distributor: distributor.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, events::ClaimTransferredEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::transfer_claim] accounts.
+#[derive(Accounts)]
+pub struct TransferClaim<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA, derived from the leaf claimant even if the claim was transferred
+    #[account(
+        mut,
+        has_one = claimant @ ErrorCode::OwnerMismatch,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claim_status.leaf_claimant.to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Current claimant
+    pub claimant: Signer<'info>,
+
+    /// New claimant the claim is transferred to.
+    /// CHECK: any wallet, only its key is stored
+    pub new_claimant: UncheckedAccount<'info>,
+}
+
+/// Transfers the rights to a claim, i.e. withdrawing its locked tokens and the rent refund,
+/// to a new claimant.
+/// CHECK:
+///     1. The current claimant signed
+///     2. The new claimant differs from the current claimant
+#[allow(clippy::result_large_err)]
+pub fn handle_transfer_claim(ctx: Context<TransferClaim>) -> Result<()> {
+    set_claimant(
+        &mut ctx.accounts.claim_status,
+        ctx.accounts.distributor.key(),
+        ctx.accounts.new_claimant.key(),
+        false,
+    )
+}
+
+/// Sets the claimant of a claim status, shared with [merkle_distributor::recover_claim].
+#[allow(clippy::result_large_err)]
+pub fn set_claimant(
+    claim_status: &mut ClaimStatus,
+    distributor: Pubkey,
+    new_claimant: Pubkey,
+    recovered: bool,
+) -> Result<()> {
+    require!(
+        claim_status.claimant != new_claimant && new_claimant != Pubkey::default(),
+        ErrorCode::SameClaimant
+    );
+
+    let old_claimant = claim_status.claimant;
+    claim_status.claimant = new_claimant;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Transferred claim of leaf {} from {} to {}",
+        claim_status.leaf_claimant,
+        old_claimant,
+        new_claimant
+    );
+    emit!(ClaimTransferredEvent {
+        distributor,
+        leaf_claimant: claim_status.leaf_claimant,
+        old_claimant,
+        new_claimant,
+        recovered,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_bitmap::MAX_BITMAP_NODES, events::RootUpdatedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::update_root] accounts.
+#[derive(Accounts)]
+pub struct UpdateRoot<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Replaces the merkle root of a live [MerkleDistributor], e.g. to add late recipients.
+/// Existing claim status accounts keep their recorded amounts and schedules, so claims
+/// made under the old root keep working.
+/// A new root may only add leaves or change the leaves of claimants who have not claimed yet,
+/// a changed leaf of a claimant who already claimed is never paid out. The program can't see
+/// the leaves, so the CLI checks this off-chain and only changes existing leaves when asked to.
+/// Bitmap tracked claims are recorded under their leaf index, so every existing leaf has to
+/// keep its index and new leaves take the next free ones.
+/// Increasing max_total_claim rejects new claims until the increase is funded through
+/// fund_distributor, existing claims keep withdrawing their locked tokens.
+/// CHECK:
+///     1. Neither the distributor nor its unclaimed allocation has been clawed back
+///     2. The distributor has no extra mints, whose totals the new root can't raise
+///     3. The new max_total_claim and max_num_nodes are at least the current ones
+///     4. Bitmap tracked distributors keep at most [MAX_BITMAP_NODES] nodes
+#[allow(clippy::result_large_err)]
+pub fn handle_update_root(
+    ctx: Context<UpdateRoot>,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(
+        !distributor.clawed_back && !distributor.unclaimed_clawed_back,
+        ErrorCode::ClaimExpired
+    );
+    require!(
+        distributor.num_extra_mints == 0,
+        ErrorCode::ExtraMintsUnsupported
+    );
+    require!(
+        max_total_claim >= distributor.max_total_claim
+            && max_num_nodes >= distributor.max_num_nodes,
+        ErrorCode::DistributorShrinks
+    );
+    require!(
+        !distributor.claim_bitmap || max_num_nodes <= MAX_BITMAP_NODES,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    // The vault has to be funded again to cover the increase before new claims are accepted
+    if max_total_claim > distributor.max_total_claim {
+        distributor.funded = false;
+    }
+
+    distributor.root = root;
+    distributor.max_total_claim = max_total_claim;
+    distributor.max_num_nodes = max_num_nodes;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "updated root with max_total_claim={}, max_nodes: {}",
+        max_total_claim,
+        max_num_nodes
+    );
+    emit!(RootUpdatedEvent {
+        distributor: distributor.key(),
+        root,
+        max_total_claim,
+        max_num_nodes,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,14 +1,23 @@
-use crate::error::ErrorCode;
-use anchor_lang::{prelude::*, solana_program::pubkey::Pubkey};
-use solana_program::system_instruction;
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, Transfer},
+    Accounts, Key, Result,
+};
 
-/// Accounts for [merkle_distributor::handle_new_distributor].
+use crate::{
+    error::ErrorCode,
+    state::{events::CustodyWithdrawnEvent, sol_custody::SolCustody},
+};
+
+/// [merkle_distributor::withdraw_custody_sol] accounts.
+/// The custody vault, owner and system program keep their order from before the [SolCustody]
+/// existed, it is appended after them.
 #[derive(Accounts)]
-#[instruction(amount_lamports: u64)]
 pub struct WithdrawCustodySol<'info> {
-    /// [SolCustody].\
-    /// CHECK: The sol_custody doesn't even need to exist, it's just a bucket for SOL.
-    #[account(        
+    /// Custody vault PDA holding the lamports.
+    /// CHECK: The custody vault doesn't even need to exist, it's just a bucket for SOL.
+    #[account(
         mut,
         seeds = [
             b"SolCustody".as_ref(),
@@ -16,48 +25,105 @@ pub struct WithdrawCustodySol<'info> {
         ],
         bump,
     )]
-    pub sol_custody: AccountInfo<'info>,
+    pub custody_vault: UncheckedAccount<'info>,
 
     /// The owner of the sol custody.
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// The [System] program.
     pub system_program: Program<'info, System>,
+
+    /// The [SolCustody] of the owner, initialized for vaults funded before it existed.
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"SolCustodyState".as_ref(),
+            owner.key().to_bytes().as_ref(),
+        ],
+        bump,
+        space = SolCustody::LEN,
+        payer = owner
+    )]
+    pub sol_custody: Account<'info, SolCustody>,
 }
 
+/// Withdraws SOL from the owner's custody by:
+/// 1. Transferring amount_lamports from the custody vault to the owner
+/// 2. Incrementing total_withdrawn by amount_lamports
+///
+/// Lamports sent to the vault directly count as deposited, so everything it holds can be withdrawn.
+///
+/// CHECK:
+///     1. The custody is not locked
+///     2. amount_lamports is at most the custody balance
+///     3. The custody vault is emptied or keeps the rent-exempt minimum after the transfer
 #[allow(clippy::result_large_err)]
 pub fn handle_withdraw_custody_sol(
     ctx: Context<WithdrawCustodySol>,
     amount_lamports: u64,
 ) -> Result<()> {
-    let sol_custody_lamports = ctx.accounts.sol_custody.to_account_info().lamports();
+    let vault_lamports = ctx.accounts.custody_vault.lamports();
+    let sol_custody = &mut ctx.accounts.sol_custody;
+    sol_custody.init_if_new(
+        ctx.accounts.owner.key(),
+        *ctx.bumps.get("sol_custody").unwrap(),
+        *ctx.bumps.get("custody_vault").unwrap(),
+    );
+    sol_custody.sync(vault_lamports)?;
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+    require!(!sol_custody.is_locked(curr_ts), ErrorCode::CustodyLocked);
     require_gte!(
-        sol_custody_lamports,
+        sol_custody.balance(),
         amount_lamports,
         ErrorCode::InsufficientFunds
     );
+    let vault_lamports_after = vault_lamports
+        .checked_sub(amount_lamports)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+    require!(
+        vault_lamports_after == 0 || Rent::get()?.is_exempt(vault_lamports_after, 0),
+        ErrorCode::SolTransferBelowRentExempt
+    );
 
-    // Create the transfer instruction
-    let transfer_instruction = system_instruction::transfer(ctx.accounts.sol_custody.key, ctx.accounts.owner.key, amount_lamports);
-
-    // Create the signer seeds
-    let bump_seed = [*ctx.bumps.get("sol_custody").unwrap()];
+    let owner = ctx.accounts.owner.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
-        "SolCustody".as_bytes(),
-        ctx.accounts.owner.key.as_ref(),
-        &bump_seed.as_ref(),
+        b"SolCustody".as_ref(),
+        owner.as_ref(),
+        &[sol_custody.vault_bump],
     ]];
-
-    // Invoke the transfer instruction    
-    anchor_lang::solana_program::program::invoke_signed(
-        &transfer_instruction,
-        &[
-            ctx.accounts.sol_custody.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
+    system_program::transfer(
+        CpiContext::new_with_signer(
             ctx.accounts.system_program.to_account_info(),
-        ],
-        &signer_seeds
+            Transfer {
+                from: ctx.accounts.custody_vault.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount_lamports,
     )?;
 
+    let sol_custody = &mut ctx.accounts.sol_custody;
+    sol_custody.total_withdrawn = sol_custody
+        .total_withdrawn
+        .checked_add(amount_lamports)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Withdrew {} lamports from custody, balance {}",
+        amount_lamports,
+        sol_custody.balance()
+    );
+
+    emit!(CustodyWithdrawnEvent {
+        owner,
+        amount: amount_lamports,
+        balance: sol_custody.balance(),
+        timestamp: curr_ts,
+    });
+
     Ok(())
 }
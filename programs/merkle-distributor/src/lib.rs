@@ -14,10 +14,12 @@
 use anchor_lang::prelude::*;
 use instructions::*;
 use solana_security_txt::security_txt;
+use state::{extra_mint::ExtraLeafAmount, vesting_mode::VestingMode};
 
 pub mod error;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 security_txt! {
     // Required fields
@@ -45,6 +47,14 @@ pub mod merkle_distributor {
         max_num_nodes: u64,
         start_vesting_ts: i64,
         end_vesting_ts: i64,
+        cliff_vesting_ts: i64,
+        cliff_percentage: u8,
+        vesting_mode: VestingMode,
+        clawback_start_ts: i64,
+        claim_bitmap: bool,
+        claim_end_ts: i64,
+        revocable: bool,
+        recoverable: bool,
     ) -> Result<()> {
         handle_new_distributor(
             ctx,
@@ -54,32 +64,309 @@ pub mod merkle_distributor {
             max_num_nodes,
             start_vesting_ts,
             end_vesting_ts,
+            cliff_vesting_ts,
+            cliff_percentage,
+            vesting_mode,
+            clawback_start_ts,
+            claim_bitmap,
+            claim_end_ts,
+            revocable,
+            recoverable,
         )
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn new_claim(
-        ctx: Context<NewClaim>,
+    pub fn fund_distributor<'info>(
+        ctx: Context<'_, '_, '_, 'info, FundDistributor<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_fund_distributor(ctx, amount)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_sol_distributor(
+        ctx: Context<NewSolDistributor>,
+        version: u64,
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+        start_vesting_ts: i64,
+        end_vesting_ts: i64,
+        cliff_vesting_ts: i64,
+        cliff_percentage: u8,
+        vesting_mode: VestingMode,
+        clawback_start_ts: i64,
+        claim_end_ts: i64,
+        recoverable: bool,
+    ) -> Result<()> {
+        handle_new_sol_distributor(
+            ctx,
+            version,
+            root,
+            max_total_claim,
+            max_num_nodes,
+            start_vesting_ts,
+            end_vesting_ts,
+            cliff_vesting_ts,
+            cliff_percentage,
+            vesting_mode,
+            clawback_start_ts,
+            claim_end_ts,
+            recoverable,
+        )
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn fund_sol_distributor(ctx: Context<FundSolDistributor>, amount: u64) -> Result<()> {
+        handle_fund_sol_distributor(ctx, amount)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaim<'info>>,
         amount_unlocked: u64,
         amount_locked: u64,
+        start_vesting_ts: Option<i64>,
+        end_vesting_ts: Option<i64>,
         proof: Vec<[u8; 32]>,
+        extra_amounts: Vec<ExtraLeafAmount>,
     ) -> Result<()> {
-        handle_new_claim(ctx, amount_unlocked, amount_locked, proof)
+        handle_new_claim(
+            ctx,
+            amount_unlocked,
+            amount_locked,
+            start_vesting_ts,
+            end_vesting_ts,
+            proof,
+            extra_amounts,
+        )
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
+    pub fn new_claim_and_stake<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaimAndStake<'info>>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        start_vesting_ts: Option<i64>,
+        end_vesting_ts: Option<i64>,
+        proof: Vec<[u8; 32]>,
+        extra_amounts: Vec<ExtraLeafAmount>,
+    ) -> Result<()> {
+        handle_new_claim_and_stake(
+            ctx,
+            amount_unlocked,
+            amount_locked,
+            start_vesting_ts,
+            end_vesting_ts,
+            proof,
+            extra_amounts,
+        )
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn init_claim_bitmap(ctx: Context<InitClaimBitmap>, page: u32) -> Result<()> {
+        handle_init_claim_bitmap(ctx, page)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_bitmap<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaimBitmap<'info>>,
+        index: u64,
+        amount_unlocked: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_bitmap(ctx, index, amount_unlocked, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_all<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAll<'info>>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        start_vesting_ts: Option<i64>,
+        end_vesting_ts: Option<i64>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_all(
+            ctx,
+            amount_unlocked,
+            amount_locked,
+            start_vesting_ts,
+            end_vesting_ts,
+            proof,
+        )
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked<'info>(ctx: Context<'_, '_, '_, 'info, ClaimLocked<'info>>) -> Result<()> {
         handle_claim_locked(ctx)
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn clawback(ctx: Context<Clawback>) -> Result<()> {
-        handle_clawback(ctx)
+    pub fn claim_locked_and_stake<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimLockedAndStake<'info>>,
+    ) -> Result<()> {
+        handle_claim_locked_and_stake(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn stake_locked(ctx: Context<StakeLocked>, amount: u64) -> Result<()> {
+        handle_stake_locked(ctx, amount, false)
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn set_admin(ctx: Context<SetAdmin>) -> Result<()> {
-        handle_set_admin(ctx)
+    pub fn unstake_locked(ctx: Context<StakeLocked>, amount: u64) -> Result<()> {
+        handle_stake_locked(ctx, amount, true)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_sol(
+        ctx: Context<NewClaimSol>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        start_vesting_ts: Option<i64>,
+        end_vesting_ts: Option<i64>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_sol(
+            ctx,
+            amount_unlocked,
+            amount_locked,
+            start_vesting_ts,
+            end_vesting_ts,
+            proof,
+        )
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_sol(ctx: Context<ClaimLockedSol>) -> Result<()> {
+        handle_claim_locked_sol(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
+        handle_close_claim_status(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn clawback<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>) -> Result<()> {
+        handle_clawback(ctx, false)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn clawback_unclaimed<'info>(
+        ctx: Context<'_, '_, '_, 'info, Clawback<'info>>,
+    ) -> Result<()> {
+        handle_clawback(ctx, true)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn clawback_sol(ctx: Context<ClawbackSol>) -> Result<()> {
+        handle_clawback_sol(ctx, false)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn clawback_unclaimed_sol(ctx: Context<ClawbackSol>) -> Result<()> {
+        handle_clawback_sol(ctx, true)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn revoke_claim<'info>(ctx: Context<'_, '_, '_, 'info, RevokeClaim<'info>>) -> Result<()> {
+        handle_revoke_claim(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn transfer_claim(ctx: Context<TransferClaim>) -> Result<()> {
+        handle_transfer_claim(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn recover_claim(ctx: Context<RecoverClaim>) -> Result<()> {
+        handle_recover_claim(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        handle_propose_admin(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        handle_accept_admin(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        handle_cancel_admin_proposal(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result<()> {
+        handle_set_clawback_receiver(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn set_staking_program(ctx: Context<SetStakingProgram>) -> Result<()> {
+        handle_set_staking_program(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn update_root(
+        ctx: Context<UpdateRoot>,
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    ) -> Result<()> {
+        handle_update_root(ctx, root, max_total_claim, max_num_nodes)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn add_extra_mint(
+        ctx: Context<AddExtraMint>,
+        root: [u8; 32],
+        max_total_claim: u64,
+    ) -> Result<()> {
+        handle_add_extra_mint(ctx, root, max_total_claim)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn migrate_distributor(
+        ctx: Context<MigrateDistributor>,
+        claim_end_ts: Option<i64>,
+    ) -> Result<()> {
+        handle_migrate_distributor(ctx, claim_end_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn migrate_claim_status(ctx: Context<MigrateClaimStatus>) -> Result<()> {
+        handle_migrate_claim_status(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        handle_set_paused(ctx, true)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        handle_set_paused(ctx, false)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn set_permissionless_claims(
+        ctx: Context<SetPermissionlessClaims>,
+        permissionless_claims: bool,
+    ) -> Result<()> {
+        handle_set_permissionless_claims(ctx, permissionless_claims)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn deposit_custody_sol(
+        ctx: Context<DepositCustodySol>,
+        amount_lamports: u64,
+        lock_until_ts: Option<i64>,
+    ) -> Result<()> {
+        handle_deposit_custody_sol(ctx, amount_lamports, lock_until_ts)
     }
 
     #[allow(clippy::result_large_err)]
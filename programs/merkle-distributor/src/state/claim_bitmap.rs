@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Size of the bitmap of a single [ClaimBitmap] page, in bytes.
+pub const CLAIM_BITMAP_BYTES: usize = 1024;
+
+/// Number of leaves tracked by a single [ClaimBitmap] page.
+pub const CLAIMS_PER_BITMAP: u64 = CLAIM_BITMAP_BYTES as u64 * 8;
+
+/// Maximum number of nodes of a bitmap tracked distributor, so the page numbers of its leaves
+/// fit in a u32 and the last one, u32::MAX, is never needed.
+pub const MAX_BITMAP_NODES: u64 = u32::MAX as u64 * CLAIMS_PER_BITMAP;
+
+/// Records which leaves of a bitmap tracked [crate::state::merkle_distributor::MerkleDistributor]
+/// have been claimed, one bit per leaf index.
+#[account]
+pub struct ClaimBitmap {
+    /// The distributor this page belongs to
+    pub distributor: Pubkey,
+    /// Page number, covering leaf indices page * CLAIMS_PER_BITMAP onwards
+    pub page: u32,
+    /// One bit per leaf, set once claimed
+    pub bits: [u8; CLAIM_BITMAP_BYTES],
+}
+
+impl ClaimBitmap {
+    pub const LEN: usize = 8 + std::mem::size_of::<ClaimBitmap>();
+
+    /// Page holding the bit of a leaf index, indices past [MAX_BITMAP_NODES] all fall on page
+    /// u32::MAX, which no distributor needs
+    pub fn page_of(index: u64) -> u32 {
+        u32::try_from(index / CLAIMS_PER_BITMAP).unwrap_or(u32::MAX)
+    }
+
+    /// Number of pages needed to track a distributor's nodes, capped at u32::MAX
+    pub fn num_pages(max_num_nodes: u64) -> u32 {
+        u32::try_from(max_num_nodes.saturating_add(CLAIMS_PER_BITMAP - 1) / CLAIMS_PER_BITMAP)
+            .unwrap_or(u32::MAX)
+    }
+
+    fn position(&self, index: u64) -> Option<(usize, u8)> {
+        if Self::page_of(index) != self.page {
+            return None;
+        }
+        let bit = (index % CLAIMS_PER_BITMAP) as usize;
+        Some((bit / 8, 1 << (bit % 8)))
+    }
+
+    /// Whether the leaf index is claimed, false for indices of other pages
+    pub fn is_claimed(&self, index: u64) -> bool {
+        matches!(self.position(index), Some((byte, mask)) if self.bits[byte] & mask != 0)
+    }
+
+    /// Marks the leaf index as claimed
+    #[allow(clippy::result_large_err)]
+    pub fn set_claimed(&mut self, index: u64) -> Result<()> {
+        let (byte, mask) = self.position(index).ok_or(ErrorCode::InvalidClaimBitmap)?;
+        require!(self.bits[byte] & mask == 0, ErrorCode::AlreadyClaimed);
+        self.bits[byte] |= mask;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim_bitmap(page: u32) -> ClaimBitmap {
+        ClaimBitmap {
+            distributor: Pubkey::new_unique(),
+            page,
+            bits: [0; CLAIM_BITMAP_BYTES],
+        }
+    }
+
+    #[test]
+    fn test_set_claimed() {
+        let mut bitmap = claim_bitmap(1);
+        let index = CLAIMS_PER_BITMAP + 9;
+
+        assert!(!bitmap.is_claimed(index));
+        bitmap.set_claimed(index).unwrap();
+        assert!(bitmap.is_claimed(index));
+        assert_eq!(bitmap.bits[1], 0b10);
+        assert!(!bitmap.is_claimed(index + 1));
+
+        // can't claim twice
+        assert_eq!(
+            bitmap.set_claimed(index),
+            Err(ErrorCode::AlreadyClaimed.into())
+        );
+    }
+
+    #[test]
+    fn test_index_of_other_page() {
+        let mut bitmap = claim_bitmap(0);
+
+        assert!(!bitmap.is_claimed(CLAIMS_PER_BITMAP));
+        assert_eq!(
+            bitmap.set_claimed(CLAIMS_PER_BITMAP),
+            Err(ErrorCode::InvalidClaimBitmap.into())
+        );
+        bitmap.set_claimed(CLAIMS_PER_BITMAP - 1).unwrap();
+        assert!(bitmap.is_claimed(CLAIMS_PER_BITMAP - 1));
+    }
+
+    #[test]
+    fn test_num_pages() {
+        assert_eq!(ClaimBitmap::num_pages(0), 0);
+        assert_eq!(ClaimBitmap::num_pages(1), 1);
+        assert_eq!(ClaimBitmap::num_pages(CLAIMS_PER_BITMAP), 1);
+        assert_eq!(ClaimBitmap::num_pages(CLAIMS_PER_BITMAP + 1), 2);
+        assert_eq!(ClaimBitmap::page_of(CLAIMS_PER_BITMAP), 1);
+
+        // the largest bitmap distributor never needs page u32::MAX, which indices past it fall on
+        assert_eq!(ClaimBitmap::num_pages(MAX_BITMAP_NODES), u32::MAX);
+        assert_eq!(ClaimBitmap::page_of(MAX_BITMAP_NODES - 1), u32::MAX - 1);
+        assert_eq!(ClaimBitmap::page_of(MAX_BITMAP_NODES), u32::MAX);
+        assert_eq!(ClaimBitmap::page_of(u64::MAX), u32::MAX);
+        assert_eq!(ClaimBitmap::num_pages(u64::MAX), u32::MAX);
+    }
+}
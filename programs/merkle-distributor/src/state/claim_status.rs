@@ -1,6 +1,20 @@
 use anchor_lang::prelude::*;
 
-use crate::error::ErrorCode::ArithmeticError;
+use crate::{
+    error::ErrorCode::ArithmeticError,
+    state::{
+        extra_mint::{ExtraClaimAmount, MAX_EXTRA_MINTS},
+        merkle_distributor::MerkleDistributor,
+        vesting_mode::VestingMode,
+    },
+};
+
+/// Layout of claim statuses created before the layout version was added, their accounts are
+/// shorter than [ClaimStatus::LEN] until migrated.
+pub const LEGACY_LAYOUT_VERSION: u8 = 0;
+
+/// Layout version of new and migrated claim statuses.
+pub const LAYOUT_VERSION: u8 = 1;
 
 /// Holds whether or not a claimant has claimed tokens.
 #[account]
@@ -14,25 +28,105 @@ pub struct ClaimStatus {
     pub locked_amount_withdrawn: u64,
     /// Unlocked amount
     pub unlocked_amount: u64,
+    /// Lockup time start (Unix Timestamp)
+    pub start_ts: i64,
+    /// Lockup time end (Unix Timestamp)
+    pub end_ts: i64,
+    /// Revocation time (Unix Timestamp), vesting stops at it, 0 if not revoked
+    pub revoked_ts: i64,
+    /// Locked amount returned to the clawback receiver on revocation
+    pub locked_amount_revoked: u64,
+    /// Claimant of the merkle leaf, the claim status PDA is derived from it.
+    /// Differs from the claimant once the claim has been transferred.
+    pub leaf_claimant: Pubkey,
+    /// Amounts of the distributor's extra mints, in the order of the distributor's extra mints
+    pub extra_amounts: [ExtraClaimAmount; MAX_EXTRA_MINTS],
+    /// Locked amount staked through the distributor, it has to be unstaked before it is withdrawn
+    pub locked_amount_staked: u64,
+    /// Layout of the account, new fields are carved out of the reserved bytes and bump it
+    pub layout_version: u8,
+    /// Zeroed bytes reserved for future fields, so adding them doesn't resize the account
+    pub reserved: [u64; 8],
 }
 
 impl ClaimStatus {
     pub const LEN: usize = 8 + std::mem::size_of::<ClaimStatus>();
 
+    /// Deserializes a claim status account of any layout. Legacy claims vest on the
+    /// distributor's schedule and were never transferred, the other fields they didn't have
+    /// are zero and their layout_version is [LEGACY_LAYOUT_VERSION].
+    #[allow(clippy::result_large_err)]
+    pub fn try_deserialize_any_layout(
+        data: &[u8],
+        distributor: &MerkleDistributor,
+    ) -> Result<Self> {
+        let mut padded = data.to_vec();
+        if padded.len() < Self::LEN {
+            padded.resize(Self::LEN, 0);
+        }
+        let mut claim_status = Self::try_deserialize(&mut padded.as_slice())?;
+        if claim_status.needs_migration() {
+            claim_status.start_ts = distributor.start_ts;
+            claim_status.end_ts = distributor.end_ts;
+            claim_status.leaf_claimant = claim_status.claimant;
+        }
+
+        Ok(claim_status)
+    }
+
+    /// Whether the account has to be migrated to the current layout with migrate_claim_status.
+    pub fn needs_migration(&self) -> bool {
+        self.layout_version < LAYOUT_VERSION
+    }
+
     /// Returns amount withdrawable, factoring in unlocked tokens and previous withdraws.
     /// payout is difference between the amount unlocked and the amount withdrawn
+    /// Revoked claims stop unlocking at the revocation time.
+    /// Staked locked tokens stay staked until unstaked, only the idle ones are withdrawable.
     #[allow(clippy::result_large_err)]
-    pub fn amount_withdrawable(&self, curr_ts: i64, start_ts: i64, end_ts: i64) -> Result<u64> {
+    pub fn amount_withdrawable(
+        &self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        cliff_ts: i64,
+        cliff_percentage: u8,
+        vesting_mode: &VestingMode,
+    ) -> Result<u64> {
+        let curr_ts = match self.is_revoked() {
+            true => curr_ts.min(self.revoked_ts),
+            false => curr_ts,
+        };
         let amount = self
-            .unlocked_amount(curr_ts, start_ts, end_ts)?
+            .unlocked_amount(
+                curr_ts,
+                start_ts,
+                end_ts,
+                cliff_ts,
+                cliff_percentage,
+                vesting_mode,
+            )?
             .checked_sub(self.locked_amount_withdrawn)
             .ok_or(ArithmeticError)?;
 
-        Ok(amount)
+        Ok(amount.min(self.locked_amount_idle()))
+    }
+
+    /// Locked amount still held by the distributor's vault, neither withdrawn, revoked nor staked
+    pub fn locked_amount_idle(&self) -> u64 {
+        self.locked_amount
+            .saturating_sub(self.locked_amount_withdrawn)
+            .saturating_sub(self.locked_amount_revoked)
+            .saturating_sub(self.locked_amount_staked)
     }
 
     /// Total amount unlocked
-    /// Equal to (time_into_unlock / total_unlock_time) * locked_amount  
+    /// Nothing is unlocked before the cliff. At the cliff, cliff_percentage of the locked amount
+    /// is unlocked at once and the remainder vests linearly until the end timestamp.
+    /// Equal to cliff_amount + (time_into_unlock / total_unlock_time) * (locked_amount - cliff_amount)
+    /// where the linear unlock starts at the later of start_ts and cliff_ts.
+    /// In periodic mode, time_into_unlock is rounded down to a whole number of periods.
+    /// In milestone mode, the percentage of the last milestone reached is unlocked instead.
     /// Multiplication safety:
     ///    The maximum possible product is (2^64 -1) * (2^64 -1) = 2^128 - 2^65 + 1
     ///    which is less than 2^128 - 1 (the maximum value of a u128), meaning that
@@ -47,31 +141,145 @@ impl ClaimStatus {
     ///         b < 2^64 -1.
     ///     Since b is a i64, this is always true, so no truncation can occur
     #[allow(clippy::result_large_err)]
-    pub fn unlocked_amount(&self, curr_ts: i64, start_ts: i64, end_ts: i64) -> Result<u64> {
-        if curr_ts >= start_ts {
-            if curr_ts >= end_ts {
-                Ok(self.locked_amount)
-            } else {
-                let time_into_unlock = curr_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
-                let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
-
-                let amount = ((time_into_unlock as u128)
-                    .checked_mul(self.locked_amount as u128)
-                    .ok_or(ArithmeticError)?)
-                .checked_div(total_unlock_time as u128)
-                .ok_or(ArithmeticError)? as u64;
-
-                Ok(amount)
+    pub fn unlocked_amount(
+        &self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        cliff_ts: i64,
+        cliff_percentage: u8,
+        vesting_mode: &VestingMode,
+    ) -> Result<u64> {
+        if let VestingMode::Milestones { .. } = vesting_mode {
+            return self.milestone_unlocked_amount(curr_ts, start_ts, vesting_mode);
+        }
+        if curr_ts < start_ts || curr_ts < cliff_ts {
+            return Ok(0);
+        }
+        if curr_ts >= end_ts {
+            return Ok(self.locked_amount);
+        }
+
+        let cliff_amount = self.percentage_amount(cliff_percentage)?;
+        let vesting_amount = self
+            .locked_amount
+            .checked_sub(cliff_amount)
+            .ok_or(ArithmeticError)?;
+
+        let vesting_start_ts = start_ts.max(cliff_ts);
+        let time_into_unlock = curr_ts
+            .checked_sub(vesting_start_ts)
+            .ok_or(ArithmeticError)?;
+        let time_into_unlock = match vesting_mode {
+            VestingMode::Periodic { period } => time_into_unlock
+                .checked_sub(
+                    time_into_unlock
+                        .checked_rem(*period)
+                        .ok_or(ArithmeticError)?,
+                )
+                .ok_or(ArithmeticError)?,
+            _ => time_into_unlock,
+        };
+        let total_unlock_time = end_ts
+            .checked_sub(vesting_start_ts)
+            .ok_or(ArithmeticError)?;
+
+        let amount = ((time_into_unlock as u128)
+            .checked_mul(vesting_amount as u128)
+            .ok_or(ArithmeticError)?)
+        .checked_div(total_unlock_time as u128)
+        .ok_or(ArithmeticError)? as u64;
+
+        let amount = cliff_amount.checked_add(amount).ok_or(ArithmeticError)?;
+
+        Ok(amount)
+    }
+
+    /// Amount unlocked in milestone mode, nothing is unlocked before the first milestone
+    #[allow(clippy::result_large_err)]
+    fn milestone_unlocked_amount(
+        &self,
+        curr_ts: i64,
+        start_ts: i64,
+        vesting_mode: &VestingMode,
+    ) -> Result<u64> {
+        let mut percentage = 0;
+        for milestone in vesting_mode.milestones() {
+            let milestone_ts = start_ts
+                .checked_add(milestone.offset)
+                .ok_or(ArithmeticError)?;
+            if curr_ts < milestone_ts {
+                break;
             }
-        } else {
-            Ok(0)
+            percentage = milestone.percentage;
+        }
+
+        self.percentage_amount(percentage)
+    }
+
+    /// Percentage of the locked amount, used for the cliff and milestones.
+    /// Equal to locked_amount * percentage / 100, rounded down
+    #[allow(clippy::result_large_err)]
+    pub fn percentage_amount(&self, percentage: u8) -> Result<u64> {
+        let amount = (self.locked_amount as u128)
+            .checked_mul(percentage as u128)
+            .ok_or(ArithmeticError)?
+            .checked_div(100)
+            .ok_or(ArithmeticError)?;
+
+        u64::try_from(amount).map_err(|_| ArithmeticError.into())
+    }
+
+    /// Claim status vesting the locked amount of an extra mint on this claim's schedule,
+    /// for computing its withdrawable and unlocked amounts.
+    pub fn extra_claim_status(&self, extra_amount: &ExtraClaimAmount) -> ClaimStatus {
+        ClaimStatus {
+            claimant: self.claimant,
+            locked_amount: extra_amount.locked_amount,
+            locked_amount_withdrawn: extra_amount.locked_amount_withdrawn,
+            unlocked_amount: extra_amount.unlocked_amount,
+            start_ts: self.start_ts,
+            end_ts: self.end_ts,
+            revoked_ts: self.revoked_ts,
+            leaf_claimant: self.leaf_claimant,
+            ..Default::default()
         }
     }
+
+    /// Whether all locked tokens have been withdrawn or revoked, the unlocked ones are sent on claim
+    pub fn is_fully_withdrawn(&self) -> bool {
+        self.locked_amount_withdrawn
+            .saturating_add(self.locked_amount_revoked)
+            >= self.locked_amount
+            && self.extra_amounts.iter().all(|extra_amount| {
+                extra_amount.locked_amount_withdrawn >= extra_amount.locked_amount
+            })
+    }
+
+    /// Whether the claim holds any amount of an extra mint
+    pub fn has_extra_amounts(&self) -> bool {
+        self.extra_amounts
+            .iter()
+            .any(|extra_amount| *extra_amount != ExtraClaimAmount::default())
+    }
+
+    /// Whether the admin revoked the unvested locked tokens
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_ts != 0
+    }
+
+    /// Whether the claim has been transferred away from the leaf claimant
+    pub fn is_transferred(&self) -> bool {
+        self.claimant != self.leaf_claimant
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use anchor_lang::Discriminator;
+
     use super::*;
+    use crate::state::vesting_mode::{Milestone, MAX_MILESTONES};
 
     #[test]
     fn test_normal_unlocking_scenario() {
@@ -80,12 +288,20 @@ mod tests {
             locked_amount: 100,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..Default::default()
         };
         let curr_ts = 50;
         let start_ts = 0;
         let end_ts = 100;
         assert_eq!(
-            claim_status.unlocked_amount(curr_ts, start_ts, end_ts),
+            claim_status.unlocked_amount(
+                curr_ts,
+                start_ts,
+                end_ts,
+                start_ts,
+                0,
+                &VestingMode::Linear
+            ),
             Ok(50)
         );
     }
@@ -97,15 +313,31 @@ mod tests {
             locked_amount: 100,
             locked_amount_withdrawn: 0,
             unlocked_amount: 0,
+            ..Default::default()
         };
         let start_ts = 0;
         let end_ts = 100;
 
-        assert_eq!(claim_status.unlocked_amount(0, start_ts, end_ts), Ok(0));
-        assert_eq!(claim_status.unlocked_amount(25, start_ts, end_ts), Ok(25));
-        assert_eq!(claim_status.unlocked_amount(50, start_ts, end_ts), Ok(50));
-        assert_eq!(claim_status.unlocked_amount(75, start_ts, end_ts), Ok(75));
-        assert_eq!(claim_status.unlocked_amount(100, start_ts, end_ts), Ok(100));
+        assert_eq!(
+            claim_status.unlocked_amount(0, start_ts, end_ts, start_ts, 0, &VestingMode::Linear),
+            Ok(0)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(25, start_ts, end_ts, start_ts, 0, &VestingMode::Linear),
+            Ok(25)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(50, start_ts, end_ts, start_ts, 0, &VestingMode::Linear),
+            Ok(50)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(75, start_ts, end_ts, start_ts, 0, &VestingMode::Linear),
+            Ok(75)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(100, start_ts, end_ts, start_ts, 0, &VestingMode::Linear),
+            Ok(100)
+        );
     }
 
     #[test]
@@ -121,6 +353,7 @@ mod tests {
             locked_amount,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..Default::default()
         };
 
         // Use large values for time_into_unlock and total_unlock_time, but ensure they are within i64 range
@@ -134,7 +367,7 @@ mod tests {
 
             // Perform the calculation using the function
             let calculated_amount = claim_status
-                .unlocked_amount(curr_ts, start_ts, end_ts)
+                .unlocked_amount(curr_ts, start_ts, end_ts, start_ts, 0, &VestingMode::Linear)
                 .unwrap();
 
             // Assert that the calculated amount matches the expected amount and is within u64 bounds
@@ -150,12 +383,20 @@ mod tests {
             locked_amount: 100,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..Default::default()
         };
         let curr_ts = 150;
         let start_ts = 0;
         let end_ts = 100;
         assert_eq!(
-            claim_status.unlocked_amount(curr_ts, start_ts, end_ts),
+            claim_status.unlocked_amount(
+                curr_ts,
+                start_ts,
+                end_ts,
+                start_ts,
+                0,
+                &VestingMode::Linear
+            ),
             Ok(100)
         );
     }
@@ -167,12 +408,20 @@ mod tests {
             locked_amount: 100,
             unlocked_amount: 0,
             locked_amount_withdrawn: 0,
+            ..Default::default()
         };
         let curr_ts = 50;
         let start_ts = 100;
         let end_ts = 100;
         assert_eq!(
-            claim_status.unlocked_amount(curr_ts, start_ts, end_ts),
+            claim_status.unlocked_amount(
+                curr_ts,
+                start_ts,
+                end_ts,
+                start_ts,
+                0,
+                &VestingMode::Linear
+            ),
             Ok(0)
         );
     }
@@ -186,7 +435,10 @@ mod tests {
         let start_ts = 100;
         let end_ts = 50;
 
-        assert_eq!(claim_status.unlocked_amount(75, start_ts, end_ts), Ok(0));
+        assert_eq!(
+            claim_status.unlocked_amount(75, start_ts, end_ts, start_ts, 0, &VestingMode::Linear),
+            Ok(0)
+        );
     }
 
     #[test]
@@ -206,12 +458,419 @@ mod tests {
                 locked_amount: 100,
                 unlocked_amount: 0,
                 locked_amount_withdrawn,
+                ..Default::default()
             };
 
             assert_eq!(
-                claim_status.amount_withdrawable(curr_ts, 0, 100),
+                claim_status.amount_withdrawable(curr_ts, 0, 100, 0, 0, &VestingMode::Linear),
+                Ok(expected)
+            );
+        }
+    }
+
+    /// The claim status layout before any fields were added to it.
+    #[derive(AnchorSerialize)]
+    struct LegacyClaimStatus {
+        claimant: Pubkey,
+        locked_amount: u64,
+        locked_amount_withdrawn: u64,
+        unlocked_amount: u64,
+    }
+
+    #[test]
+    fn test_deserialize_legacy_layout() {
+        let claimant = Pubkey::new_unique();
+        let legacy = LegacyClaimStatus {
+            claimant,
+            locked_amount: 100,
+            locked_amount_withdrawn: 40,
+            unlocked_amount: 10,
+        };
+        let mut data = ClaimStatus::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), 8 + std::mem::size_of::<LegacyClaimStatus>());
+
+        let distributor = MerkleDistributor {
+            start_ts: 100,
+            end_ts: 200,
+            ..Default::default()
+        };
+        assert!(ClaimStatus::try_deserialize(&mut data.as_slice()).is_err());
+        let claim_status = ClaimStatus::try_deserialize_any_layout(&data, &distributor).unwrap();
+        assert_eq!(claim_status.claimant, claimant);
+        assert_eq!(claim_status.locked_amount, 100);
+        assert_eq!(claim_status.locked_amount_withdrawn, 40);
+        assert_eq!(claim_status.unlocked_amount, 10);
+        assert_eq!((claim_status.start_ts, claim_status.end_ts), (100, 200));
+        assert_eq!(claim_status.leaf_claimant, claimant);
+        assert!(!claim_status.is_transferred());
+        assert!(!claim_status.is_revoked());
+        assert!(!claim_status.has_extra_amounts());
+        assert_eq!(claim_status.layout_version, LEGACY_LAYOUT_VERSION);
+        assert!(claim_status.needs_migration());
+        assert_eq!(
+            claim_status.amount_withdrawable(
+                150,
+                claim_status.start_ts,
+                claim_status.end_ts,
+                distributor.claim_cliff_ts(claim_status.start_ts, claim_status.end_ts),
+                0,
+                &VestingMode::Linear
+            ),
+            Ok(10)
+        );
+
+        // the current layout is read as is, without the distributor's schedule
+        let current = ClaimStatus {
+            claimant,
+            start_ts: 300,
+            end_ts: 400,
+            leaf_claimant: claimant,
+            layout_version: LAYOUT_VERSION,
+            ..Default::default()
+        };
+        let mut data = vec![];
+        current.try_serialize(&mut data).unwrap();
+        let claim_status = ClaimStatus::try_deserialize_any_layout(&data, &distributor).unwrap();
+        assert_eq!((claim_status.start_ts, claim_status.end_ts), (300, 400));
+        assert!(!claim_status.needs_migration());
+
+        // tombstones of closed claims are not claim statuses
+        assert!(ClaimStatus::try_deserialize_any_layout(&[], &distributor).is_err());
+    }
+
+    #[test]
+    fn test_revoked_claim_fully_withdrawn() {
+        let mut claim_status = ClaimStatus {
+            locked_amount: 100,
+            locked_amount_withdrawn: 40,
+            locked_amount_revoked: 60,
+            revoked_ts: 40,
+            ..Default::default()
+        };
+        assert!(!claim_status.has_extra_amounts());
+        assert!(claim_status.is_fully_withdrawn());
+
+        // revoke_claim rejects claims with extra amounts, they could never be fully withdrawn
+        claim_status.extra_amounts[1].locked_amount = 10;
+        assert!(claim_status.has_extra_amounts());
+        assert!(!claim_status.is_fully_withdrawn());
+    }
+
+    #[test]
+    fn test_nothing_unlocked_before_cliff() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+        let start_ts = 0;
+        let cliff_ts = 50;
+        let end_ts = 150;
+
+        for curr_ts in [0, 25, 49] {
+            assert_eq!(
+                claim_status.unlocked_amount(
+                    curr_ts,
+                    start_ts,
+                    end_ts,
+                    cliff_ts,
+                    20,
+                    &VestingMode::Linear
+                ),
+                Ok(0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cliff_unlocking_scenario() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+        let start_ts = 0;
+        let cliff_ts = 50;
+        let end_ts = 150;
+
+        // 20% unlocks at the cliff, the remaining 80 vest linearly over 100 seconds
+        assert_eq!(
+            claim_status.unlocked_amount(50, start_ts, end_ts, cliff_ts, 20, &VestingMode::Linear),
+            Ok(20)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(75, start_ts, end_ts, cliff_ts, 20, &VestingMode::Linear),
+            Ok(40)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(100, start_ts, end_ts, cliff_ts, 20, &VestingMode::Linear),
+            Ok(60)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(150, start_ts, end_ts, cliff_ts, 20, &VestingMode::Linear),
+            Ok(100)
+        );
+        assert_eq!(
+            claim_status.amount_withdrawable(
+                100,
+                start_ts,
+                end_ts,
+                cliff_ts,
+                20,
+                &VestingMode::Linear
+            ),
+            Ok(60)
+        );
+    }
+
+    #[test]
+    fn test_full_cliff() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            claim_status.unlocked_amount(49, 0, 100, 50, 100, &VestingMode::Linear),
+            Ok(0)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(50, 0, 100, 50, 100, &VestingMode::Linear),
+            Ok(100)
+        );
+    }
+
+    #[test]
+    fn test_percentage_amount_rounds_down() {
+        let claim_status = ClaimStatus {
+            locked_amount: 99,
+            ..Default::default()
+        };
+
+        assert_eq!(claim_status.percentage_amount(33), Ok(32));
+        assert_eq!(claim_status.percentage_amount(0), Ok(0));
+        assert_eq!(claim_status.percentage_amount(100), Ok(99));
+    }
+
+    #[test]
+    fn test_periodic_unlocking_scenario() {
+        let claim_status = ClaimStatus {
+            locked_amount: 120,
+            ..Default::default()
+        };
+        let vesting_mode = VestingMode::Periodic { period: 30 };
+        let start_ts = 0;
+        let end_ts = 360;
+
+        // 12 tranches of 10 tokens, one every 30 seconds
+        for (curr_ts, expected) in [(0, 0), (29, 0), (30, 10), (59, 10), (60, 20), (359, 110)] {
+            assert_eq!(
+                claim_status.unlocked_amount(curr_ts, start_ts, end_ts, start_ts, 0, &vesting_mode),
                 Ok(expected)
             );
         }
+        assert_eq!(
+            claim_status.unlocked_amount(360, start_ts, end_ts, start_ts, 0, &vesting_mode),
+            Ok(120)
+        );
+    }
+
+    #[test]
+    fn test_periodic_unlocking_with_cliff() {
+        let claim_status = ClaimStatus {
+            locked_amount: 100,
+            ..Default::default()
+        };
+        let vesting_mode = VestingMode::Periodic { period: 25 };
+
+        // 20 unlock at the cliff, then 20 every 25 seconds
+        assert_eq!(
+            claim_status.unlocked_amount(49, 0, 150, 50, 20, &vesting_mode),
+            Ok(0)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(74, 0, 150, 50, 20, &vesting_mode),
+            Ok(20)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(75, 0, 150, 50, 20, &vesting_mode),
+            Ok(40)
+        );
+    }
+
+    #[test]
+    fn test_milestone_unlocking_scenario() {
+        let claim_status = ClaimStatus {
+            locked_amount: 1000,
+            locked_amount_withdrawn: 100,
+            ..Default::default()
+        };
+        let mut milestones = [Milestone::default(); MAX_MILESTONES];
+        milestones[0] = Milestone {
+            offset: 0,
+            percentage: 10,
+        };
+        milestones[1] = Milestone {
+            offset: 100,
+            percentage: 50,
+        };
+        milestones[2] = Milestone {
+            offset: 300,
+            percentage: 100,
+        };
+        let vesting_mode = VestingMode::Milestones {
+            milestones,
+            num_milestones: 3,
+        };
+        let start_ts = 1000;
+
+        for (curr_ts, expected) in [
+            (999, 0),
+            (1000, 100),
+            (1099, 100),
+            (1100, 500),
+            (1300, 1000),
+        ] {
+            assert_eq!(
+                claim_status.unlocked_amount(curr_ts, start_ts, 0, 0, 0, &vesting_mode),
+                Ok(expected)
+            );
+        }
+        assert_eq!(
+            claim_status.amount_withdrawable(1100, start_ts, 0, 0, 0, &vesting_mode),
+            Ok(400)
+        );
+    }
+
+    #[test]
+    fn test_is_fully_withdrawn() {
+        let mut claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            unlocked_amount: 50,
+            locked_amount_withdrawn: 99,
+            ..Default::default()
+        };
+        assert!(!claim_status.is_fully_withdrawn());
+
+        claim_status.locked_amount_withdrawn = 100;
+        assert!(claim_status.is_fully_withdrawn());
+
+        // nothing locked, so the claim was fully paid out on creation
+        let claim_status = ClaimStatus {
+            unlocked_amount: 50,
+            ..Default::default()
+        };
+        assert!(claim_status.is_fully_withdrawn());
+    }
+
+    #[test]
+    fn test_revoked_claim() {
+        let mut claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            locked_amount_withdrawn: 10,
+            ..Default::default()
+        };
+        let vested = claim_status
+            .unlocked_amount(40, 0, 100, 0, 0, &VestingMode::Linear)
+            .unwrap();
+        claim_status.revoked_ts = 40;
+        claim_status.locked_amount_revoked = claim_status.locked_amount - vested;
+
+        // vesting stops at the revocation
+        assert_eq!(
+            claim_status.amount_withdrawable(100, 0, 100, 0, 0, &VestingMode::Linear),
+            Ok(30)
+        );
+        assert!(!claim_status.is_fully_withdrawn());
+
+        claim_status.locked_amount_withdrawn = vested;
+        assert!(claim_status.is_fully_withdrawn());
+        assert_eq!(
+            claim_status.amount_withdrawable(100, 0, 100, 0, 0, &VestingMode::Linear),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_transferred_claim() {
+        let leaf_claimant = Pubkey::new_unique();
+        let mut claim_status = ClaimStatus {
+            claimant: leaf_claimant,
+            leaf_claimant,
+            ..Default::default()
+        };
+        assert!(!claim_status.is_transferred());
+
+        claim_status.claimant = Pubkey::new_unique();
+        assert!(claim_status.is_transferred());
+
+        // transferring back to the leaf claimant undoes the transfer
+        claim_status.claimant = leaf_claimant;
+        assert!(!claim_status.is_transferred());
+    }
+
+    #[test]
+    fn test_extra_claim_amounts() {
+        let mut claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            locked_amount_withdrawn: 100,
+            start_ts: 0,
+            end_ts: 100,
+            ..Default::default()
+        };
+        claim_status.extra_amounts[0] = ExtraClaimAmount {
+            locked_amount: 1000,
+            locked_amount_withdrawn: 200,
+            unlocked_amount: 10,
+        };
+
+        // the extra mint vests on the claim's schedule
+        let extra_claim_status = claim_status.extra_claim_status(&claim_status.extra_amounts[0]);
+        assert_eq!(
+            extra_claim_status.amount_withdrawable(
+                50,
+                claim_status.start_ts,
+                claim_status.end_ts,
+                0,
+                0,
+                &VestingMode::Linear
+            ),
+            Ok(300)
+        );
+        assert!(!claim_status.is_fully_withdrawn());
+
+        claim_status.extra_amounts[0].locked_amount_withdrawn = 1000;
+        assert!(claim_status.is_fully_withdrawn());
+    }
+
+    #[test]
+    fn test_staked_locked_tokens() {
+        let mut claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            locked_amount_withdrawn: 10,
+            locked_amount_staked: 60,
+            ..Default::default()
+        };
+        assert_eq!(claim_status.locked_amount_idle(), 30);
+
+        // vesting goes on while staked, but only the idle tokens can be withdrawn
+        assert_eq!(
+            claim_status.amount_withdrawable(50, 0, 100, 0, 0, &VestingMode::Linear),
+            Ok(30)
+        );
+        assert_eq!(
+            claim_status.amount_withdrawable(20, 0, 100, 0, 0, &VestingMode::Linear),
+            Ok(10)
+        );
+
+        claim_status.locked_amount_staked = 0;
+        assert_eq!(
+            claim_status.amount_withdrawable(50, 0, 100, 0, 0, &VestingMode::Linear),
+            Ok(40)
+        );
+        assert!(!claim_status.is_fully_withdrawn());
     }
 }
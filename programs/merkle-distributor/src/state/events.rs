@@ -0,0 +1,378 @@
+use anchor_lang::{event, prelude::*};
+
+use crate::state::extra_mint::ExtraLeafAmount;
+
+/// Emitted when a new distributor is created.
+#[event]
+#[derive(Debug)]
+pub struct NewDistributorEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Mint distributed, the native mint for native SOL distributors.
+    pub mint: Pubkey,
+    /// Token account or SOL vault holding the distributed funds.
+    pub token_vault: Pubkey,
+    /// Admin of the distributor.
+    pub admin: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Maximum number of tokens that can ever be claimed.
+    pub max_total_claim: u64,
+    /// Maximum number of nodes that can ever be claimed.
+    pub max_num_nodes: u64,
+    /// Vesting start timestamp.
+    pub start_ts: i64,
+    /// Vesting end timestamp.
+    pub end_ts: i64,
+    /// Timestamp clawbacks are allowed from.
+    pub clawback_start_ts: i64,
+    /// Timestamp new claims are allowed until.
+    pub claim_end_ts: i64,
+    /// Whether the distributor pays out native SOL.
+    pub native_sol: bool,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a distributor is funded.
+#[event]
+#[derive(Debug)]
+pub struct FundedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Amount of tokens or lamports received by the vault.
+    pub amount: u64,
+    /// Total amount funded.
+    pub total_funded: u64,
+    /// Amount still missing to cover all unclaimed and locked tokens.
+    pub shortfall: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a new claim is created.
+#[event]
+#[derive(Debug)]
+pub struct NewClaimEvent {
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Unlocked amount of the merkle leaf, paid out on claim.
+    pub amount_unlocked: u64,
+    /// Locked amount of the merkle leaf, withdrawn as it vests.
+    pub amount_locked: u64,
+    /// Amounts of the extra mints in the merkle leaf, in the order of the distributor's extra
+    /// mints. Their unlocked amounts are paid out on claim too.
+    pub extra_amounts: Vec<ExtraLeafAmount>,
+}
+
+/// Emitted when tokens are claimed.
+#[event]
+#[derive(Debug)]
+pub struct ClaimedEvent {
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Amount of tokens to distribute.
+    pub amount: u64,
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+    /// Amounts of the extra mints withdrawn, in the order of the distributor's extra mints.
+    pub extra_amounts: Vec<u64>,
+}
+
+/// Emitted when claimed or locked tokens are staked.
+#[event]
+#[derive(Debug)]
+pub struct StakedEvent {
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Stake account the tokens were staked into.
+    pub stake_account: Pubkey,
+    /// Amount of tokens staked.
+    pub amount: u64,
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when staked locked tokens are unstaked.
+#[event]
+#[derive(Debug)]
+pub struct UnstakedEvent {
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Stake account the tokens were unstaked from.
+    pub stake_account: Pubkey,
+    /// Amount of tokens unstaked.
+    pub amount: u64,
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a claim bitmap page is initialized.
+#[event]
+#[derive(Debug)]
+pub struct ClaimBitmapInitializedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Page number.
+    pub page: u32,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a fully withdrawn claim status is closed.
+#[event]
+#[derive(Debug)]
+pub struct ClaimStatusClosedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Claimant of the closed claim.
+    pub claimant: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when funds are clawed back.
+#[event]
+#[derive(Debug)]
+pub struct ClawbackEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Account the funds were clawed back to.
+    pub receiver: Pubkey,
+    /// Amount of tokens or lamports clawed back.
+    pub amount: u64,
+    /// Whether only the unclaimed allocation was clawed back.
+    pub unclaimed_only: bool,
+    /// Locked tokens still owed to existing claims.
+    pub total_locked_outstanding: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the unvested locked tokens of a claim are revoked.
+#[event]
+#[derive(Debug)]
+pub struct ClaimRevokedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Claimant of the merkle leaf.
+    pub leaf_claimant: Pubkey,
+    /// Amount of locked tokens revoked.
+    pub amount: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a claim is transferred by its claimant or recovered by the admin.
+#[event]
+#[derive(Debug)]
+pub struct ClaimTransferredEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Claimant of the merkle leaf.
+    pub leaf_claimant: Pubkey,
+    /// Previous claimant.
+    pub old_claimant: Pubkey,
+    /// New claimant.
+    pub new_claimant: Pubkey,
+    /// Whether the admin recovered the claim.
+    pub recovered: bool,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin proposes a new admin.
+#[event]
+#[derive(Debug)]
+pub struct AdminProposedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Current admin.
+    pub admin: Pubkey,
+    /// Proposed admin.
+    pub pending_admin: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the proposed admin accepts.
+#[event]
+#[derive(Debug)]
+pub struct AdminAcceptedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Previous admin.
+    pub old_admin: Pubkey,
+    /// New admin.
+    pub new_admin: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin cancels an admin proposal.
+#[event]
+#[derive(Debug)]
+pub struct AdminProposalCancelledEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Admin that was proposed.
+    pub pending_admin: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the clawback receiver is changed.
+#[event]
+#[derive(Debug)]
+pub struct ClawbackReceiverSetEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Previous clawback receiver.
+    pub old_receiver: Pubkey,
+    /// New clawback receiver.
+    pub new_receiver: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the staking program is set.
+#[event]
+#[derive(Debug)]
+pub struct StakingProgramSetEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Staking program tokens are staked through.
+    pub staking_program: Pubkey,
+    /// Stake pool of the staking program.
+    pub stake_pool: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the merkle root is updated.
+#[event]
+#[derive(Debug)]
+pub struct RootUpdatedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// New merkle root.
+    pub root: [u8; 32],
+    /// New maximum number of tokens that can ever be claimed.
+    pub max_total_claim: u64,
+    /// New maximum number of nodes that can ever be claimed.
+    pub max_num_nodes: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when an extra mint is added.
+#[event]
+#[derive(Debug)]
+pub struct ExtraMintAddedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// The extra mint.
+    pub mint: Pubkey,
+    /// Token account holding the extra mint's tokens.
+    pub token_vault: Pubkey,
+    /// Maximum number of the extra mint's tokens that can ever be claimed.
+    pub max_total_claim: u64,
+    /// The new merkle root, carrying the extra mint amounts.
+    pub root: [u8; 32],
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when claims are paused or unpaused.
+#[event]
+#[derive(Debug)]
+pub struct PausedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Whether claims are paused now.
+    pub paused: bool,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when claims without the claimant's signature are enabled or disabled.
+#[event]
+#[derive(Debug)]
+pub struct PermissionlessClaimsSetEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Whether claims without the claimant's signature are enabled now.
+    pub permissionless_claims: bool,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a distributor account is migrated to the current layout.
+#[event]
+#[derive(Debug)]
+pub struct DistributorMigratedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Layout version before the migration.
+    pub old_layout_version: u8,
+    /// Layout version after the migration.
+    pub new_layout_version: u8,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a claim status account is migrated to the current layout.
+#[event]
+#[derive(Debug)]
+pub struct ClaimStatusMigratedEvent {
+    /// The distributor.
+    pub distributor: Pubkey,
+    /// Claimant of the merkle leaf the claim status is derived from.
+    pub leaf_claimant: Pubkey,
+    /// Layout version before the migration.
+    pub old_layout_version: u8,
+    /// Layout version after the migration.
+    pub new_layout_version: u8,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when SOL is deposited into custody.
+#[event]
+#[derive(Debug)]
+pub struct CustodyDepositedEvent {
+    /// Owner of the custody.
+    pub owner: Pubkey,
+    /// Lamports deposited.
+    pub amount: u64,
+    /// Lamports held in custody afterwards.
+    pub balance: u64,
+    /// Withdrawals are locked until this timestamp.
+    pub lock_until_ts: i64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when SOL is withdrawn from custody.
+#[event]
+#[derive(Debug)]
+pub struct CustodyWithdrawnEvent {
+    /// Owner of the custody.
+    pub owner: Pubkey,
+    /// Lamports withdrawn.
+    pub amount: u64,
+    /// Lamports held in custody afterwards.
+    pub balance: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of mints a [crate::state::merkle_distributor::MerkleDistributor] distributes
+/// alongside its mint.
+pub const MAX_EXTRA_MINTS: usize = 2;
+
+/// A mint distributed alongside the distributor's mint, every leaf carries an amount of it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExtraMint {
+    /// [Mint] of the token to be distributed.
+    pub mint: Pubkey,
+    /// Token Address of the vault, the distributor's associated token account
+    pub token_vault: Pubkey,
+    /// Maximum number of tokens of this mint that can ever be claimed.
+    pub max_total_claim: u64,
+    /// Total amount of tokens of this mint that have been claimed.
+    pub total_amount_claimed: u64,
+}
+
+impl ExtraMint {
+    /// Amount of tokens of this mint that can still be claimed, its vault has to hold them.
+    pub fn amount_outstanding(&self) -> u64 {
+        self.max_total_claim
+            .saturating_sub(self.total_amount_claimed)
+    }
+}
+
+/// Amounts of an extra mint in a merkle leaf.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExtraLeafAmount {
+    /// Amount unlocked
+    pub amount_unlocked: u64,
+    /// Amount locked
+    pub amount_locked: u64,
+}
+
+/// Amounts of an extra mint in a [crate::state::claim_status::ClaimStatus], vesting on the
+/// claim's schedule.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExtraClaimAmount {
+    /// Locked amount
+    pub locked_amount: u64,
+    /// Locked amount withdrawn
+    pub locked_amount_withdrawn: u64,
+    /// Unlocked amount
+    pub unlocked_amount: u64,
+}
@@ -3,6 +3,18 @@ use anchor_lang::{
     prelude::{Pubkey, *},
 };
 
+use crate::state::{
+    extra_mint::{ExtraMint, MAX_EXTRA_MINTS},
+    vesting_mode::VestingMode,
+};
+
+/// Layout of distributors created before the layout version was added, their accounts are
+/// shorter than [MerkleDistributor::LEN] until migrated.
+pub const LEGACY_LAYOUT_VERSION: u8 = 0;
+
+/// Layout version of new and migrated distributors.
+pub const LAYOUT_VERSION: u8 = 1;
+
 /// State for the account which distributes tokens.
 #[account]
 #[derive(Default, Debug)]
@@ -35,8 +47,319 @@ pub struct MerkleDistributor {
     pub admin: Pubkey,
     /// Whether or not the distributor has been clawed back
     pub clawed_back: bool,
+    /// Lockup cliff (Unix Timestamp), nothing unlocks before it
+    pub cliff_ts: i64,
+    /// Percentage of the locked amount unlocked at the cliff
+    pub cliff_percentage: u8,
+    /// How locked tokens unlock over the lockup period
+    pub vesting_mode: VestingMode,
+    /// Whether or not claims are paused by the admin
+    pub paused: bool,
+    /// Clawback start (Unix Timestamp), the unclaimed tokens can't be clawed back before it.
+    /// At least [MIN_CLAWBACK_DELAY] after end_ts and after every leaf's own vesting end, as a
+    /// full clawback ignores per-leaf schedules. Leaves can't be checked on-chain, the CLI and
+    /// merkle tree builder enforce it for them.
+    ///
+    /// [MIN_CLAWBACK_DELAY]: crate::instructions::new_distributor::MIN_CLAWBACK_DELAY
+    pub clawback_start_ts: i64,
+    /// Token account the unclaimed tokens are clawed back to
+    pub clawback_receiver: Pubkey,
+    /// Proposed admin wallet, default if there is no pending proposal
+    pub pending_admin: Pubkey,
+    /// Whether claims are tracked in [crate::state::claim_bitmap::ClaimBitmap] pages instead of
+    /// claim status accounts, only for distributors without locked tokens
+    pub claim_bitmap: bool,
+    /// Total amount of tokens the vault received through fund_distributor
+    pub total_funded: u64,
+    /// Whether the vault covered all unclaimed tokens when last funded, new claims are rejected until then
+    pub funded: bool,
+    /// Claim window end (Unix Timestamp), no new claims after it but existing claims keep vesting
+    pub claim_end_ts: i64,
+    /// Locked tokens of existing claims that have not been withdrawn yet
+    pub total_locked_outstanding: u64,
+    /// Amount of unclaimed or revoked tokens clawed back while existing claims keep vesting
+    pub amount_clawed_back: u64,
+    /// Whether the unclaimed allocation has been clawed back, no new claims after it
+    pub unclaimed_clawed_back: bool,
+    /// Whether the admin can revoke the unvested locked tokens of a claim
+    pub revocable: bool,
+    /// Whether the admin can move a claim to a new wallet of its claimant
+    pub recoverable: bool,
+    /// Whether the distributor pays out native SOL from its SOL vault PDA instead of tokens,
+    /// the token vault then holds the SOL vault address and the clawback receiver a wallet
+    pub native_sol: bool,
+    /// Mints distributed alongside the mint, only the first num_extra_mints entries are used
+    pub extra_mints: [ExtraMint; MAX_EXTRA_MINTS],
+    /// Number of extra mints, every leaf carries an amount of each
+    pub num_extra_mints: u8,
+    /// Staking program claimants can stake their claimed tokens into, default if staking is disabled,
+    /// it can only be set once
+    pub staking_program: Pubkey,
+    /// Stake pool of the staking program the claimed tokens are staked into
+    pub stake_pool: Pubkey,
+    /// Locked tokens of existing claims staked by the distributor instead of held by the vault
+    pub total_locked_staked: u64,
+    /// Whether anyone can claim to a claimant's associated token account without their
+    /// signature, off unless enabled by the admin
+    pub permissionless_claims: bool,
+    /// Layout of the account, new fields are carved out of the reserved bytes and bump it
+    pub layout_version: u8,
+    /// Zeroed bytes reserved for future fields, so adding them doesn't resize the account
+    pub reserved: [u64; 16],
 }
 
 impl MerkleDistributor {
     pub const LEN: usize = 8 + std::mem::size_of::<MerkleDistributor>();
+
+    /// Deserializes a distributor account of any layout, their layout_version is
+    /// [LEGACY_LAYOUT_VERSION]. Legacy distributors keep their behavior: the claim window never
+    /// ends, there is no cliff and the locked tokens of existing claims, unknown to the account,
+    /// are bounded by everything not claimed yet. The other fields they didn't have are zero,
+    /// migrate_distributor sets the clawback receiver and whether the vault is funded.
+    #[allow(clippy::result_large_err)]
+    pub fn try_deserialize_any_layout(data: &[u8]) -> Result<Self> {
+        let mut padded = data.to_vec();
+        if padded.len() < Self::LEN {
+            padded.resize(Self::LEN, 0);
+        }
+        let mut distributor = Self::try_deserialize(&mut padded.as_slice())?;
+        if distributor.needs_migration() {
+            distributor.claim_end_ts = i64::MAX;
+            distributor.cliff_ts = distributor.start_ts;
+            distributor.total_locked_outstanding = distributor
+                .max_total_claim
+                .saturating_sub(distributor.total_amount_claimed);
+        }
+
+        Ok(distributor)
+    }
+
+    /// Whether the account has to be migrated to the current layout with migrate_distributor.
+    pub fn needs_migration(&self) -> bool {
+        self.layout_version < LAYOUT_VERSION
+    }
+
+    /// Amount of tokens that can still be claimed.
+    pub fn amount_outstanding(&self) -> u64 {
+        self.max_total_claim
+            .saturating_sub(self.total_amount_claimed)
+            .saturating_sub(self.amount_clawed_back)
+    }
+
+    /// Amount of tokens allocated to leaves that have not been claimed yet.
+    pub fn amount_unclaimed(&self) -> u64 {
+        self.amount_outstanding()
+            .saturating_sub(self.total_locked_outstanding)
+    }
+
+    /// Whether new claims are accepted, existing claims keep vesting regardless.
+    pub fn claim_window_open(&self, curr_ts: i64) -> bool {
+        curr_ts < self.claim_end_ts && !self.unclaimed_clawed_back
+    }
+
+    /// Cliff of a claim vesting from start_ts to end_ts. Leaves with their own schedule keep the
+    /// distributor's cliff offset from the start, capped at their end, so the cliff always
+    /// falls within the schedule it applies to.
+    pub fn claim_cliff_ts(&self, start_ts: i64, end_ts: i64) -> i64 {
+        let cliff_offset = self.cliff_ts.saturating_sub(self.start_ts).max(0);
+        start_ts.saturating_add(cliff_offset).min(end_ts)
+    }
+
+    /// The extra mints in use.
+    pub fn extra_mints(&self) -> &[ExtraMint] {
+        &self.extra_mints[..self.num_extra_mints as usize]
+    }
+
+    /// Amount of tokens the vault is missing to cover all unclaimed tokens, staked locked tokens
+    /// are covered by the stake.
+    pub fn funding_shortfall(&self, vault_amount: u64) -> u64 {
+        self.amount_outstanding()
+            .saturating_sub(vault_amount.saturating_add(self.total_locked_staked))
+    }
+
+    /// Whether the vaults of the extra mints, in the order of the extra mints, hold everything of
+    /// their mint that can still be claimed.
+    pub fn extra_mints_funded(&self, extra_vault_amounts: &[u64]) -> bool {
+        self.extra_mints()
+            .iter()
+            .zip(extra_vault_amounts)
+            .all(|(extra_mint, vault_amount)| *vault_amount >= extra_mint.amount_outstanding())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn test_funding_shortfall() {
+        let distributor = MerkleDistributor {
+            max_total_claim: 1_000,
+            total_amount_claimed: 400,
+            ..Default::default()
+        };
+
+        assert_eq!(distributor.amount_outstanding(), 600);
+        assert_eq!(distributor.funding_shortfall(0), 600);
+        assert_eq!(distributor.funding_shortfall(500), 100);
+        assert_eq!(distributor.funding_shortfall(600), 0);
+        assert_eq!(distributor.funding_shortfall(700), 0);
+
+        // staked locked tokens left the vault but are still covered
+        let distributor = MerkleDistributor {
+            total_locked_staked: 200,
+            ..distributor
+        };
+        assert_eq!(distributor.funding_shortfall(400), 0);
+        assert_eq!(distributor.funding_shortfall(300), 100);
+    }
+
+    #[test]
+    fn test_extra_mints_funded() {
+        let mut distributor = MerkleDistributor {
+            num_extra_mints: 2,
+            ..Default::default()
+        };
+        distributor.extra_mints[0].max_total_claim = 500;
+        distributor.extra_mints[1].max_total_claim = 300;
+        distributor.extra_mints[1].total_amount_claimed = 100;
+
+        assert!(distributor.extra_mints_funded(&[500, 200]));
+        assert!(!distributor.extra_mints_funded(&[499, 200]));
+        assert!(!distributor.extra_mints_funded(&[500, 199]));
+
+        // distributors without extra mints are always covered
+        distributor.num_extra_mints = 0;
+        assert!(distributor.extra_mints_funded(&[]));
+    }
+
+    /// The distributor layout before any fields were added to it.
+    #[derive(AnchorSerialize)]
+    struct LegacyMerkleDistributor {
+        bump: u8,
+        version: u64,
+        root: [u8; 32],
+        mint: Pubkey,
+        token_vault: Pubkey,
+        max_total_claim: u64,
+        max_num_nodes: u64,
+        total_amount_claimed: u64,
+        num_nodes_claimed: u64,
+        start_ts: i64,
+        end_ts: i64,
+        creator: Pubkey,
+        admin: Pubkey,
+        clawed_back: bool,
+    }
+
+    #[test]
+    fn test_deserialize_any_layout() {
+        let distributor = MerkleDistributor {
+            max_total_claim: 1_000,
+            total_locked_staked: 200,
+            layout_version: LAYOUT_VERSION,
+            ..Default::default()
+        };
+        let mut data = vec![];
+        distributor.try_serialize(&mut data).unwrap();
+
+        let current = MerkleDistributor::try_deserialize_any_layout(&data).unwrap();
+        assert_eq!(current.max_total_claim, 1_000);
+        assert_eq!(current.total_locked_staked, 200);
+        assert!(!current.needs_migration());
+
+        // other accounts are rejected
+        assert!(MerkleDistributor::try_deserialize_any_layout(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_legacy_layout() {
+        let legacy = LegacyMerkleDistributor {
+            bump: 255,
+            version: 0,
+            root: [1; 32],
+            mint: Pubkey::new_unique(),
+            token_vault: Pubkey::new_unique(),
+            max_total_claim: 1_000,
+            max_num_nodes: 10,
+            total_amount_claimed: 400,
+            num_nodes_claimed: 4,
+            start_ts: 100,
+            end_ts: 200,
+            creator: Pubkey::new_unique(),
+            admin: Pubkey::new_unique(),
+            clawed_back: false,
+        };
+        // legacy accounts were allocated with the size of the legacy struct
+        let mut data = MerkleDistributor::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(8 + std::mem::size_of::<LegacyMerkleDistributor>(), 0);
+        assert!(MerkleDistributor::try_deserialize(&mut data.as_slice()).is_err());
+
+        let distributor = MerkleDistributor::try_deserialize_any_layout(&data).unwrap();
+        assert_eq!(distributor.bump, 255);
+        assert_eq!(distributor.root, [1; 32]);
+        assert_eq!(distributor.mint, legacy.mint);
+        assert_eq!(distributor.token_vault, legacy.token_vault);
+        assert_eq!(distributor.max_total_claim, 1_000);
+        assert_eq!(distributor.total_amount_claimed, 400);
+        assert_eq!(distributor.num_nodes_claimed, 4);
+        assert_eq!((distributor.start_ts, distributor.end_ts), (100, 200));
+        assert_eq!(distributor.creator, legacy.creator);
+        assert_eq!(distributor.admin, legacy.admin);
+        assert_eq!(distributor.layout_version, LEGACY_LAYOUT_VERSION);
+        assert!(distributor.needs_migration());
+
+        // claims stay open, without a cliff, and withdrawing the locked tokens of existing
+        // claims can't underflow the locked token accounting
+        assert!(distributor.claim_window_open(i64::MAX - 1));
+        assert_eq!(distributor.claim_cliff_ts(100, 200), 100);
+        assert_eq!(distributor.total_locked_outstanding, 600);
+        assert_eq!(distributor.amount_unclaimed(), 0);
+        assert_eq!(distributor.funding_shortfall(600), 0);
+        assert_eq!(distributor.funding_shortfall(500), 100);
+    }
+
+    #[test]
+    fn test_claim_cliff_ts() {
+        let distributor = MerkleDistributor {
+            start_ts: 100,
+            end_ts: 200,
+            cliff_ts: 150,
+            ..Default::default()
+        };
+
+        assert_eq!(distributor.claim_cliff_ts(100, 200), 150);
+        // leaf schedules keep the cliff offset, within the leaf's schedule
+        assert_eq!(distributor.claim_cliff_ts(1_000, 1_100), 1_050);
+        assert_eq!(distributor.claim_cliff_ts(1_000, 1_020), 1_020);
+        assert_eq!(distributor.claim_cliff_ts(0, 30), 30);
+
+        // a cliff before the start, as left by the legacy layout, is no cliff
+        let distributor = MerkleDistributor {
+            cliff_ts: 0,
+            ..distributor
+        };
+        assert_eq!(distributor.claim_cliff_ts(1_000, 1_100), 1_000);
+    }
+
+    #[test]
+    fn test_amount_unclaimed() {
+        let mut distributor = MerkleDistributor {
+            max_total_claim: 1_000,
+            total_amount_claimed: 400,
+            total_locked_outstanding: 250,
+            ..Default::default()
+        };
+
+        assert_eq!(distributor.amount_unclaimed(), 350);
+
+        // after clawing back the unclaimed tokens only the locked obligations are outstanding
+        distributor.amount_clawed_back = distributor.amount_unclaimed();
+        assert_eq!(distributor.amount_outstanding(), 250);
+        assert_eq!(distributor.amount_unclaimed(), 0);
+        assert_eq!(distributor.funding_shortfall(250), 0);
+    }
 }
@@ -1,3 +1,7 @@
+pub mod claim_bitmap;
 pub mod claim_status;
-pub mod claimed_event;
+pub mod events;
+pub mod extra_mint;
 pub mod merkle_distributor;
+pub mod sol_custody;
+pub mod vesting_mode;
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode::ArithmeticError;
+
+/// Tracks the SOL an owner keeps in the custody vault PDA `[b"SolCustody", owner]`.
+#[account]
+#[derive(Default, Debug)]
+pub struct SolCustody {
+    /// Owner of the custody, the only one able to withdraw
+    pub owner: Pubkey,
+    /// Bump seed of this account.
+    pub bump: u8,
+    /// Bump seed of the custody vault holding the lamports.
+    pub vault_bump: u8,
+    /// Withdrawals are rejected before this timestamp, 0 if never locked
+    pub lock_until_ts: i64,
+    /// Lamports deposited, including those sent to the vault directly
+    pub total_deposited: u64,
+    /// Lamports withdrawn
+    pub total_withdrawn: u64,
+}
+
+impl SolCustody {
+    pub const LEN: usize = 8 + std::mem::size_of::<SolCustody>();
+
+    /// Sets up a newly created account
+    pub fn init_if_new(&mut self, owner: Pubkey, bump: u8, vault_bump: u8) {
+        if self.owner != Pubkey::default() {
+            return;
+        }
+        self.owner = owner;
+        self.bump = bump;
+        self.vault_bump = vault_bump;
+    }
+
+    /// Counts lamports sent to the vault directly as deposited, so the balance is everything the
+    /// vault holds. Only lamports the custody withdraws leave the vault, it never holds less.
+    #[allow(clippy::result_large_err)]
+    pub fn sync(&mut self, vault_lamports: u64) -> Result<()> {
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(vault_lamports.saturating_sub(self.balance()))
+            .ok_or(ArithmeticError)?;
+
+        Ok(())
+    }
+
+    /// Lamports held in custody
+    pub fn balance(&self) -> u64 {
+        self.total_deposited.saturating_sub(self.total_withdrawn)
+    }
+
+    /// Whether withdrawals are still locked at the timestamp
+    pub fn is_locked(&self, curr_ts: i64) -> bool {
+        curr_ts < self.lock_until_ts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_if_new() {
+        let owner = Pubkey::new_unique();
+        let mut custody = SolCustody::default();
+        custody.init_if_new(owner, 254, 253);
+        assert_eq!(custody.owner, owner);
+        assert_eq!(custody.vault_bump, 253);
+
+        // existing accounts are left untouched
+        custody.init_if_new(Pubkey::new_unique(), 1, 1);
+        assert_eq!(custody.owner, owner);
+        assert_eq!(custody.bump, 254);
+    }
+
+    #[test]
+    fn test_sync() {
+        // lamports sent before the account existed
+        let mut custody = SolCustody::default();
+        custody.sync(500).unwrap();
+        assert_eq!(custody.total_deposited, 500);
+        assert_eq!(custody.balance(), 500);
+
+        // lamports sent directly after some were withdrawn
+        custody.total_withdrawn = 200;
+        custody.sync(700).unwrap();
+        assert_eq!(custody.total_deposited, 900);
+        assert_eq!(custody.balance(), 700);
+
+        // nothing new in the vault
+        custody.sync(700).unwrap();
+        assert_eq!(custody.total_deposited, 900);
+    }
+
+    #[test]
+    fn test_balance_and_lock() {
+        let mut custody = SolCustody {
+            total_deposited: 1_000,
+            ..SolCustody::default()
+        };
+        assert_eq!(custody.balance(), 1_000);
+        assert!(!custody.is_locked(0));
+
+        custody.total_withdrawn = 400;
+        custody.lock_until_ts = 100;
+        assert_eq!(custody.balance(), 600);
+        assert!(custody.is_locked(99));
+        assert!(!custody.is_locked(100));
+    }
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of entries in a [VestingMode::Milestones] table.
+pub const MAX_MILESTONES: usize = 16;
+
+/// A single entry of a milestone vesting table.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Milestone {
+    /// Seconds after the lockup start at which this milestone is reached
+    pub offset: i64,
+    /// Cumulative percentage of the locked amount unlocked once this milestone is reached
+    pub percentage: u8,
+}
+
+/// How locked tokens unlock between the lockup start and end.
+/// Stored inline in [crate::state::merkle_distributor::MerkleDistributor], so the milestone table
+/// is a fixed size array rather than a boxed vector.
+#[allow(clippy::large_enum_variant)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VestingMode {
+    /// Tokens unlock every second
+    #[default]
+    Linear,
+    /// Tokens unlock in equal tranches at the end of every period
+    Periodic {
+        /// Period length in seconds
+        period: i64,
+    },
+    /// Tokens unlock according to a table of milestones, the lockup end and cliff are not used
+    Milestones {
+        /// Milestone table, only the first num_milestones entries are used
+        milestones: [Milestone; MAX_MILESTONES],
+        /// Number of milestones in the table
+        num_milestones: u8,
+    },
+}
+
+impl VestingMode {
+    /// Returns whether the vesting mode parameters are usable:
+    ///     1. A period is longer than zero
+    ///     2. Milestones are ordered by offset, never decrease in percentage and end at 100%
+    pub fn is_valid(&self) -> bool {
+        match self {
+            VestingMode::Linear => true,
+            VestingMode::Periodic { period } => *period > 0,
+            VestingMode::Milestones { .. } => {
+                let milestones = self.milestones();
+                if milestones.is_empty() || milestones.len() != self.num_milestones() as usize {
+                    return false;
+                }
+                let ordered = milestones.windows(2).all(|pair| {
+                    pair[0].offset < pair[1].offset && pair[0].percentage <= pair[1].percentage
+                });
+                ordered && milestones.last().map(|m| m.percentage) == Some(100)
+            }
+        }
+    }
+
+    /// The used part of the milestone table, empty for other vesting modes
+    pub fn milestones(&self) -> &[Milestone] {
+        match self {
+            VestingMode::Milestones {
+                milestones,
+                num_milestones,
+            } => &milestones[..(*num_milestones as usize).min(MAX_MILESTONES)],
+            _ => &[],
+        }
+    }
+
+    fn num_milestones(&self) -> u8 {
+        match self {
+            VestingMode::Milestones { num_milestones, .. } => *num_milestones,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn milestones(table: &[(i64, u8)]) -> VestingMode {
+        let mut milestones = [Milestone::default(); MAX_MILESTONES];
+        for (i, (offset, percentage)) in table.iter().enumerate() {
+            milestones[i] = Milestone {
+                offset: *offset,
+                percentage: *percentage,
+            };
+        }
+        VestingMode::Milestones {
+            milestones,
+            num_milestones: table.len() as u8,
+        }
+    }
+
+    #[test]
+    fn test_vesting_mode_validation() {
+        assert!(VestingMode::Linear.is_valid());
+        assert!(VestingMode::Periodic { period: 30 }.is_valid());
+        assert!(!VestingMode::Periodic { period: 0 }.is_valid());
+
+        assert!(milestones(&[(0, 25), (100, 50), (200, 100)]).is_valid());
+        assert!(!milestones(&[]).is_valid());
+        // doesn't reach 100%
+        assert!(!milestones(&[(0, 25), (100, 50)]).is_valid());
+        // offsets out of order
+        assert!(!milestones(&[(100, 25), (0, 100)]).is_valid());
+        // percentage decreases
+        assert!(!milestones(&[(0, 50), (100, 25), (200, 100)]).is_valid());
+        // more milestones than fit in the table
+        assert!(!VestingMode::Milestones {
+            milestones: [Milestone::default(); MAX_MILESTONES],
+            num_milestones: MAX_MILESTONES as u8 + 1,
+        }
+        .is_valid());
+    }
+}
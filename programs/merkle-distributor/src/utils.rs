@@ -0,0 +1,827 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::{invoke, invoke_signed},
+        system_instruction,
+    },
+};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{approve, revoke, Approve, Mint, Revoke, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::{ClaimStatus, LAYOUT_VERSION},
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// Transfers tokens out of an account owned by the [MerkleDistributor] PDA with `transfer_checked`.
+/// Remaining accounts are appended to the transfer so Token-2022 transfer hooks can find their
+/// extra accounts.
+#[allow(clippy::result_large_err)]
+pub fn transfer_from_distributor<'info>(
+    distributor: &Account<'info, MerkleDistributor>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.creator.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        &from.key(),
+        &mint.key(),
+        &to.key(),
+        &distributor.key(),
+        &[],
+        amount,
+        mint.decimals,
+    )?;
+    let mut account_infos = vec![
+        from.to_account_info(),
+        mint.to_account_info(),
+        to.to_account_info(),
+        distributor.to_account_info(),
+    ];
+    for account in remaining_accounts {
+        ix.accounts.push(AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(account.clone());
+    }
+
+    invoke_signed(&ix, &account_infos, &[&seeds[..]]).map_err(Into::into)
+}
+
+/// Transfers tokens from an account owned by a signer with `transfer_checked`.
+/// Remaining accounts are appended to the transfer so Token-2022 transfer hooks can find their
+/// extra accounts.
+#[allow(clippy::result_large_err)]
+pub fn transfer_from_signer<'info>(
+    authority: &Signer<'info>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        &from.key(),
+        &mint.key(),
+        &to.key(),
+        &authority.key(),
+        &[],
+        amount,
+        mint.decimals,
+    )?;
+    let mut account_infos = vec![
+        from.to_account_info(),
+        mint.to_account_info(),
+        to.to_account_info(),
+        authority.to_account_info(),
+    ];
+    for account in remaining_accounts {
+        ix.accounts.push(AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(account.clone());
+    }
+
+    invoke(&ix, &account_infos).map_err(Into::into)
+}
+
+/// Transfers lamports out of the SOL vault PDA of a native SOL [MerkleDistributor].
+/// The recipient has to end up rent-exempt, which the runtime would otherwise reject with an
+/// opaque error after the transfer.
+#[allow(clippy::result_large_err)]
+pub fn transfer_from_sol_vault<'info>(
+    distributor: &Account<'info, MerkleDistributor>,
+    sol_vault: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    sol_vault_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let distributor_key = distributor.key();
+    let seeds = [
+        b"SolVault".as_ref(),
+        distributor_key.as_ref(),
+        &[sol_vault_bump],
+    ];
+
+    check_rent_exempt_after_transfer(&Rent::get()?, to.lamports(), to.data_len(), amount)?;
+
+    invoke_signed(
+        &system_instruction::transfer(sol_vault.key, to.key, amount),
+        &[
+            sol_vault.clone(),
+            to.clone(),
+            system_program.to_account_info(),
+        ],
+        &[&seeds[..]],
+    )
+    .map_err(Into::into)
+}
+
+/// Checks an account holding lamports with data_len bytes of data is rent-exempt after
+/// receiving amount lamports.
+#[allow(clippy::result_large_err)]
+pub fn check_rent_exempt_after_transfer(
+    rent: &Rent,
+    lamports: u64,
+    data_len: usize,
+    amount: u64,
+) -> Result<()> {
+    let balance = lamports
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    require!(
+        rent.is_exempt(balance, data_len),
+        ErrorCode::SolTransferBelowRentExempt
+    );
+
+    Ok(())
+}
+
+/// Returns the lamports of a SOL vault available for distribution, the vault keeps the
+/// rent-exempt minimum so it can't be garbage collected while claims are outstanding.
+#[allow(clippy::result_large_err)]
+pub fn sol_vault_amount(sol_vault: &AccountInfo) -> Result<u64> {
+    Ok(sol_vault
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0)))
+}
+
+/// Accounts of an extra mint transfer, passed in remaining accounts.
+pub struct ExtraMintAccounts<'info> {
+    /// Distributor ATA containing the tokens of the extra mint.
+    pub from: InterfaceAccount<'info, TokenAccount>,
+    /// Account to send the tokens to.
+    pub to: InterfaceAccount<'info, TokenAccount>,
+    /// The extra mint.
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// The SPL Token or Token-2022 program of the extra mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Parses the remaining accounts of a [MerkleDistributor] with extra mints: for every extra mint
+/// in order, its vault, the token account to send to, the mint and its token program.
+#[allow(clippy::result_large_err)]
+pub fn extra_mint_accounts<'info>(
+    distributor: &MerkleDistributor,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Vec<ExtraMintAccounts<'info>>> {
+    if distributor.num_extra_mints == 0 {
+        return Ok(vec![]);
+    }
+    require!(
+        remaining_accounts.len() == 4 * distributor.extra_mints().len(),
+        ErrorCode::InvalidExtraMintAccounts
+    );
+
+    distributor
+        .extra_mints()
+        .iter()
+        .zip(remaining_accounts.chunks(4))
+        .map(|(extra_mint, accounts)| {
+            require_keys_eq!(
+                accounts[0].key(),
+                extra_mint.token_vault,
+                ErrorCode::InvalidExtraMintAccounts
+            );
+            require_keys_eq!(
+                accounts[2].key(),
+                extra_mint.mint,
+                ErrorCode::InvalidExtraMintAccounts
+            );
+            let extra_mint_accounts = ExtraMintAccounts {
+                from: InterfaceAccount::try_from(&accounts[0])?,
+                to: InterfaceAccount::try_from(&accounts[1])?,
+                mint: InterfaceAccount::try_from(&accounts[2])?,
+                token_program: Interface::try_from(&accounts[3])?,
+            };
+            require_keys_eq!(
+                extra_mint_accounts.to.mint,
+                extra_mint.mint,
+                ErrorCode::InvalidExtraMintAccounts
+            );
+
+            Ok(extra_mint_accounts)
+        })
+        .collect()
+}
+
+/// Returns the balances of the extra mint vaults of a [MerkleDistributor], passed in remaining
+/// accounts in the order of the extra mints.
+#[allow(clippy::result_large_err)]
+pub fn extra_mint_vault_amounts(
+    distributor: &MerkleDistributor,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Vec<u64>> {
+    require!(
+        remaining_accounts.len() == distributor.extra_mints().len(),
+        ErrorCode::InvalidExtraMintAccounts
+    );
+
+    distributor
+        .extra_mints()
+        .iter()
+        .zip(remaining_accounts)
+        .map(|(extra_mint, token_vault)| {
+            require_keys_eq!(
+                token_vault.key(),
+                extra_mint.token_vault,
+                ErrorCode::InvalidExtraMintAccounts
+            );
+
+            Ok(InterfaceAccount::<TokenAccount>::try_from(token_vault)?.amount)
+        })
+        .collect()
+}
+
+/// Transfers amounts of the extra mints to the claimant's token accounts passed in remaining
+/// accounts and adds them to the extra mints' claimed totals.
+#[allow(clippy::result_large_err)]
+pub fn transfer_extra_mints_to_claimant<'info>(
+    distributor: &mut Account<'info, MerkleDistributor>,
+    claimant: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amounts: &[u64],
+) -> Result<()> {
+    let extra_mint_accounts = extra_mint_accounts(distributor, remaining_accounts)?;
+    for (i, (accounts, amount)) in extra_mint_accounts.iter().zip(amounts).enumerate() {
+        require_keys_eq!(accounts.to.owner, claimant.key(), ErrorCode::OwnerMismatch);
+        check_claimant_authorized(
+            distributor,
+            claimant,
+            &accounts.to,
+            &accounts.mint,
+            &accounts.token_program,
+        )?;
+
+        if *amount > 0 {
+            transfer_from_distributor(
+                distributor,
+                &accounts.from,
+                &accounts.to,
+                &accounts.mint,
+                &accounts.token_program,
+                &[],
+                *amount,
+            )?;
+        }
+
+        let extra_mint = &mut distributor.extra_mints[i];
+        extra_mint.total_amount_claimed = extra_mint
+            .total_amount_claimed
+            .checked_add(*amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        require!(
+            extra_mint.total_amount_claimed <= extra_mint.max_total_claim,
+            ErrorCode::ExceededMaxClaim
+        );
+    }
+
+    Ok(())
+}
+
+/// Remaining accounts forwarded to the token transfer of the distributor's mint, distributors
+/// with extra mints pass the extra mint accounts instead and don't support transfer hooks.
+pub fn transfer_hook_accounts<'a, 'info>(
+    distributor: &MerkleDistributor,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> &'a [AccountInfo<'info>] {
+    match distributor.num_extra_mints {
+        0 => remaining_accounts,
+        _ => &[],
+    }
+}
+
+/// Tag of the staking program's `Stake` instruction. Access Protocol is not an anchor program, its
+/// instruction data is the index of the instruction followed by the borsh encoded parameters.
+pub const STAKE_IX_TAG: u8 = 4;
+
+/// Tag of the staking program's `Unstake` instruction.
+pub const UNSTAKE_IX_TAG: u8 = 5;
+
+/// Stakes tokens from the source token account into a stake account of the staking program.
+/// The staking program's `Stake { amount: u64 }` takes its central state, the stake account, the
+/// stake pool, the owner of the stake account as the only signer, who also has to be the authority
+/// of the source, the source, the token program, the stake pool vault and the fee account.
+/// Stake pools charging a fee move it from the source to the fee account on top of the amount.
+#[allow(clippy::result_large_err)]
+pub fn stake_tokens<'info>(
+    staking_program: &AccountInfo<'info>,
+    central_state: &AccountInfo<'info>,
+    stake_account: &AccountInfo<'info>,
+    stake_pool: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    stake_vault: &AccountInfo<'info>,
+    fee_account: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = vec![STAKE_IX_TAG];
+    data.extend_from_slice(&amount.to_le_bytes());
+    let ix = Instruction {
+        program_id: staking_program.key(),
+        accounts: vec![
+            AccountMeta::new(central_state.key(), false),
+            AccountMeta::new(stake_account.key(), false),
+            AccountMeta::new(stake_pool.key(), false),
+            AccountMeta::new_readonly(owner.key(), true),
+            AccountMeta::new(source.key(), false),
+            AccountMeta::new_readonly(token_program.key(), false),
+            AccountMeta::new(stake_vault.key(), false),
+            AccountMeta::new(fee_account.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            central_state.clone(),
+            stake_account.clone(),
+            stake_pool.clone(),
+            owner.clone(),
+            source.clone(),
+            token_program.clone(),
+            stake_vault.clone(),
+            fee_account.clone(),
+            staking_program.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Unstakes tokens from a stake account of the staking program into the destination token account.
+/// The staking program's `Unstake { amount: u64 }` takes its central state, the stake account, the
+/// stake pool, the owner of the stake account as the only signer, the destination, the token
+/// program and the stake pool vault.
+#[allow(clippy::result_large_err)]
+pub fn unstake_tokens<'info>(
+    staking_program: &AccountInfo<'info>,
+    central_state: &AccountInfo<'info>,
+    stake_account: &AccountInfo<'info>,
+    stake_pool: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    stake_vault: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = vec![UNSTAKE_IX_TAG];
+    data.extend_from_slice(&amount.to_le_bytes());
+    let ix = Instruction {
+        program_id: staking_program.key(),
+        accounts: vec![
+            AccountMeta::new(central_state.key(), false),
+            AccountMeta::new(stake_account.key(), false),
+            AccountMeta::new(stake_pool.key(), false),
+            AccountMeta::new_readonly(owner.key(), true),
+            AccountMeta::new(destination.key(), false),
+            AccountMeta::new_readonly(token_program.key(), false),
+            AccountMeta::new(stake_vault.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            central_state.clone(),
+            stake_account.clone(),
+            stake_pool.clone(),
+            owner.clone(),
+            destination.clone(),
+            token_program.clone(),
+            stake_vault.clone(),
+            staking_program.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Stakes amount out of the distributor's vault into a stake account of the staking program.
+/// The vault delegates exactly the amount to the owner of the stake account for the duration of
+/// the stake, so the staking program never gets the distributor's signature. The owner is either
+/// the claimant, who signed the transaction, or the claim's stake authority PDA, which signs with
+/// owner_seeds.
+/// Only fee-free stake pools are supported: a fee charged on top of the amount exceeds the
+/// delegated amount, and a fee taken out of it is caught by checking the stake pool vault
+/// received exactly the amount, so other claimants' tokens never pay it. Checks exactly the
+/// amount left the vault, the claim accounting relies on it.
+#[allow(clippy::result_large_err)]
+pub fn stake_from_vault<'info>(
+    distributor: &Account<'info, MerkleDistributor>,
+    token_vault: &mut InterfaceAccount<'info, TokenAccount>,
+    token_program: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    owner_seeds: &[&[&[u8]]],
+    staking_program: &AccountInfo<'info>,
+    central_state: &AccountInfo<'info>,
+    stake_account: &AccountInfo<'info>,
+    stake_pool: &AccountInfo<'info>,
+    stake_vault: &AccountInfo<'info>,
+    fee_account: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = [
+        b"MerkleDistributor".as_ref(),
+        &distributor.mint.to_bytes(),
+        &distributor.creator.to_bytes(),
+        &distributor.version.to_le_bytes(),
+        &[distributor.bump],
+    ];
+    let vault_amount_before = token_vault.amount;
+    let stake_vault_amount_before = InterfaceAccount::<TokenAccount>::try_from(stake_vault)?.amount;
+
+    approve(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Approve {
+                to: token_vault.to_account_info(),
+                delegate: owner.clone(),
+                authority: distributor.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )?;
+    stake_tokens(
+        staking_program,
+        central_state,
+        stake_account,
+        stake_pool,
+        owner,
+        &token_vault.to_account_info(),
+        token_program,
+        stake_vault,
+        fee_account,
+        amount,
+        owner_seeds,
+    )?;
+    revoke(CpiContext::new_with_signer(
+        token_program.clone(),
+        Revoke {
+            source: token_vault.to_account_info(),
+            authority: distributor.to_account_info(),
+        },
+        &[&seeds[..]],
+    ))?;
+
+    token_vault.reload()?;
+    require!(
+        vault_amount_before.checked_sub(token_vault.amount) == Some(amount),
+        ErrorCode::InvalidStakeTransfer
+    );
+    let stake_vault_amount = InterfaceAccount::<TokenAccount>::try_from(stake_vault)?.amount;
+    require!(
+        stake_vault_amount.checked_sub(stake_vault_amount_before) == Some(amount),
+        ErrorCode::StakingFeeNotSupported
+    );
+
+    Ok(())
+}
+
+/// Checks the claimant signed, or the distributor allows permissionless claims and the claimant
+/// receives the tokens in their associated token account, so anyone can push tokens to a
+/// claimant but only the claimant can send them elsewhere.
+#[allow(clippy::result_large_err)]
+pub fn check_claimant_authorized(
+    distributor: &MerkleDistributor,
+    claimant: &AccountInfo,
+    to: &InterfaceAccount<TokenAccount>,
+    mint: &InterfaceAccount<Mint>,
+    token_program: &Interface<TokenInterface>,
+) -> Result<()> {
+    if claimant.is_signer {
+        return Ok(());
+    }
+    require!(
+        distributor.permissionless_claims,
+        ErrorCode::ClaimantSignatureRequired
+    );
+
+    let claimant_ata = get_associated_token_address_with_program_id(
+        claimant.key,
+        &mint.key(),
+        &token_program.key(),
+    );
+    require_keys_eq!(to.key(), claimant_ata, ErrorCode::ClaimantSignatureRequired);
+
+    Ok(())
+}
+
+/// Returns the Token-2022 transfer fee withheld when transferring amount in the given epoch.
+/// Mints without a transfer fee, including SPL Token mints, have no fee.
+#[allow(clippy::result_large_err)]
+pub fn transfer_fee(mint_data: &[u8], epoch: u64, amount: u64) -> Result<u64> {
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(mint_data)?;
+
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or_else(|| ErrorCode::ArithmeticError.into()),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Checks new claims are accepted: the claim window is open, the distributor, or its unclaimed
+/// allocation, has not been clawed back, and it is funded and not paused.
+#[allow(clippy::result_large_err)]
+pub fn check_new_claim_allowed(distributor: &MerkleDistributor, curr_ts: i64) -> Result<()> {
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        distributor.claim_window_open(curr_ts),
+        ErrorCode::ClaimExpired
+    );
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(distributor.funded, ErrorCode::DistributorNotFunded);
+
+    Ok(())
+}
+
+/// Counts a new claim against max_num_nodes.
+#[allow(clippy::result_large_err)]
+pub fn increment_num_nodes_claimed(distributor: &mut MerkleDistributor) -> Result<()> {
+    distributor.num_nodes_claimed = distributor
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.num_nodes_claimed <= distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    Ok(())
+}
+
+/// Seeds a new claim status with the amounts and vesting schedule of the claimant's leaf.
+pub fn init_claim_status(
+    claim_status: &mut ClaimStatus,
+    claimant: Pubkey,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    start_ts: i64,
+    end_ts: i64,
+) {
+    claim_status.claimant = claimant;
+    claim_status.leaf_claimant = claimant;
+    claim_status.layout_version = LAYOUT_VERSION;
+    claim_status.locked_amount = amount_locked;
+    claim_status.unlocked_amount = amount_unlocked;
+    claim_status.locked_amount_withdrawn = 0;
+    claim_status.start_ts = start_ts;
+    claim_status.end_ts = end_ts;
+}
+
+/// Records a new claim paying out amount_unlocked, whose amount_locked is owed until it vests.
+#[allow(clippy::result_large_err)]
+pub fn record_new_claim(
+    distributor: &mut MerkleDistributor,
+    amount_unlocked: u64,
+    amount_locked: u64,
+) -> Result<()> {
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount_unlocked)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    distributor.total_locked_outstanding = distributor
+        .total_locked_outstanding
+        .checked_add(amount_locked)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.total_amount_claimed <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    Ok(())
+}
+
+/// Records the withdrawal of amount vested locked tokens of a claim.
+#[allow(clippy::result_large_err)]
+pub fn record_locked_withdrawal(
+    distributor: &mut MerkleDistributor,
+    claim_status: &mut ClaimStatus,
+    amount: u64,
+) -> Result<()> {
+    claim_status.locked_amount_withdrawn = claim_status
+        .locked_amount_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        claim_status.locked_amount_withdrawn <= claim_status.locked_amount,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    distributor.total_locked_outstanding = distributor
+        .total_locked_outstanding
+        .checked_sub(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.total_amount_claimed <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    Ok(())
+}
+
+/// Checks the distributor can be clawed back, in full or only its unclaimed allocation.
+#[allow(clippy::result_large_err)]
+pub fn check_clawback_allowed(
+    distributor: &MerkleDistributor,
+    unclaimed_only: bool,
+    curr_ts: i64,
+) -> Result<()> {
+    require!(!distributor.clawed_back, ErrorCode::ClawbackAlreadyClaimed);
+    require!(
+        !(unclaimed_only && distributor.unclaimed_clawed_back),
+        ErrorCode::ClawbackAlreadyClaimed
+    );
+    require!(
+        curr_ts >= distributor.clawback_start_ts,
+        ErrorCode::ClawbackBeforeStart
+    );
+
+    Ok(())
+}
+
+/// Marks the distributor as clawed back, or only its unclaimed allocation so existing claims keep
+/// withdrawing their locked tokens. amount is what was transferred out of the vault, which may
+/// fall short of the unclaimed allocation.
+#[allow(clippy::result_large_err)]
+pub fn record_clawback(
+    distributor: &mut MerkleDistributor,
+    unclaimed_only: bool,
+    amount: u64,
+) -> Result<()> {
+    if unclaimed_only {
+        distributor.amount_clawed_back = distributor
+            .amount_clawed_back
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        distributor.unclaimed_clawed_back = true;
+    } else {
+        distributor.clawed_back = true;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{transfer_fee::TransferFee, ExtensionType, StateWithExtensionsMut},
+        state::Mint as SplMint,
+    };
+
+    use super::*;
+
+    fn transfer_fee_mint(
+        older_basis_points: u16,
+        newer_basis_points: u16,
+        newer_epoch: u64,
+    ) -> Vec<u8> {
+        let mut data =
+            vec![0; ExtensionType::get_account_len::<SplMint>(&[ExtensionType::TransferFeeConfig])];
+        let mut mint = StateWithExtensionsMut::<SplMint>::unpack_uninitialized(&mut data).unwrap();
+        let transfer_fee_config = mint.init_extension::<TransferFeeConfig>(true).unwrap();
+        transfer_fee_config.older_transfer_fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: 5_000.into(),
+            transfer_fee_basis_points: older_basis_points.into(),
+        };
+        transfer_fee_config.newer_transfer_fee = TransferFee {
+            epoch: newer_epoch.into(),
+            maximum_fee: 5_000.into(),
+            transfer_fee_basis_points: newer_basis_points.into(),
+        };
+        mint.base = SplMint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+
+        data
+    }
+
+    #[test]
+    fn test_transfer_fee_mint() {
+        let data = transfer_fee_mint(100, 200, 10);
+
+        // 1% before epoch 10, 2% from epoch 10, rounded up
+        assert_eq!(transfer_fee(&data, 0, 10_000), Ok(100));
+        assert_eq!(transfer_fee(&data, 0, 10_001), Ok(101));
+        assert_eq!(transfer_fee(&data, 10, 10_000), Ok(200));
+        // capped at the maximum fee
+        assert_eq!(transfer_fee(&data, 10, 1_000_000), Ok(5_000));
+        assert_eq!(transfer_fee(&data, 10, 0), Ok(0));
+    }
+
+    #[test]
+    fn test_transfer_fee_without_extension() {
+        let mut data = vec![0; SplMint::LEN];
+        SplMint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+
+        assert_eq!(transfer_fee(&data, 0, 10_000), Ok(0));
+    }
+
+    #[test]
+    fn test_check_rent_exempt_after_transfer() {
+        let rent = Rent::default();
+        let minimum_balance = rent.minimum_balance(0);
+
+        // a small SOL leaf can't be paid to a zero-balance recipient
+        assert_eq!(
+            check_rent_exempt_after_transfer(&rent, 0, 0, 1_000),
+            Err(ErrorCode::SolTransferBelowRentExempt.into())
+        );
+        assert_eq!(
+            check_rent_exempt_after_transfer(&rent, 0, 0, minimum_balance - 1),
+            Err(ErrorCode::SolTransferBelowRentExempt.into())
+        );
+        assert_eq!(
+            check_rent_exempt_after_transfer(&rent, 0, 0, minimum_balance),
+            Ok(())
+        );
+        // nor to a funded recipient whose data needs a larger balance
+        assert_eq!(
+            check_rent_exempt_after_transfer(&rent, minimum_balance, 165, 1_000),
+            Err(ErrorCode::SolTransferBelowRentExempt.into())
+        );
+        assert_eq!(
+            check_rent_exempt_after_transfer(&rent, minimum_balance, 0, 1_000),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_record_clawback_unclaimed_only() {
+        let mut distributor = MerkleDistributor {
+            max_total_claim: 1_000,
+            total_amount_claimed: 400,
+            total_locked_outstanding: 250,
+            ..Default::default()
+        };
+        assert_eq!(distributor.amount_unclaimed(), 350);
+
+        // the vault only held 300 of the 350 unclaimed tokens
+        record_clawback(&mut distributor, true, 300).unwrap();
+        assert_eq!(distributor.amount_clawed_back, 300);
+        assert!(distributor.unclaimed_clawed_back);
+        assert!(!distributor.clawed_back);
+        assert_eq!(distributor.amount_outstanding(), 300);
+
+        record_clawback(&mut distributor, false, 250).unwrap();
+        assert_eq!(distributor.amount_clawed_back, 300);
+        assert!(distributor.clawed_back);
+    }
+}
@@ -1,2 +1,4 @@
+from .claim_bitmap import ClaimBitmap, ClaimBitmapJSON
 from .claim_status import ClaimStatus, ClaimStatusJSON
 from .merkle_distributor import MerkleDistributor, MerkleDistributorJSON
+from .sol_custody import SolCustody, SolCustodyJSON
@@ -9,6 +9,7 @@ from anchorpy.error import AccountInvalidDiscriminator
 from anchorpy.utils.rpc import get_multiple_accounts
 from anchorpy.borsh_extension import BorshPubkey
 from ..program_id import PROGRAM_ID
+from .. import types
 
 
 class ClaimStatusJSON(typing.TypedDict):
@@ -16,6 +17,15 @@ class ClaimStatusJSON(typing.TypedDict):
     locked_amount: int
     locked_amount_withdrawn: int
     unlocked_amount: int
+    start_ts: int
+    end_ts: int
+    revoked_ts: int
+    locked_amount_revoked: int
+    leaf_claimant: str
+    extra_amounts: list[types.extra_claim_amount.ExtraClaimAmountJSON]
+    locked_amount_staked: int
+    layout_version: int
+    reserved: list[int]
 
 
 @dataclass
@@ -26,11 +36,29 @@ class ClaimStatus:
         "locked_amount" / borsh.U64,
         "locked_amount_withdrawn" / borsh.U64,
         "unlocked_amount" / borsh.U64,
+        "start_ts" / borsh.I64,
+        "end_ts" / borsh.I64,
+        "revoked_ts" / borsh.I64,
+        "locked_amount_revoked" / borsh.U64,
+        "leaf_claimant" / BorshPubkey,
+        "extra_amounts" / types.extra_claim_amount.ExtraClaimAmount.layout[2],
+        "locked_amount_staked" / borsh.U64,
+        "layout_version" / borsh.U8,
+        "reserved" / borsh.U64[8],
     )
     claimant: Pubkey
     locked_amount: int
     locked_amount_withdrawn: int
     unlocked_amount: int
+    start_ts: int
+    end_ts: int
+    revoked_ts: int
+    locked_amount_revoked: int
+    leaf_claimant: Pubkey
+    extra_amounts: list[types.extra_claim_amount.ExtraClaimAmount]
+    locked_amount_staked: int
+    layout_version: int
+    reserved: list[int]
 
     @classmethod
     async def fetch(
@@ -80,6 +108,22 @@ class ClaimStatus:
             locked_amount=dec.locked_amount,
             locked_amount_withdrawn=dec.locked_amount_withdrawn,
             unlocked_amount=dec.unlocked_amount,
+            start_ts=dec.start_ts,
+            end_ts=dec.end_ts,
+            revoked_ts=dec.revoked_ts,
+            locked_amount_revoked=dec.locked_amount_revoked,
+            leaf_claimant=dec.leaf_claimant,
+            extra_amounts=list(
+                map(
+                    lambda item: types.extra_claim_amount.ExtraClaimAmount.from_decoded(
+                        item
+                    ),
+                    dec.extra_amounts,
+                )
+            ),
+            locked_amount_staked=dec.locked_amount_staked,
+            layout_version=dec.layout_version,
+            reserved=dec.reserved,
         )
 
     def to_json(self) -> ClaimStatusJSON:
@@ -88,6 +132,15 @@ class ClaimStatus:
             "locked_amount": self.locked_amount,
             "locked_amount_withdrawn": self.locked_amount_withdrawn,
             "unlocked_amount": self.unlocked_amount,
+            "start_ts": self.start_ts,
+            "end_ts": self.end_ts,
+            "revoked_ts": self.revoked_ts,
+            "locked_amount_revoked": self.locked_amount_revoked,
+            "leaf_claimant": str(self.leaf_claimant),
+            "extra_amounts": list(map(lambda item: item.to_json(), self.extra_amounts)),
+            "locked_amount_staked": self.locked_amount_staked,
+            "layout_version": self.layout_version,
+            "reserved": self.reserved,
         }
 
     @classmethod
@@ -97,4 +150,20 @@ class ClaimStatus:
             locked_amount=obj["locked_amount"],
             locked_amount_withdrawn=obj["locked_amount_withdrawn"],
             unlocked_amount=obj["unlocked_amount"],
+            start_ts=obj["start_ts"],
+            end_ts=obj["end_ts"],
+            revoked_ts=obj["revoked_ts"],
+            locked_amount_revoked=obj["locked_amount_revoked"],
+            leaf_claimant=Pubkey.from_string(obj["leaf_claimant"]),
+            extra_amounts=list(
+                map(
+                    lambda item: types.extra_claim_amount.ExtraClaimAmount.from_json(
+                        item
+                    ),
+                    obj["extra_amounts"],
+                )
+            ),
+            locked_amount_staked=obj["locked_amount_staked"],
+            layout_version=obj["layout_version"],
+            reserved=obj["reserved"],
         )
@@ -0,0 +1,112 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class SolCustodyJSON(typing.TypedDict):
+    owner: str
+    bump: int
+    vault_bump: int
+    lock_until_ts: int
+    total_deposited: int
+    total_withdrawn: int
+
+
+@dataclass
+class SolCustody:
+    discriminator: typing.ClassVar = b'Y"K\xa0$i\xb0\xbf'
+    layout: typing.ClassVar = borsh.CStruct(
+        "owner" / BorshPubkey,
+        "bump" / borsh.U8,
+        "vault_bump" / borsh.U8,
+        "lock_until_ts" / borsh.I64,
+        "total_deposited" / borsh.U64,
+        "total_withdrawn" / borsh.U64,
+    )
+    owner: Pubkey
+    bump: int
+    vault_bump: int
+    lock_until_ts: int
+    total_deposited: int
+    total_withdrawn: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["SolCustody"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["SolCustody"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["SolCustody"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "SolCustody":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = SolCustody.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            owner=dec.owner,
+            bump=dec.bump,
+            vault_bump=dec.vault_bump,
+            lock_until_ts=dec.lock_until_ts,
+            total_deposited=dec.total_deposited,
+            total_withdrawn=dec.total_withdrawn,
+        )
+
+    def to_json(self) -> SolCustodyJSON:
+        return {
+            "owner": str(self.owner),
+            "bump": self.bump,
+            "vault_bump": self.vault_bump,
+            "lock_until_ts": self.lock_until_ts,
+            "total_deposited": self.total_deposited,
+            "total_withdrawn": self.total_withdrawn,
+        }
+
+    @classmethod
+    def from_json(cls, obj: SolCustodyJSON) -> "SolCustody":
+        return cls(
+            owner=Pubkey.from_string(obj["owner"]),
+            bump=obj["bump"],
+            vault_bump=obj["vault_bump"],
+            lock_until_ts=obj["lock_until_ts"],
+            total_deposited=obj["total_deposited"],
+            total_withdrawn=obj["total_withdrawn"],
+        )
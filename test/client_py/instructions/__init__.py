@@ -1,5 +1,77 @@
 from .new_distributor import new_distributor, NewDistributorArgs, NewDistributorAccounts
+from .fund_distributor import (
+    fund_distributor,
+    FundDistributorArgs,
+    FundDistributorAccounts,
+)
+from .new_sol_distributor import (
+    new_sol_distributor,
+    NewSolDistributorArgs,
+    NewSolDistributorAccounts,
+)
+from .fund_sol_distributor import (
+    fund_sol_distributor,
+    FundSolDistributorArgs,
+    FundSolDistributorAccounts,
+)
 from .new_claim import new_claim, NewClaimArgs, NewClaimAccounts
+from .new_claim_and_stake import (
+    new_claim_and_stake,
+    NewClaimAndStakeArgs,
+    NewClaimAndStakeAccounts,
+)
+from .init_claim_bitmap import (
+    init_claim_bitmap,
+    InitClaimBitmapArgs,
+    InitClaimBitmapAccounts,
+)
+from .new_claim_bitmap import (
+    new_claim_bitmap,
+    NewClaimBitmapArgs,
+    NewClaimBitmapAccounts,
+)
+from .claim_all import claim_all, ClaimAllArgs, ClaimAllAccounts
 from .claim_locked import claim_locked, ClaimLockedAccounts
+from .claim_locked_and_stake import claim_locked_and_stake, ClaimLockedAndStakeAccounts
+from .stake_locked import stake_locked, StakeLockedArgs, StakeLockedAccounts
+from .unstake_locked import unstake_locked, UnstakeLockedArgs, UnstakeLockedAccounts
+from .new_claim_sol import new_claim_sol, NewClaimSolArgs, NewClaimSolAccounts
+from .claim_locked_sol import claim_locked_sol, ClaimLockedSolAccounts
+from .close_claim_status import close_claim_status, CloseClaimStatusAccounts
 from .clawback import clawback, ClawbackAccounts
-from .set_admin import set_admin, SetAdminAccounts
+from .clawback_unclaimed import clawback_unclaimed, ClawbackUnclaimedAccounts
+from .clawback_sol import clawback_sol, ClawbackSolAccounts
+from .clawback_unclaimed_sol import clawback_unclaimed_sol, ClawbackUnclaimedSolAccounts
+from .revoke_claim import revoke_claim, RevokeClaimAccounts
+from .transfer_claim import transfer_claim, TransferClaimAccounts
+from .recover_claim import recover_claim, RecoverClaimAccounts
+from .propose_admin import propose_admin, ProposeAdminAccounts
+from .accept_admin import accept_admin, AcceptAdminAccounts
+from .cancel_admin_proposal import cancel_admin_proposal, CancelAdminProposalAccounts
+from .set_clawback_receiver import set_clawback_receiver, SetClawbackReceiverAccounts
+from .set_staking_program import set_staking_program, SetStakingProgramAccounts
+from .update_root import update_root, UpdateRootArgs, UpdateRootAccounts
+from .add_extra_mint import add_extra_mint, AddExtraMintArgs, AddExtraMintAccounts
+from .migrate_distributor import (
+    migrate_distributor,
+    MigrateDistributorArgs,
+    MigrateDistributorAccounts,
+)
+from .migrate_claim_status import migrate_claim_status, MigrateClaimStatusAccounts
+from .pause import pause, PauseAccounts
+from .unpause import unpause, UnpauseAccounts
+from .set_permissionless_claims import (
+    set_permissionless_claims,
+    SetPermissionlessClaimsArgs,
+    SetPermissionlessClaimsAccounts,
+)
+from .deposit_custody_sol import (
+    deposit_custody_sol,
+    DepositCustodySolArgs,
+    DepositCustodySolAccounts,
+)
+from .withdraw_custody_sol import (
+    withdraw_custody_sol,
+    WithdrawCustodySolArgs,
+    WithdrawCustodySolAccounts,
+)
@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class AddExtraMintArgs(typing.TypedDict):
+    root: list[int]
+    max_total_claim: int
+
+
+layout = borsh.CStruct("root" / borsh.U8[32], "max_total_claim" / borsh.U64)
+
+
+class AddExtraMintAccounts(typing.TypedDict):
+    distributor: Pubkey
+    mint: Pubkey
+    token_vault: Pubkey
+    admin: Pubkey
+    token_program: Pubkey
+
+
+def add_extra_mint(
+    args: AddExtraMintArgs,
+    accounts: AddExtraMintAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["token_vault"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"X\xad_\xf6\xf2\xa5)\\"
+    encoded_args = layout.build(
+        {
+            "root": args["root"],
+            "max_total_claim": args["max_total_claim"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
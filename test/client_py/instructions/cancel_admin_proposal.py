@@ -0,0 +1,27 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CancelAdminProposalAccounts(typing.TypedDict):
+    distributor: Pubkey
+    admin: Pubkey
+
+
+def cancel_admin_proposal(
+    accounts: CancelAdminProposalAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=True),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"D\x06\x91\x83\x10I\xb6\xe5"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
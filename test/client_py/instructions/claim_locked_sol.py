@@ -0,0 +1,36 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class ClaimLockedSolAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    sol_vault: Pubkey
+    claimant: Pubkey
+
+
+def claim_locked_sol(
+    accounts: ClaimLockedSolAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+    claimant_signer: bool = True,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["sol_vault"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["claimant"], is_signer=claimant_signer, is_writable=True
+        ),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"82m\x1c\n\xc5\xe8\xa9"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,39 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class ClawbackUnclaimedAccounts(typing.TypedDict):
+    distributor: Pubkey
+    from_: Pubkey
+    to: Pubkey
+    admin: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
+
+
+def clawback_unclaimed(
+    accounts: ClawbackUnclaimedAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["from_"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["to"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"L8\x99\x0f\xfe\xaa8\xae"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,33 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class ClawbackUnclaimedSolAccounts(typing.TypedDict):
+    distributor: Pubkey
+    sol_vault: Pubkey
+    to: Pubkey
+    admin: Pubkey
+
+
+def clawback_unclaimed_sol(
+    accounts: ClawbackUnclaimedSolAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["sol_vault"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["to"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb0>\xc8\xad\t;>\xb9"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,40 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class InitClaimBitmapArgs(typing.TypedDict):
+    page: int
+
+
+layout = borsh.CStruct("page" / borsh.U32)
+
+
+class InitClaimBitmapAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_bitmap: Pubkey
+    payer: Pubkey
+
+
+def init_claim_bitmap(
+    args: InitClaimBitmapArgs,
+    accounts: InitClaimBitmapAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["claim_bitmap"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"Q\x8a?\xf3\xef'M\xda"
+    encoded_args = layout.build({"page": args["page"]})
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,64 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class NewClaimBitmapArgs(typing.TypedDict):
+    index: int
+    amount_unlocked: int
+    proof: list[list[int]]
+
+
+layout = borsh.CStruct(
+    "index" / borsh.U64,
+    "amount_unlocked" / borsh.U64,
+    "proof" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+)
+
+
+class NewClaimBitmapAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_bitmap: Pubkey
+    from_: Pubkey
+    to: Pubkey
+    claimant: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
+
+
+def new_claim_bitmap(
+    args: NewClaimBitmapArgs,
+    accounts: NewClaimBitmapAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+    claimant_signer: bool = True,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["claim_bitmap"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["from_"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["to"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["claimant"], is_signer=claimant_signer, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x97\xab\xed9\xdb9+\xb3"
+    encoded_args = layout.build(
+        {
+            "index": args["index"],
+            "amount_unlocked": args["amount_unlocked"],
+            "proof": args["proof"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,66 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class NewClaimSolArgs(typing.TypedDict):
+    amount_unlocked: int
+    amount_locked: int
+    start_vesting_ts: typing.Optional[int]
+    end_vesting_ts: typing.Optional[int]
+    proof: list[list[int]]
+
+
+layout = borsh.CStruct(
+    "amount_unlocked" / borsh.U64,
+    "amount_locked" / borsh.U64,
+    "start_vesting_ts" / borsh.Option(borsh.I64),
+    "end_vesting_ts" / borsh.Option(borsh.I64),
+    "proof" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+)
+
+
+class NewClaimSolAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    sol_vault: Pubkey
+    claimant: Pubkey
+    payer: Pubkey
+
+
+def new_claim_sol(
+    args: NewClaimSolArgs,
+    accounts: NewClaimSolAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+    claimant_signer: bool = True,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["sol_vault"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["claimant"], is_signer=claimant_signer, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x89\x00\xbd\xc1\xa6,\xbfS"
+    encoded_args = layout.build(
+        {
+            "amount_unlocked": args["amount_unlocked"],
+            "amount_locked": args["amount_locked"],
+            "start_vesting_ts": args["start_vesting_ts"],
+            "end_vesting_ts": args["end_vesting_ts"],
+            "proof": args["proof"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
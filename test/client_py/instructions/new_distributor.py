@@ -2,9 +2,10 @@ from __future__ import annotations
 import typing
 from solders.pubkey import Pubkey
 from solders.system_program import ID as SYS_PROGRAM_ID
-from spl.token.constants import TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID
+from spl.token.constants import ASSOCIATED_TOKEN_PROGRAM_ID
 from solders.instruction import Instruction, AccountMeta
 import borsh_construct as borsh
+from .. import types
 from ..program_id import PROGRAM_ID
 
 
@@ -15,6 +16,14 @@ class NewDistributorArgs(typing.TypedDict):
     max_num_nodes: int
     start_vesting_ts: int
     end_vesting_ts: int
+    cliff_vesting_ts: int
+    cliff_percentage: int
+    vesting_mode: types.vesting_mode.VestingModeKind
+    clawback_start_ts: int
+    claim_bitmap: bool
+    claim_end_ts: int
+    revocable: bool
+    recoverable: bool
 
 
 layout = borsh.CStruct(
@@ -24,6 +33,14 @@ layout = borsh.CStruct(
     "max_num_nodes" / borsh.U64,
     "start_vesting_ts" / borsh.I64,
     "end_vesting_ts" / borsh.I64,
+    "cliff_vesting_ts" / borsh.I64,
+    "cliff_percentage" / borsh.U8,
+    "vesting_mode" / types.vesting_mode.layout,
+    "clawback_start_ts" / borsh.I64,
+    "claim_bitmap" / borsh.Bool,
+    "claim_end_ts" / borsh.I64,
+    "revocable" / borsh.Bool,
+    "recoverable" / borsh.Bool,
 )
 
 
@@ -31,7 +48,9 @@ class NewDistributorAccounts(typing.TypedDict):
     distributor: Pubkey
     mint: Pubkey
     token_vault: Pubkey
+    clawback_receiver: Pubkey
     creator: Pubkey
+    token_program: Pubkey
 
 
 def new_distributor(
@@ -44,12 +63,17 @@ def new_distributor(
         AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
         AccountMeta(pubkey=accounts["token_vault"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["clawback_receiver"], is_signer=False, is_writable=False
+        ),
         AccountMeta(pubkey=accounts["creator"], is_signer=True, is_writable=True),
         AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
         AccountMeta(
             pubkey=ASSOCIATED_TOKEN_PROGRAM_ID, is_signer=False, is_writable=False
         ),
-        AccountMeta(pubkey=TOKEN_PROGRAM_ID, is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
     ]
     if remaining_accounts is not None:
         keys += remaining_accounts
@@ -62,6 +86,14 @@ def new_distributor(
             "max_num_nodes": args["max_num_nodes"],
             "start_vesting_ts": args["start_vesting_ts"],
             "end_vesting_ts": args["end_vesting_ts"],
+            "cliff_vesting_ts": args["cliff_vesting_ts"],
+            "cliff_percentage": args["cliff_percentage"],
+            "vesting_mode": args["vesting_mode"].to_encodable(),
+            "clawback_start_ts": args["clawback_start_ts"],
+            "claim_bitmap": args["claim_bitmap"],
+            "claim_end_ts": args["claim_end_ts"],
+            "revocable": args["revocable"],
+            "recoverable": args["recoverable"],
         }
     )
     data = identifier + encoded_args
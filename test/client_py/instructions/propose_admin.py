@@ -5,25 +5,25 @@ from solders.instruction import Instruction, AccountMeta
 from ..program_id import PROGRAM_ID
 
 
-class SetAminAccounts(typing.TypedDict):
+class ProposeAdminAccounts(typing.TypedDict):
     distributor: Pubkey
     admin: Pubkey
     new_admin: Pubkey
 
 
-def set_amin(
-    accounts: SetAminAccounts,
+def propose_admin(
+    accounts: ProposeAdminAccounts,
     program_id: Pubkey = PROGRAM_ID,
     remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
 ) -> Instruction:
     keys: list[AccountMeta] = [
         AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
         AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=True),
-        AccountMeta(pubkey=accounts["new_admin"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["new_admin"], is_signer=False, is_writable=False),
     ]
     if remaining_accounts is not None:
         keys += remaining_accounts
-    identifier = b"\xb0\x054 \x16\x18\xa7\xf9"
+    identifier = b"y\xd6\xc7\xd4W'u\xea"
     encoded_args = b""
     data = identifier + encoded_args
     return Instruction(program_id, data, keys)
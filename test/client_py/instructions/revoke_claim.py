@@ -0,0 +1,39 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class RevokeClaimAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    from_: Pubkey
+    to: Pubkey
+    admin: Pubkey
+    mint: Pubkey
+    token_program: Pubkey
+
+
+def revoke_claim(
+    accounts: RevokeClaimAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["from_"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["to"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=False),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb6\x01\x8e!\xcf\x99%\x84"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,38 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetPermissionlessClaimsArgs(typing.TypedDict):
+    permissionless_claims: bool
+
+
+layout = borsh.CStruct("permissionless_claims" / borsh.Bool)
+
+
+class SetPermissionlessClaimsAccounts(typing.TypedDict):
+    distributor: Pubkey
+    admin: Pubkey
+
+
+def set_permissionless_claims(
+    args: SetPermissionlessClaimsArgs,
+    accounts: SetPermissionlessClaimsAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xdd\xad\x07\xea\xe1/\xf2\xcf"
+    encoded_args = layout.build(
+        {"permissionless_claims": args["permissionless_claims"]}
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,66 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class UnstakeLockedArgs(typing.TypedDict):
+    amount: int
+
+
+layout = borsh.CStruct("amount" / borsh.U64)
+
+
+class UnstakeLockedAccounts(typing.TypedDict):
+    distributor: Pubkey
+    claim_status: Pubkey
+    token_vault: Pubkey
+    authority: Pubkey
+    token_program: Pubkey
+    central_state: Pubkey
+    stake_pool: Pubkey
+    stake_authority: Pubkey
+    stake_account: Pubkey
+    stake_vault: Pubkey
+    fee_account: Pubkey
+    staking_program: Pubkey
+
+
+def unstake_locked(
+    args: UnstakeLockedArgs,
+    accounts: UnstakeLockedAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["claim_status"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["token_vault"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["token_program"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["central_state"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["stake_pool"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["stake_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["stake_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["stake_vault"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["fee_account"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["staking_program"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xfbhl/\xa8ZZ\xc7"
+    encoded_args = layout.build({"amount": args["amount"]})
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class UpdateRootArgs(typing.TypedDict):
+    root: list[int]
+    max_total_claim: int
+    max_num_nodes: int
+
+
+layout = borsh.CStruct(
+    "root" / borsh.U8[32], "max_total_claim" / borsh.U64, "max_num_nodes" / borsh.U64
+)
+
+
+class UpdateRootAccounts(typing.TypedDict):
+    distributor: Pubkey
+    admin: Pubkey
+
+
+def update_root(
+    args: UpdateRootArgs,
+    accounts: UpdateRootAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["distributor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["admin"], is_signer=True, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b":\xc39\xf6t\xc6\xaa\x8a"
+    encoded_args = layout.build(
+        {
+            "root": args["root"],
+            "max_total_claim": args["max_total_claim"],
+            "max_num_nodes": args["max_num_nodes"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
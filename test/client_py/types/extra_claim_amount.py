@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class ExtraClaimAmountJSON(typing.TypedDict):
+    locked_amount: int
+    locked_amount_withdrawn: int
+    unlocked_amount: int
+
+
+@dataclass
+class ExtraClaimAmount:
+    layout: typing.ClassVar = borsh.CStruct(
+        "locked_amount" / borsh.U64,
+        "locked_amount_withdrawn" / borsh.U64,
+        "unlocked_amount" / borsh.U64,
+    )
+    locked_amount: int
+    locked_amount_withdrawn: int
+    unlocked_amount: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ExtraClaimAmount":
+        return cls(
+            locked_amount=obj.locked_amount,
+            locked_amount_withdrawn=obj.locked_amount_withdrawn,
+            unlocked_amount=obj.unlocked_amount,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "locked_amount": self.locked_amount,
+            "locked_amount_withdrawn": self.locked_amount_withdrawn,
+            "unlocked_amount": self.unlocked_amount,
+        }
+
+    def to_json(self) -> ExtraClaimAmountJSON:
+        return {
+            "locked_amount": self.locked_amount,
+            "locked_amount_withdrawn": self.locked_amount_withdrawn,
+            "unlocked_amount": self.unlocked_amount,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ExtraClaimAmountJSON) -> "ExtraClaimAmount":
+        return cls(
+            locked_amount=obj["locked_amount"],
+            locked_amount_withdrawn=obj["locked_amount_withdrawn"],
+            unlocked_amount=obj["unlocked_amount"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class ExtraMintJSON(typing.TypedDict):
+    mint: str
+    token_vault: str
+    max_total_claim: int
+    total_amount_claimed: int
+
+
+@dataclass
+class ExtraMint:
+    layout: typing.ClassVar = borsh.CStruct(
+        "mint" / BorshPubkey,
+        "token_vault" / BorshPubkey,
+        "max_total_claim" / borsh.U64,
+        "total_amount_claimed" / borsh.U64,
+    )
+    mint: Pubkey
+    token_vault: Pubkey
+    max_total_claim: int
+    total_amount_claimed: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ExtraMint":
+        return cls(
+            mint=obj.mint,
+            token_vault=obj.token_vault,
+            max_total_claim=obj.max_total_claim,
+            total_amount_claimed=obj.total_amount_claimed,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "mint": self.mint,
+            "token_vault": self.token_vault,
+            "max_total_claim": self.max_total_claim,
+            "total_amount_claimed": self.total_amount_claimed,
+        }
+
+    def to_json(self) -> ExtraMintJSON:
+        return {
+            "mint": str(self.mint),
+            "token_vault": str(self.token_vault),
+            "max_total_claim": self.max_total_claim,
+            "total_amount_claimed": self.total_amount_claimed,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ExtraMintJSON) -> "ExtraMint":
+        return cls(
+            mint=Pubkey.from_string(obj["mint"]),
+            token_vault=Pubkey.from_string(obj["token_vault"]),
+            max_total_claim=obj["max_total_claim"],
+            total_amount_claimed=obj["total_amount_claimed"],
+        )
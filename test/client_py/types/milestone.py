@@ -0,0 +1,45 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class MilestoneJSON(typing.TypedDict):
+    offset: int
+    percentage: int
+
+
+@dataclass
+class Milestone:
+    layout: typing.ClassVar = borsh.CStruct(
+        "offset" / borsh.I64, "percentage" / borsh.U8
+    )
+    offset: int
+    percentage: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "Milestone":
+        return cls(
+            offset=obj.offset,
+            percentage=obj.percentage,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "offset": self.offset,
+            "percentage": self.percentage,
+        }
+
+    def to_json(self) -> MilestoneJSON:
+        return {
+            "offset": self.offset,
+            "percentage": self.percentage,
+        }
+
+    @classmethod
+    def from_json(cls, obj: MilestoneJSON) -> "Milestone":
+        return cls(
+            offset=obj["offset"],
+            percentage=obj["percentage"],
+        )
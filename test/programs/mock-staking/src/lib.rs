@@ -0,0 +1,335 @@
+//! A mock staking program for testing the merkle distributor staking in the local test harness.
+//!
+//! It implements the `Stake` and `Unstake` instructions the merkle distributor calls with Access
+//! Protocol's layout, a one byte instruction tag followed by the amount, and only records the
+//! staked amount of each stake account, stake pools hold the staked tokens in their vault.
+//! Like Access Protocol, only the owner of a stake account can stake into it, and stake pools
+//! charge their fee in basis points of the amount on top of it.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, Transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+declare_id!("AHPY5uEMBk8x4KwYkKoHUKFeV4rjt7VyCXxULHHG36Wc");
+
+/// Tag of Access Protocol's `Stake` instruction.
+pub const STAKE_TAG: u8 = 4;
+
+/// Tag of Access Protocol's `Unstake` instruction.
+pub const UNSTAKE_TAG: u8 = 5;
+
+#[program]
+pub mod mock_staking {
+    use super::*;
+
+    /// Creates a stake pool holding the staked tokens of a mint in its vault, charging
+    /// fee_basis_points of every stake.
+    #[allow(clippy::result_large_err)]
+    pub fn init_stake_pool(ctx: Context<InitStakePool>, fee_basis_points: u16) -> Result<()> {
+        let stake_pool = &mut ctx.accounts.stake_pool;
+        stake_pool.mint = ctx.accounts.mint.key();
+        stake_pool.vault = ctx.accounts.vault.key();
+        stake_pool.fee_basis_points = fee_basis_points;
+        stake_pool.bump = *ctx.bumps.get("stake_pool").unwrap();
+
+        Ok(())
+    }
+
+    /// Creates a stake account of the owner, which can be a PDA of another program.
+    #[allow(clippy::result_large_err)]
+    pub fn create_stake_account(ctx: Context<CreateStakeAccount>) -> Result<()> {
+        let stake_account = &mut ctx.accounts.stake_account;
+        stake_account.owner = ctx.accounts.owner.key();
+        stake_account.stake_pool = ctx.accounts.stake_pool.key();
+
+        Ok(())
+    }
+
+    /// Handles the `Stake` and `Unstake` instructions, their data is not anchor's but the
+    /// instruction tag followed by the borsh encoded amount.
+    #[allow(clippy::result_large_err)]
+    pub fn fallback(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> Result<()> {
+        let (tag, amount) = match data {
+            [tag, amount @ ..] if amount.len() == 8 => {
+                (*tag, u64::from_le_bytes(amount.try_into().unwrap()))
+            }
+            _ => return Err(ProgramError::InvalidInstructionData.into()),
+        };
+        match tag {
+            STAKE_TAG => stake(program_id, accounts, amount),
+            UNSTAKE_TAG => unstake(program_id, accounts, amount),
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+/// Moves tokens from the source to the vault and adds them to the stake, and the stake pool's
+/// fee on top of them to the fee account. The owner of the stake account has to sign and be the
+/// owner or delegate of the source.
+/// The accounts are the central state, stake account, stake pool, owner, source, token program,
+/// vault and fee account.
+#[allow(clippy::result_large_err)]
+fn stake<'info>(program_id: &Pubkey, accounts: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+    let [central_state, stake_account_info, stake_pool_info, owner, source, token_program, vault, fee_account] =
+        accounts
+    else {
+        return Err(ErrorCode::AccountNotEnoughKeys.into());
+    };
+    check_central_state(program_id, central_state)?;
+    let stake_pool = load_stake_pool(stake_pool_info, vault)?;
+    let mut stake_account = load_stake_account(stake_account_info, stake_pool_info)?;
+    require!(owner.is_signer, ErrorCode::AccountNotSigner);
+    require_keys_eq!(
+        stake_account.owner,
+        owner.key(),
+        MockStakingError::InvalidOwner
+    );
+    let token_program = Program::<Token>::try_from(token_program)?;
+    let source_account = InterfaceAccount::<TokenAccount>::try_from(source)?;
+    require_keys_eq!(
+        source_account.mint,
+        stake_pool.mint,
+        MockStakingError::InvalidMint
+    );
+    let fee_token_account = InterfaceAccount::<TokenAccount>::try_from(fee_account)?;
+    require_keys_eq!(
+        fee_token_account.mint,
+        stake_pool.mint,
+        MockStakingError::InvalidMint
+    );
+    let fee = ((amount as u128) * (stake_pool.fee_basis_points as u128) / 10_000) as u64;
+
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: source.clone(),
+                to: vault.clone(),
+                authority: owner.clone(),
+            },
+        ),
+        amount,
+    )?;
+    if fee > 0 {
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: source.clone(),
+                    to: fee_account.clone(),
+                    authority: owner.clone(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    stake_account.stake_amount = stake_account
+        .stake_amount
+        .checked_add(amount)
+        .ok_or(MockStakingError::ArithmeticError)?;
+    stake_account.exit(program_id)?;
+
+    msg!("Staked {} with a fee of {}", amount, fee);
+
+    Ok(())
+}
+
+/// Moves tokens from the vault back to the destination and subtracts them from the stake, the
+/// owner of the stake account has to sign.
+/// The accounts are the central state, stake account, stake pool, owner, destination, token
+/// program and vault.
+#[allow(clippy::result_large_err)]
+fn unstake<'info>(program_id: &Pubkey, accounts: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+    let [central_state, stake_account_info, stake_pool_info, owner, destination, token_program, vault] =
+        accounts
+    else {
+        return Err(ErrorCode::AccountNotEnoughKeys.into());
+    };
+    check_central_state(program_id, central_state)?;
+    let stake_pool = load_stake_pool(stake_pool_info, vault)?;
+    let mut stake_account = load_stake_account(stake_account_info, stake_pool_info)?;
+    require!(owner.is_signer, ErrorCode::AccountNotSigner);
+    require_keys_eq!(
+        stake_account.owner,
+        owner.key(),
+        MockStakingError::InvalidOwner
+    );
+    let token_program = Program::<Token>::try_from(token_program)?;
+    let destination_account = InterfaceAccount::<TokenAccount>::try_from(destination)?;
+    require_keys_eq!(
+        destination_account.mint,
+        stake_pool.mint,
+        MockStakingError::InvalidMint
+    );
+
+    stake_account.stake_amount = stake_account
+        .stake_amount
+        .checked_sub(amount)
+        .ok_or(MockStakingError::ArithmeticError)?;
+    stake_account.exit(program_id)?;
+
+    let seeds = [
+        b"StakePool".as_ref(),
+        stake_pool.mint.as_ref(),
+        &[stake_pool.bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault.clone(),
+                to: destination.clone(),
+                authority: stake_pool_info.clone(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )?;
+
+    msg!("Unstaked {}", amount);
+
+    Ok(())
+}
+
+/// The central state is a PDA of the program id, the mock keeps no state in it.
+#[allow(clippy::result_large_err)]
+fn check_central_state(program_id: &Pubkey, central_state: &AccountInfo) -> Result<()> {
+    let (expected, _bump) = Pubkey::find_program_address(&[program_id.as_ref()], program_id);
+    require_keys_eq!(
+        central_state.key(),
+        expected,
+        MockStakingError::InvalidCentralState
+    );
+
+    Ok(())
+}
+
+#[allow(clippy::result_large_err)]
+fn load_stake_pool<'info>(
+    stake_pool: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+) -> Result<Account<'info, StakePool>> {
+    let stake_pool = Account::<StakePool>::try_from(stake_pool)?;
+    require_keys_eq!(
+        stake_pool.vault,
+        vault.key(),
+        MockStakingError::InvalidVault
+    );
+
+    Ok(stake_pool)
+}
+
+#[allow(clippy::result_large_err)]
+fn load_stake_account<'info>(
+    stake_account: &AccountInfo<'info>,
+    stake_pool: &AccountInfo<'info>,
+) -> Result<Account<'info, StakeAccount>> {
+    require!(stake_account.is_writable, ErrorCode::AccountNotMutable);
+    let stake_account = Account::<StakeAccount>::try_from(stake_account)?;
+    require_keys_eq!(
+        stake_account.stake_pool,
+        stake_pool.key(),
+        MockStakingError::InvalidStakePool
+    );
+
+    Ok(stake_account)
+}
+
+#[derive(Accounts)]
+pub struct InitStakePool<'info> {
+    #[account(
+        init,
+        seeds = [b"StakePool".as_ref(), mint.key().as_ref()],
+        bump,
+        space = StakePool::LEN,
+        payer = payer
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        token::mint = mint,
+        token::authority = stake_pool,
+        token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateStakeAccount<'info> {
+    #[account(
+        init,
+        seeds = [
+            b"stake_account".as_ref(),
+            owner.key().as_ref(),
+            stake_pool.key().as_ref()
+        ],
+        bump,
+        space = StakeAccount::LEN,
+        payer = payer
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// CHECK: any wallet or PDA can own a stake account
+    pub owner: UncheckedAccount<'info>,
+
+    pub stake_pool: Account<'info, StakePool>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct StakePool {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub fee_basis_points: u16,
+    pub bump: u8,
+}
+
+impl StakePool {
+    pub const LEN: usize = 8 + std::mem::size_of::<StakePool>();
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct StakeAccount {
+    pub owner: Pubkey,
+    pub stake_pool: Pubkey,
+    pub stake_amount: u64,
+}
+
+impl StakeAccount {
+    pub const LEN: usize = 8 + std::mem::size_of::<StakeAccount>();
+}
+
+#[error_code]
+pub enum MockStakingError {
+    #[msg("Arithmetic Error (overflow/underflow)")]
+    ArithmeticError,
+    #[msg("Invalid central state")]
+    InvalidCentralState,
+    #[msg("Invalid stake pool vault")]
+    InvalidVault,
+    #[msg("Stake account of another stake pool")]
+    InvalidStakePool,
+    #[msg("Token account of another mint")]
+    InvalidMint,
+    #[msg("Only the owner of the stake account can stake and unstake")]
+    InvalidOwner,
+}
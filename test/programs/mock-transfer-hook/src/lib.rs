@@ -0,0 +1,148 @@
+//! A mock Token-2022 transfer hook program for testing transfer hook mints through the merkle
+//! distributor in the local test harness.
+//!
+//! Every transfer of a mint using it as its transfer hook requires one extra account, a counter
+//! PDA of the mint, which records the number and total amount of transfers.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address,
+    instruction::{ExecuteInstruction, TransferHookInstruction},
+};
+
+declare_id!("H28i597XTB2oFTJyMk1K6kx8vU6j1ML9VRQT92nHuUvK");
+
+#[program]
+pub mod mock_transfer_hook {
+    use super::*;
+
+    /// Creates the extra account metas of a mint, requiring its counter in every transfer.
+    #[allow(clippy::result_large_err)]
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        let extra_account_metas = [ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: b"Counter".to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            true,
+        )?];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+
+        ctx.accounts.counter.mint = ctx.accounts.mint.key();
+
+        Ok(())
+    }
+
+    /// Handles the transfer hook interface `execute` instruction Token-2022 invokes on transfers,
+    /// its discriminator is not an anchor one.
+    #[allow(clippy::result_large_err)]
+    pub fn fallback(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => execute(program_id, accounts, amount),
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+/// Counts a transfer, the accounts are the source, mint, destination, authority, extra account
+/// metas and counter.
+#[allow(clippy::result_large_err)]
+fn execute(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> Result<()> {
+    let [_source, mint, _destination, _authority, extra_account_meta_list, counter_info] = accounts
+    else {
+        return Err(ErrorCode::AccountNotEnoughKeys.into());
+    };
+    require_keys_eq!(
+        extra_account_meta_list.key(),
+        get_extra_account_metas_address(mint.key, program_id),
+        MockTransferHookError::InvalidExtraAccountMetas
+    );
+
+    require_keys_eq!(
+        *counter_info.owner,
+        *program_id,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+    let mut counter = Counter::try_deserialize(&mut &counter_info.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        counter.mint,
+        mint.key(),
+        MockTransferHookError::InvalidCounter
+    );
+    counter.transfers = counter
+        .transfers
+        .checked_add(1)
+        .ok_or(MockTransferHookError::ArithmeticError)?;
+    counter.amount = counter
+        .amount
+        .checked_add(amount)
+        .ok_or(MockTransferHookError::ArithmeticError)?;
+    counter.try_serialize(&mut &mut counter_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Transfer hook executed for {}", amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// CHECK: the extra account metas PDA of the mint, initialized as a TLV account
+    #[account(
+        init,
+        seeds = [b"extra-account-metas".as_ref(), mint.key().as_ref()],
+        bump,
+        space = ExtraAccountMetaList::size_of(1)?,
+        payer = payer
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [b"Counter".as_ref(), mint.key().as_ref()],
+        bump,
+        space = Counter::LEN,
+        payer = payer
+    )]
+    pub counter: Account<'info, Counter>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct Counter {
+    pub mint: Pubkey,
+    pub transfers: u64,
+    pub amount: u64,
+}
+
+impl Counter {
+    pub const LEN: usize = 8 + std::mem::size_of::<Counter>();
+}
+
+#[error_code]
+pub enum MockTransferHookError {
+    #[msg("Arithmetic Error (overflow/underflow)")]
+    ArithmeticError,
+    #[msg("Extra account metas are not the mint's")]
+    InvalidExtraAccountMetas,
+    #[msg("Counter is not the mint's")]
+    InvalidCounter,
+}
@@ -1,4 +1,6 @@
-from typing import List
+from itertools import count
+import struct
+from typing import List, Optional
 
 from solders.account import Account
 from solders.bankrun import ProgramTestContext
@@ -6,13 +8,73 @@ from solders.instruction import Instruction
 from solders.message import Message
 from solders.token.associated import get_associated_token_address
 from dataclasses import dataclass
-from time import time
 
 from client_py.instructions.new_distributor import new_distributor
 from merkle_tree import MerkleTree
-from test_utils import get_distributor_pda
+from test_utils import (
+    get_distributor_pda,
+    get_sol_distributor_pda,
+    get_sol_vault_pda,
+    get_leaf_hash,
+    get_indexed_leaf_hash,
+    get_stake_pool_pda,
+    get_stake_account_pda,
+    get_central_state_pda,
+    get_stake_amount,
+    init_stake_pool_ix,
+    create_stake_account_ix,
+    MOCK_STAKING_PROGRAM,
+    MOCK_STAKING_PROGRAM_ID,
+    MOCK_TRANSFER_HOOK_PROGRAM,
+    MOCK_TRANSFER_HOOK_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
+    get_associated_token_address_2022,
+    get_transfer_hook_counter,
+    get_transfer_hook_pdas,
+    get_withheld_amount,
+    initialize_extra_account_meta_list_ix,
+    transfer_fee_amount,
+    transfer_fee_config,
+    transfer_hook,
+    transfer_hook_account,
+    transfer_hook_accounts,
+    with_extensions,
+)
+from client_py.instructions.fund_distributor import fund_distributor
+from client_py.instructions.set_staking_program import set_staking_program
+from client_py.instructions.new_claim_and_stake import new_claim_and_stake
+from client_py.instructions.stake_locked import stake_locked
+from client_py.instructions.unstake_locked import unstake_locked
+from client_py.instructions.new_claim import new_claim
+from client_py.instructions.claim_locked import claim_locked
+from client_py.instructions.close_claim_status import close_claim_status
+from client_py.instructions.pause import pause
+from client_py.instructions.unpause import unpause
+from client_py.instructions.propose_admin import propose_admin
+from client_py.instructions.accept_admin import accept_admin
+from client_py.instructions.cancel_admin_proposal import cancel_admin_proposal
+from client_py.instructions.set_permissionless_claims import set_permissionless_claims
+from client_py.instructions.init_claim_bitmap import init_claim_bitmap
+from client_py.instructions.new_claim_bitmap import new_claim_bitmap
+from client_py.accounts.merkle_distributor import MerkleDistributor
+from client_py.types.vesting_mode import Linear
 from client_py.instructions.clawback import clawback
+from client_py.instructions.clawback_unclaimed import clawback_unclaimed
+from client_py.instructions.revoke_claim import revoke_claim
+from client_py.instructions.transfer_claim import transfer_claim
+from client_py.instructions.recover_claim import recover_claim
+from client_py.instructions.new_sol_distributor import new_sol_distributor
+from client_py.instructions.fund_sol_distributor import fund_sol_distributor
+from client_py.instructions.new_claim_sol import new_claim_sol
+from client_py.instructions.claim_locked_sol import claim_locked_sol
+from client_py.instructions.clawback_sol import clawback_sol
+from client_py.instructions.deposit_custody_sol import deposit_custody_sol
+from client_py.instructions.withdraw_custody_sol import withdraw_custody_sol
+from client_py.accounts.sol_custody import SolCustody
+from client_py.instructions.migrate_distributor import migrate_distributor
+from client_py.instructions.set_clawback_receiver import set_clawback_receiver
 from solders.clock import Clock
+from solders.compute_budget import set_compute_unit_price
 
 from solders.transaction import TransactionError, VersionedTransaction
 from client_py.program_id import PROGRAM_ID
@@ -21,11 +83,13 @@ from spl.token.instructions import create_associated_token_account
 
 from pathlib import Path
 from pytest import mark, raises
-from solders.bankrun import start_anchor
+from solders.bankrun import BanksClientError, start_anchor
 from solders.pubkey import Pubkey
 from solders.keypair import Keypair
 
 TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+SYSTEM_PROGRAM_ID = Pubkey.from_string("11111111111111111111111111111111")
+NATIVE_MINT = Pubkey.from_string("So11111111111111111111111111111111111111112")
 
 
 @dataclass
@@ -90,6 +154,26 @@ async def init_test_accounts(program_id) -> TestHook:
     )
 
 
+def new_distributor_args(curr_ts: int) -> dict:
+    """Arguments of a linear vesting distributor starting in the future"""
+    return {
+        "version": 0,
+        "root": [0] * 32,
+        "max_total_claim": 100_00_00,
+        "max_num_nodes": 1,
+        "start_vesting_ts": curr_ts + 100000,
+        "end_vesting_ts": curr_ts + 200000,
+        "cliff_vesting_ts": curr_ts + 100000,
+        "cliff_percentage": 0,
+        "vesting_mode": Linear(),
+        "clawback_start_ts": curr_ts + 200000 + 24 * 3600,
+        "claim_bitmap": False,
+        "claim_end_ts": curr_ts + 200000,
+        "revocable": False,
+        "recoverable": False,
+    }
+
+
 @mark.asyncio
 async def test_new_distributor():
     """Test that a new distributor can successfully be created"""
@@ -108,19 +192,16 @@ async def test_new_distributor():
 
     curr_ts = await context.banks_client.get_clock()
     distributor = new_distributor(
-        {
-            "version": 0,
-            "root": [0] * 32,
-            "max_total_claim": 100_00_00,
-            "max_num_nodes": 1,
-            "start_vesting_ts": curr_ts.unix_timestamp + 100000,
-            "end_vesting_ts": curr_ts.unix_timestamp + 200000,
-        },
+        new_distributor_args(curr_ts.unix_timestamp),
         {
             "distributor": distributor,
             "token_vault": get_associated_token_address(distributor, mint),
             "mint": mint,
+            "clawback_receiver": get_associated_token_address(
+                testhook.clawback_keypair.pubkey(), mint
+            ),
             "creator": payer.pubkey(),
+            "token_program": TOKEN_PROGRAM_ID,
         },
     )
 
@@ -154,38 +235,49 @@ async def test_clawback():
     )
     
     curr_ts = await context.banks_client.get_clock()
+    args = new_distributor_args(curr_ts.unix_timestamp)
     new_distributor_ix = new_distributor(
-        {
-            "version": 0,
-            "root": [0] * 32,
-            "max_total_claim": 100_00_00,
-            "max_num_nodes": 1,
-            "start_vesting_ts": curr_ts.unix_timestamp + 100000,
-            "end_vesting_ts": curr_ts.unix_timestamp + 200000,
-        },
+        args,
         {
             "distributor": distributor,
             "token_vault": get_associated_token_address(distributor, mint),
             "mint": mint,
+            "clawback_receiver": clawback_address,
             "creator": payer.pubkey(),
+            "token_program": TOKEN_PROGRAM_ID,
         },
     )
 
+    client = context.banks_client
+    msg = Message.new_with_blockhash(
+        [ata_creation_ix, new_distributor_ix], payer.pubkey(), context.last_blockhash
+    )
+    await client.process_transaction(VersionedTransaction(msg, [payer]))
+
+    # clawing back is only possible once the clawback start has passed
+    context.set_clock(
+        Clock(
+            slot=curr_ts.slot,
+            epoch_start_timestamp=curr_ts.epoch_start_timestamp,
+            epoch=curr_ts.epoch,
+            leader_schedule_epoch=curr_ts.leader_schedule_epoch,
+            unix_timestamp=args["clawback_start_ts"],
+        )
+    )
+
     clawback_ix = clawback(
         {
             "distributor": distributor,
             "from_": distributor_ata,
             "to": clawback_address,
             "admin": payer.pubkey(),
+            "mint": mint,
+            "token_program": TOKEN_PROGRAM_ID,
         }
     )
 
-    ixs = [ata_creation_ix, new_distributor_ix, clawback_ix]
-    blockhash = context.last_blockhash
-    msg = Message.new_with_blockhash(ixs, payer.pubkey(), blockhash)
-    tx = VersionedTransaction(msg, [payer])
-    client = context.banks_client
-    await client.process_transaction(tx)
+    msg = Message.new_with_blockhash([clawback_ix], payer.pubkey(), context.last_blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [payer]))
 
 
 async def setup_clawback_test_case() -> (TestHook, List[Instruction]):
@@ -198,27 +290,1503 @@ async def setup_clawback_test_case() -> (TestHook, List[Instruction]):
     # setup distributor
     payer = context.payer
     (distributor, bump) = get_distributor_pda(mint, program_id, payer.pubkey(), 0)
-    curr_ts = int(time())
+    curr_ts = (await context.banks_client.get_clock()).unix_timestamp
 
     new_distributor_ix = new_distributor(
-        {
-            "version": 0,
-            "root": [0] * 32,
-            "max_total_claim": 100_00_00,
-            "max_num_nodes": 1,
-            "start_vesting_ts": curr_ts,
-            "end_vesting_ts": curr_ts + 100_000,
-        },
+        new_distributor_args(curr_ts),
         {
             "distributor": distributor,
             "token_vault": get_associated_token_address(distributor, mint),
             "mint": mint,
+            "clawback_receiver": get_associated_token_address(
+                test_hook.clawback_keypair.pubkey(), mint
+            ),
             "creator": context.payer.pubkey(),
+            "token_program": TOKEN_PROGRAM_ID,
         },
     )
 
     return test_hook, new_distributor_ix
 
+def token_account(mint: Pubkey, owner: Pubkey, amount: int) -> Account:
+    """An initialized SPL token account, to be loaded into the test context"""
+    return Account(
+        lamports=1_000_000_000,
+        data=bytes(
+            TokenAccount(
+                mint=mint,
+                owner=owner,
+                amount=amount,
+                delegate=None,
+                state=TokenAccountState.Initialized,
+                is_native=None,
+                delegated_amount=0,
+                close_authority=None,
+            )
+        ),
+        owner=TOKEN_PROGRAM_ID,
+        executable=False,
+    )
+
+
+@mark.asyncio
+async def test_new_claim_and_stake():
+    """Test that claimed tokens are staked into the claimant's stake account of the mock staking program"""
+    amount = 1_000
+    mint = Pubkey.new_unique()
+    claimant = Keypair()
+    clawback_keypair = Keypair()
+    claimant_ata = get_associated_token_address(claimant.pubkey(), mint)
+    clawback_ata = get_associated_token_address(clawback_keypair.pubkey(), mint)
+    (stake_pool, _) = get_stake_pool_pda(mint)
+    stake_vault = get_associated_token_address(stake_pool, mint)
+    (stake_account, _) = get_stake_account_pda(claimant.pubkey(), stake_pool)
+    (central_state, _) = get_central_state_pda()
+    fee_account = get_associated_token_address(central_state, mint)
+
+    # the claimant creates and funds the distributor, the claim stakes its tokens from the vault
+    accounts = [
+        (
+            mint,
+            Account(
+                data=bytes(
+                    Mint(
+                        decimals=9,
+                        mint_authority=None,
+                        supply=amount,
+                        is_initialized=True,
+                    )
+                ),
+                lamports=100_000,
+                owner=TOKEN_PROGRAM_ID,
+            ),
+        ),
+        (
+            claimant.pubkey(),
+            Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID),
+        ),
+        (claimant_ata, token_account(mint, claimant.pubkey(), amount)),
+        (clawback_ata, token_account(mint, clawback_keypair.pubkey(), 0)),
+        (stake_vault, token_account(mint, stake_pool, 0)),
+        (fee_account, token_account(mint, central_state, 0)),
+    ]
+    context = await start_anchor(
+        Path("../"), extra_programs=[MOCK_STAKING_PROGRAM], accounts=accounts
+    )
+    client = context.banks_client
+    payer = context.payer
+
+    (distributor, _) = get_distributor_pda(mint, PROGRAM_ID, claimant.pubkey(), 0)
+    distributor_ata = get_associated_token_address(distributor, mint)
+    curr_ts = (await client.get_clock()).unix_timestamp
+    setup_ixs = [
+        create_associated_token_account(
+            payer=payer.pubkey(), owner=distributor, mint=mint
+        ),
+        new_distributor(
+            {
+                "version": 0,
+                "root": list(get_leaf_hash(claimant.pubkey(), amount, 0)),
+                "max_total_claim": amount,
+                "max_num_nodes": 1,
+                "start_vesting_ts": curr_ts + 100,
+                "end_vesting_ts": curr_ts + 200,
+                "cliff_vesting_ts": curr_ts + 100,
+                "cliff_percentage": 0,
+                "vesting_mode": Linear(),
+                "clawback_start_ts": curr_ts + 200 + 24 * 3600,
+                "claim_bitmap": False,
+                "claim_end_ts": curr_ts + 200,
+                "revocable": False,
+                "recoverable": False,
+            },
+            {
+                "distributor": distributor,
+                "mint": mint,
+                "token_vault": distributor_ata,
+                "clawback_receiver": clawback_ata,
+                "creator": claimant.pubkey(),
+                "token_program": TOKEN_PROGRAM_ID,
+            },
+        ),
+        fund_distributor(
+            {"amount": amount},
+            {
+                "distributor": distributor,
+                "token_vault": distributor_ata,
+                "from_": claimant_ata,
+                "creator": claimant.pubkey(),
+                "mint": mint,
+                "token_program": TOKEN_PROGRAM_ID,
+            },
+        ),
+        init_stake_pool_ix(
+            stake_pool, mint, stake_vault, payer.pubkey(), TOKEN_PROGRAM_ID
+        ),
+        create_stake_account_ix(
+            stake_account, claimant.pubkey(), stake_pool, payer.pubkey()
+        ),
+        set_staking_program(
+            {
+                "distributor": distributor,
+                "staking_program": MOCK_STAKING_PROGRAM_ID,
+                "stake_pool": stake_pool,
+                "admin": claimant.pubkey(),
+            }
+        ),
+    ]
+    msg = Message.new_with_blockhash(setup_ixs, payer.pubkey(), context.last_blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [payer, claimant]))
+
+    (claim_status, _) = Pubkey.find_program_address(
+        [b"ClaimStatus", bytes(claimant.pubkey()), bytes(distributor)], PROGRAM_ID
+    )
+    claim_accounts = {
+        "claim": {
+            "distributor": distributor,
+            "claim_status": claim_status,
+            "from_": distributor_ata,
+            "to": claimant_ata,
+            "claimant": claimant.pubkey(),
+            "payer": payer.pubkey(),
+            "mint": mint,
+            "token_program": TOKEN_PROGRAM_ID,
+        },
+        "central_state": central_state,
+        "stake_pool": stake_pool,
+        "stake_account": stake_account,
+        "stake_vault": stake_vault,
+        "fee_account": fee_account,
+        "staking_program": MOCK_STAKING_PROGRAM_ID,
+    }
+    claim_args = {
+        "amount_unlocked": amount,
+        "amount_locked": 0,
+        "start_vesting_ts": None,
+        "end_vesting_ts": None,
+        "proof": [],
+        "extra_amounts": [],
+    }
+
+    # only the claimant's own stake account can receive the claim
+    (other_stake_account, _) = get_stake_account_pda(payer.pubkey(), stake_pool)
+    with raises(BanksClientError):
+        ix = new_claim_and_stake(
+            claim_args, {**claim_accounts, "stake_account": other_stake_account}
+        )
+        await send(context, [ix], [claimant])
+
+    await send(context, [new_claim_and_stake(claim_args, claim_accounts)], [claimant])
+
+    # the claimed tokens went straight from the distributor's vault into the stake pool vault
+    stake_account_data = (await client.get_account(stake_account)).data
+    assert get_stake_amount(stake_account_data) == amount
+    assert TokenAccount.from_bytes((await client.get_account(stake_vault)).data).amount == amount
+    assert TokenAccount.from_bytes((await client.get_account(claimant_ata)).data).amount == 0
+
+
+def token_2022_mint(supply: int, extension: tuple) -> Account:
+    """An initialized Token-2022 mint with one extension, to be loaded into the test context"""
+    mint = Mint(decimals=9, mint_authority=None, supply=supply, is_initialized=True)
+    return Account(
+        lamports=1_000_000_000,
+        data=with_extensions(bytes(mint), 1, [extension]),
+        owner=TOKEN_2022_PROGRAM_ID,
+        executable=False,
+    )
+
+
+def token_2022_account(mint: Pubkey, owner: Pubkey, amount: int, extension: tuple) -> Account:
+    """An initialized Token-2022 account with one extension, to be loaded into the test context"""
+    account = TokenAccount(
+        mint=mint,
+        owner=owner,
+        amount=amount,
+        delegate=None,
+        state=TokenAccountState.Initialized,
+        is_native=None,
+        delegated_amount=0,
+        close_authority=None,
+    )
+    return Account(
+        lamports=1_000_000_000,
+        data=with_extensions(bytes(account), 2, [extension]),
+        owner=TOKEN_2022_PROGRAM_ID,
+        executable=False,
+    )
+
+
+def token_2022_distributor(
+    distributor: Pubkey,
+    mint: Pubkey,
+    creator: Pubkey,
+    claimant: Pubkey,
+    amount: int,
+    curr_ts: int,
+) -> (Instruction, dict):
+    """The new distributor instruction of a Token-2022 mint with a single leaf, and the accounts to claim it"""
+    distributor_ata = get_associated_token_address_2022(distributor, mint)
+    new_distributor_ix = new_distributor(
+        {
+            **new_distributor_args(curr_ts),
+            "root": list(get_leaf_hash(claimant, amount, 0)),
+            "max_total_claim": amount,
+        },
+        {
+            "distributor": distributor,
+            "mint": mint,
+            "token_vault": distributor_ata,
+            "clawback_receiver": get_associated_token_address_2022(creator, mint),
+            "creator": creator,
+            "token_program": TOKEN_2022_PROGRAM_ID,
+        },
+    )
+    (claim_status, _) = Pubkey.find_program_address(
+        [b"ClaimStatus", bytes(claimant), bytes(distributor)], PROGRAM_ID
+    )
+    claim_accounts = {
+        "distributor": distributor,
+        "claim_status": claim_status,
+        "from_": distributor_ata,
+        "to": get_associated_token_address_2022(claimant, mint),
+        "claimant": claimant,
+        "payer": claimant,
+        "mint": mint,
+        "token_program": TOKEN_2022_PROGRAM_ID,
+    }
+    return new_distributor_ix, claim_accounts
+
+
+def fund_distributor_ix(
+    distributor: Pubkey, mint: Pubkey, creator: Pubkey, amount: int, remaining_accounts=None
+) -> Instruction:
+    return fund_distributor(
+        {"amount": amount},
+        {
+            "distributor": distributor,
+            "token_vault": get_associated_token_address_2022(distributor, mint),
+            "from_": get_associated_token_address_2022(creator, mint),
+            "creator": creator,
+            "mint": mint,
+            "token_program": TOKEN_2022_PROGRAM_ID,
+        },
+        remaining_accounts=remaining_accounts,
+    )
+
+
+def claim_args(amount: int) -> dict:
+    return {
+        "amount_unlocked": amount,
+        "amount_locked": 0,
+        "start_vesting_ts": None,
+        "end_vesting_ts": None,
+        "proof": [],
+        "extra_amounts": [],
+    }
+
+
+@dataclass
+class Airdrop:
+    """A funded SPL token distributor with a single leaf, and the accounts to claim it"""
+    context: ProgramTestContext
+    creator: Keypair
+    claimant: Keypair
+    mint: Pubkey
+    distributor: Pubkey
+    distributor_ata: Pubkey
+    claimant_ata: Pubkey
+    clawback_ata: Pubkey
+    claim_status: Pubkey
+    args: dict
+    claim_accounts: dict
+
+
+# compute unit prices making every transaction unique, so a retry isn't rejected as a duplicate
+transaction_nonce = count(1)
+
+
+async def send(context: ProgramTestContext, ixs: List[Instruction], signers: List[Keypair] = []):
+    """Process a transaction paid by the test context payer"""
+    ixs = [set_compute_unit_price(next(transaction_nonce)), *ixs]
+    msg = Message.new_with_blockhash(ixs, context.payer.pubkey(), context.last_blockhash)
+    await context.banks_client.process_transaction(
+        VersionedTransaction(msg, [context.payer, *signers])
+    )
+
+
+async def set_time(context: ProgramTestContext, unix_timestamp: int):
+    """Move the clock of the test context to unix_timestamp"""
+    clock = await context.banks_client.get_clock()
+    context.set_clock(
+        Clock(
+            slot=clock.slot,
+            epoch_start_timestamp=clock.epoch_start_timestamp,
+            epoch=clock.epoch,
+            leader_schedule_epoch=clock.leader_schedule_epoch,
+            unix_timestamp=unix_timestamp,
+        )
+    )
+
+
+async def single_leaf_airdrop(
+    amount_unlocked: int,
+    amount_locked: int,
+    funded: bool = True,
+    accounts: list = [],
+    extra_programs: list = [],
+    index: Optional[int] = None,
+    **args,
+) -> Airdrop:
+    """Create a distributor of a new SPL token mint paying a single claimant, funded by its creator
+    unless funded is unset. The leaf is bitmap tracked at index if set. args override the
+    distributor arguments."""
+    amount = amount_unlocked + amount_locked
+    mint = Pubkey.new_unique()
+    creator = Keypair()
+    claimant = Keypair()
+    (distributor, _) = get_distributor_pda(mint, PROGRAM_ID, creator.pubkey(), 0)
+    distributor_ata = get_associated_token_address(distributor, mint)
+    creator_ata = get_associated_token_address(creator.pubkey(), mint)
+    claimant_ata = get_associated_token_address(claimant.pubkey(), mint)
+    clawback_ata = get_associated_token_address(Pubkey.new_unique(), mint)
+
+    mint_account = Account(
+        data=bytes(Mint(decimals=9, mint_authority=None, supply=amount, is_initialized=True)),
+        lamports=100_000,
+        owner=TOKEN_PROGRAM_ID,
+    )
+    wallet = Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID)
+    context = await start_anchor(
+        Path("../"),
+        extra_programs=extra_programs,
+        accounts=[
+            (mint, mint_account),
+            (creator.pubkey(), wallet),
+            (claimant.pubkey(), wallet),
+            (creator_ata, token_account(mint, creator.pubkey(), amount)),
+            (distributor_ata, token_account(mint, distributor, 0)),
+            (claimant_ata, token_account(mint, claimant.pubkey(), 0)),
+            (clawback_ata, token_account(mint, Pubkey.new_unique(), 0)),
+            *accounts,
+        ],
+    )
+
+    curr_ts = (await context.banks_client.get_clock()).unix_timestamp
+    leaf = (
+        get_leaf_hash(claimant.pubkey(), amount_unlocked, amount_locked)
+        if index is None
+        else get_indexed_leaf_hash(claimant.pubkey(), amount_unlocked, index)
+    )
+    args = {
+        **new_distributor_args(curr_ts),
+        "root": list(leaf),
+        "max_total_claim": amount,
+        "claim_bitmap": index is not None,
+        **args,
+    }
+    ixs = [
+        new_distributor(
+            args,
+            {
+                "distributor": distributor,
+                "mint": mint,
+                "token_vault": distributor_ata,
+                "clawback_receiver": clawback_ata,
+                "creator": creator.pubkey(),
+                "token_program": TOKEN_PROGRAM_ID,
+            },
+        )
+    ]
+    if funded:
+        ixs.append(
+            fund_distributor(
+                {"amount": amount},
+                {
+                    "distributor": distributor,
+                    "token_vault": distributor_ata,
+                    "from_": creator_ata,
+                    "creator": creator.pubkey(),
+                    "mint": mint,
+                    "token_program": TOKEN_PROGRAM_ID,
+                },
+            )
+        )
+    await send(context, ixs, [creator])
+
+    (claim_status, _) = Pubkey.find_program_address(
+        [b"ClaimStatus", bytes(claimant.pubkey()), bytes(distributor)], PROGRAM_ID
+    )
+    return Airdrop(
+        context=context,
+        creator=creator,
+        claimant=claimant,
+        mint=mint,
+        distributor=distributor,
+        distributor_ata=distributor_ata,
+        claimant_ata=claimant_ata,
+        clawback_ata=clawback_ata,
+        claim_status=claim_status,
+        args=args,
+        claim_accounts={
+            "distributor": distributor,
+            "claim_status": claim_status,
+            "from_": distributor_ata,
+            "to": claimant_ata,
+            "claimant": claimant.pubkey(),
+            "payer": claimant.pubkey(),
+            "mint": mint,
+            "token_program": TOKEN_PROGRAM_ID,
+        },
+    )
+
+
+async def token_amount(context: ProgramTestContext, token_account: Pubkey) -> int:
+    account = await context.banks_client.get_account(token_account)
+    return TokenAccount.from_bytes(account.data).amount
+
+
+async def get_distributor(airdrop: Airdrop) -> MerkleDistributor:
+    account = await airdrop.context.banks_client.get_account(airdrop.distributor)
+    return MerkleDistributor.decode(account.data)
+
+
+def clawback_accounts(airdrop: Airdrop) -> dict:
+    return {
+        "distributor": airdrop.distributor,
+        "from_": airdrop.distributor_ata,
+        "to": airdrop.clawback_ata,
+        "admin": airdrop.creator.pubkey(),
+        "mint": airdrop.mint,
+        "token_program": TOKEN_PROGRAM_ID,
+    }
+
+
+@mark.asyncio
+async def test_transfer_fee_mint():
+    """Test that the vault is funded and claims are paid net of a Token-2022 transfer fee"""
+    amount = 1_000_000
+    # 1% fee, the claim pays 10_000 of it
+    fee_config = transfer_fee_config(100, 1_000_000_000)
+    mint = Pubkey.new_unique()
+    creator = Keypair()
+    claimant = Keypair()
+    (distributor, _) = get_distributor_pda(mint, PROGRAM_ID, creator.pubkey(), 0)
+    distributor_ata = get_associated_token_address_2022(distributor, mint)
+    claimant_ata = get_associated_token_address_2022(claimant.pubkey(), mint)
+
+    accounts = [
+        (mint, token_2022_mint(2 * amount, fee_config)),
+        (
+            creator.pubkey(),
+            Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID),
+        ),
+        (
+            claimant.pubkey(),
+            Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID),
+        ),
+        (
+            get_associated_token_address_2022(creator.pubkey(), mint),
+            token_2022_account(mint, creator.pubkey(), 2 * amount, transfer_fee_amount()),
+        ),
+        (distributor_ata, token_2022_account(mint, distributor, 0, transfer_fee_amount())),
+        (claimant_ata, token_2022_account(mint, claimant.pubkey(), 0, transfer_fee_amount())),
+    ]
+    context = await start_anchor(Path("../"), accounts=accounts)
+    client = context.banks_client
+    payer = context.payer
+
+    curr_ts = (await client.get_clock()).unix_timestamp
+    (new_distributor_ix, claim_accounts) = token_2022_distributor(
+        distributor, mint, creator.pubkey(), claimant.pubkey(), amount, curr_ts
+    )
+    msg = Message.new_with_blockhash(
+        [
+            new_distributor_ix,
+            fund_distributor_ix(distributor, mint, creator.pubkey(), amount),
+        ],
+        payer.pubkey(),
+        context.last_blockhash,
+    )
+    await client.process_transaction(VersionedTransaction(msg, [payer, creator]))
+
+    # the vault received the amount net of the fee, which leaves it short of the claim
+    distributor_account = MerkleDistributor.decode((await client.get_account(distributor)).data)
+    assert distributor_account.total_funded == amount - 10_000
+    assert not distributor_account.funded
+    vault_data = (await client.get_account(distributor_ata)).data
+    assert TokenAccount.from_bytes(vault_data[:165]).amount == amount - 10_000
+    assert get_withheld_amount(vault_data) == 10_000
+
+    # 10_102 pays a fee of 102 and covers the shortfall
+    msg = Message.new_with_blockhash(
+        [fund_distributor_ix(distributor, mint, creator.pubkey(), 10_102)],
+        payer.pubkey(),
+        context.last_blockhash,
+    )
+    await client.process_transaction(VersionedTransaction(msg, [payer, creator]))
+    distributor_account = MerkleDistributor.decode((await client.get_account(distributor)).data)
+    assert distributor_account.total_funded == amount
+    assert distributor_account.funded
+
+    claim_ix = new_claim(claim_args(amount), claim_accounts)
+    msg = Message.new_with_blockhash([claim_ix], claimant.pubkey(), context.last_blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [claimant]))
+
+    # the claim spent the full amount from the vault, the claimant received it net of the fee
+    vault_data = (await client.get_account(distributor_ata)).data
+    assert TokenAccount.from_bytes(vault_data[:165]).amount == 0
+    claimant_data = (await client.get_account(claimant_ata)).data
+    assert TokenAccount.from_bytes(claimant_data[:165]).amount == amount - 10_000
+    assert get_withheld_amount(claimant_data) == 10_000
+    distributor_account = MerkleDistributor.decode((await client.get_account(distributor)).data)
+    assert distributor_account.total_amount_claimed == amount
+
+
+@mark.asyncio
+async def test_transfer_hook_mint():
+    """Test that funding and claims of a Token-2022 transfer hook mint invoke the hook"""
+    amount = 1_000
+    mint = Pubkey.new_unique()
+    creator = Keypair()
+    claimant = Keypair()
+    (distributor, _) = get_distributor_pda(mint, PROGRAM_ID, creator.pubkey(), 0)
+    distributor_ata = get_associated_token_address_2022(distributor, mint)
+    claimant_ata = get_associated_token_address_2022(claimant.pubkey(), mint)
+    (_, counter) = get_transfer_hook_pdas(mint)
+
+    accounts = [
+        (mint, token_2022_mint(amount, transfer_hook(MOCK_TRANSFER_HOOK_PROGRAM_ID))),
+        (
+            creator.pubkey(),
+            Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID),
+        ),
+        (
+            claimant.pubkey(),
+            Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID),
+        ),
+        (
+            get_associated_token_address_2022(creator.pubkey(), mint),
+            token_2022_account(mint, creator.pubkey(), amount, transfer_hook_account()),
+        ),
+        (distributor_ata, token_2022_account(mint, distributor, 0, transfer_hook_account())),
+        (claimant_ata, token_2022_account(mint, claimant.pubkey(), 0, transfer_hook_account())),
+    ]
+    context = await start_anchor(
+        Path("../"), extra_programs=[MOCK_TRANSFER_HOOK_PROGRAM], accounts=accounts
+    )
+    client = context.banks_client
+    payer = context.payer
+
+    curr_ts = (await client.get_clock()).unix_timestamp
+    (new_distributor_ix, claim_accounts) = token_2022_distributor(
+        distributor, mint, creator.pubkey(), claimant.pubkey(), amount, curr_ts
+    )
+
+    # the hook accounts are required, a transfer without them fails
+    msg = Message.new_with_blockhash(
+        [
+            new_distributor_ix,
+            fund_distributor_ix(distributor, mint, creator.pubkey(), amount),
+        ],
+        payer.pubkey(),
+        context.last_blockhash,
+    )
+    with raises(BanksClientError):
+        await client.process_transaction(VersionedTransaction(msg, [payer, creator]))
+
+    msg = Message.new_with_blockhash(
+        [
+            initialize_extra_account_meta_list_ix(mint, payer.pubkey()),
+            new_distributor_ix,
+            fund_distributor_ix(
+                distributor,
+                mint,
+                creator.pubkey(),
+                amount,
+                remaining_accounts=transfer_hook_accounts(mint),
+            ),
+        ],
+        payer.pubkey(),
+        context.last_blockhash,
+    )
+    await client.process_transaction(VersionedTransaction(msg, [payer, creator]))
+
+    claim_ix = new_claim(
+        claim_args(amount), claim_accounts, remaining_accounts=transfer_hook_accounts(mint)
+    )
+    msg = Message.new_with_blockhash([claim_ix], claimant.pubkey(), context.last_blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [claimant]))
+
+    # the hook counted the funding and the claim
+    counter_data = (await client.get_account(counter)).data
+    assert get_transfer_hook_counter(counter_data) == (2, 2 * amount)
+    claimant_data = (await client.get_account(claimant_ata)).data
+    assert TokenAccount.from_bytes(claimant_data[:165]).amount == amount
+
+
+@mark.asyncio
+async def test_close_claim_status():
+    """Test that only the claimant closes their withdrawn claim status and its leaf stays claimed"""
+    amount = 1_000
+    airdrop = await single_leaf_airdrop(amount, 0)
+    claimant = airdrop.claimant
+
+    await send(airdrop.context, [new_claim(claim_args(amount), airdrop.claim_accounts)], [claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+
+    # anyone else signing as claimant is rejected
+    other = Keypair()
+    close_accounts = {
+        "distributor": airdrop.distributor,
+        "claim_status": airdrop.claim_status,
+        "claimant": other.pubkey(),
+    }
+    with raises(BanksClientError):
+        await send(airdrop.context, [close_claim_status(close_accounts)], [other])
+
+    rent = (await airdrop.context.banks_client.get_account(airdrop.claim_status)).lamports
+    await send(
+        airdrop.context,
+        [close_claim_status({**close_accounts, "claimant": claimant.pubkey()})],
+        [claimant],
+    )
+    tombstone = await airdrop.context.banks_client.get_account(airdrop.claim_status)
+    assert tombstone.data == b""
+    assert tombstone.lamports < rent
+
+    # the tombstone keeps the leaf from being claimed again
+    claim_ix = new_claim(claim_args(amount), airdrop.claim_accounts)
+    with raises(BanksClientError):
+        await send(airdrop.context, [claim_ix], [claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+
+
+@mark.asyncio
+async def test_pause():
+    """Test that only the admin pauses claims, which resume once unpaused"""
+    amount = 1_000
+    airdrop = await single_leaf_airdrop(amount, 0)
+    admin = airdrop.creator
+    claim_ix = new_claim(claim_args(amount), airdrop.claim_accounts)
+
+    admin_accounts = {"distributor": airdrop.distributor, "admin": admin.pubkey()}
+
+    other = Keypair()
+    with raises(BanksClientError):
+        await send(airdrop.context, [pause({**admin_accounts, "admin": other.pubkey()})], [other])
+
+    await send(airdrop.context, [pause(admin_accounts)], [admin])
+    assert (await get_distributor(airdrop)).paused
+    with raises(BanksClientError):
+        await send(airdrop.context, [claim_ix], [airdrop.claimant])
+
+    await send(airdrop.context, [unpause(admin_accounts)], [admin])
+    assert not (await get_distributor(airdrop)).paused
+    await send(airdrop.context, [claim_ix], [airdrop.claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+
+
+@mark.asyncio
+async def test_clawback_start():
+    """Test that the admin claws back the vault once the clawback start has passed, not before"""
+    amount = 1_000
+    airdrop = await single_leaf_airdrop(amount, 0)
+    clawback_ix = clawback(clawback_accounts(airdrop))
+
+    await set_time(airdrop.context, airdrop.args["clawback_start_ts"] - 1)
+    with raises(BanksClientError):
+        await send(airdrop.context, [clawback_ix], [airdrop.creator])
+    assert await token_amount(airdrop.context, airdrop.clawback_ata) == 0
+
+    await set_time(airdrop.context, airdrop.args["clawback_start_ts"])
+    await send(airdrop.context, [clawback_ix], [airdrop.creator])
+    assert await token_amount(airdrop.context, airdrop.clawback_ata) == amount
+    assert (await get_distributor(airdrop)).clawed_back
+
+
+@mark.asyncio
+async def test_clawback_receiver():
+    """Test that clawbacks only go to the stored receiver, which only the admin replaces"""
+    amount = 1_000
+    airdrop = await single_leaf_airdrop(amount, 0)
+    new_receiver = Pubkey.new_unique()
+    new_receiver_ata = get_associated_token_address(new_receiver, airdrop.mint)
+    airdrop.context.set_account(new_receiver_ata, token_account(airdrop.mint, new_receiver, 0))
+
+    await set_time(airdrop.context, airdrop.args["clawback_start_ts"])
+    to_new_receiver = clawback({**clawback_accounts(airdrop), "to": new_receiver_ata})
+    with raises(BanksClientError):
+        await send(airdrop.context, [to_new_receiver], [airdrop.creator])
+
+    other = Keypair()
+    receiver_accounts = {
+        "distributor": airdrop.distributor,
+        "new_clawback_account": new_receiver_ata,
+        "admin": other.pubkey(),
+    }
+    with raises(BanksClientError):
+        await send(airdrop.context, [set_clawback_receiver(receiver_accounts)], [other])
+
+    admin = airdrop.creator
+    await send(
+        airdrop.context,
+        [set_clawback_receiver({**receiver_accounts, "admin": admin.pubkey()})],
+        [admin],
+    )
+    assert (await get_distributor(airdrop)).clawback_receiver == new_receiver_ata
+    with raises(BanksClientError):
+        await send(airdrop.context, [clawback(clawback_accounts(airdrop))], [admin])
+
+    await send(airdrop.context, [to_new_receiver], [admin])
+    assert await token_amount(airdrop.context, new_receiver_ata) == amount
+    assert await token_amount(airdrop.context, airdrop.clawback_ata) == 0
+
+
+@mark.asyncio
+async def test_admin_transfer():
+    """Test that the admin role only moves to the proposed admin once they accept it"""
+    airdrop = await single_leaf_airdrop(1_000, 0)
+    admin = airdrop.creator
+    new_admin = Keypair()
+    other = Keypair()
+    admin_accounts = {"distributor": airdrop.distributor, "admin": admin.pubkey()}
+    propose_ix = propose_admin({**admin_accounts, "new_admin": new_admin.pubkey()})
+
+    other_accounts = {"distributor": airdrop.distributor, "admin": other.pubkey()}
+    with raises(BanksClientError):
+        ix = propose_admin({**other_accounts, "new_admin": other.pubkey()})
+        await send(airdrop.context, [ix], [other])
+
+    await send(airdrop.context, [propose_ix], [admin])
+    assert (await get_distributor(airdrop)).pending_admin == new_admin.pubkey()
+    with raises(BanksClientError):
+        ix = accept_admin({"distributor": airdrop.distributor, "new_admin": other.pubkey()})
+        await send(airdrop.context, [ix], [other])
+
+    # a cancelled proposal can't be accepted
+    accept_ix = accept_admin({"distributor": airdrop.distributor, "new_admin": new_admin.pubkey()})
+    await send(airdrop.context, [cancel_admin_proposal(admin_accounts)], [admin])
+    with raises(BanksClientError):
+        await send(airdrop.context, [accept_ix], [new_admin])
+
+    await send(airdrop.context, [propose_ix], [admin])
+    await send(airdrop.context, [accept_ix], [new_admin])
+    distributor = await get_distributor(airdrop)
+    assert distributor.admin == new_admin.pubkey()
+    assert distributor.pending_admin == Pubkey.default()
+
+    with raises(BanksClientError):
+        await send(airdrop.context, [pause(admin_accounts)], [admin])
+    await send(
+        airdrop.context, [pause({**admin_accounts, "admin": new_admin.pubkey()})], [new_admin]
+    )
+
+
+@mark.asyncio
+async def test_sponsored_claim():
+    """Test that a relayer claims for a claimant without their signature once the admin allows
+    permissionless claims, and only to the claimant's associated token account"""
+    amount = 1_000
+    airdrop = await single_leaf_airdrop(amount, 0)
+    relayer = Keypair()
+    airdrop.context.set_account(
+        relayer.pubkey(), Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID)
+    )
+    claimant = airdrop.claimant.pubkey()
+    other_account = Pubkey.new_unique()
+    airdrop.context.set_account(other_account, token_account(airdrop.mint, claimant, 0))
+    accounts = {**airdrop.claim_accounts, "payer": relayer.pubkey()}
+    claim_ix = new_claim(claim_args(amount), accounts, claimant_signer=False)
+
+    with raises(BanksClientError):
+        await send(airdrop.context, [claim_ix], [relayer])
+
+    await send(
+        airdrop.context,
+        [
+            set_permissionless_claims(
+                {"permissionless_claims": True},
+                {"distributor": airdrop.distributor, "admin": airdrop.creator.pubkey()},
+            )
+        ],
+        [airdrop.creator],
+    )
+    with raises(BanksClientError):
+        ix = new_claim(claim_args(amount), {**accounts, "to": other_account}, claimant_signer=False)
+        await send(airdrop.context, [ix], [relayer])
+
+    await send(airdrop.context, [claim_ix], [relayer])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+    claimant_wallet = await airdrop.context.banks_client.get_account(claimant)
+    assert claimant_wallet.lamports == 1_000_000_000
+
+
+@mark.asyncio
+async def test_claim_bitmap():
+    """Test that a bitmap tracked leaf is claimed once, without a claim status account"""
+    amount = 1_000
+    index = 0
+    airdrop = await single_leaf_airdrop(amount, 0, index=index)
+    (claim_bitmap, _) = Pubkey.find_program_address(
+        [b"ClaimBitmap", bytes(airdrop.distributor), (0).to_bytes(4, "little")], PROGRAM_ID
+    )
+    await send(
+        airdrop.context,
+        [
+            init_claim_bitmap(
+                {"page": 0},
+                {
+                    "distributor": airdrop.distributor,
+                    "claim_bitmap": claim_bitmap,
+                    "payer": airdrop.creator.pubkey(),
+                },
+            )
+        ],
+        [airdrop.creator],
+    )
+
+    # claim status accounts are refused on bitmap tracked distributors
+    with raises(BanksClientError):
+        ix = new_claim(claim_args(amount), airdrop.claim_accounts)
+        await send(airdrop.context, [ix], [airdrop.claimant])
+
+    claim_accounts = {
+        "distributor": airdrop.distributor,
+        "claim_bitmap": claim_bitmap,
+        "from_": airdrop.distributor_ata,
+        "to": airdrop.claimant_ata,
+        "claimant": airdrop.claimant.pubkey(),
+        "mint": airdrop.mint,
+        "token_program": TOKEN_PROGRAM_ID,
+    }
+    claim_ix = new_claim_bitmap(
+        {"index": index, "amount_unlocked": amount, "proof": []}, claim_accounts
+    )
+    await send(airdrop.context, [claim_ix], [airdrop.claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+    assert await airdrop.context.banks_client.get_account(airdrop.claim_status) is None
+    assert (await get_distributor(airdrop)).num_nodes_claimed == 1
+
+    with raises(BanksClientError):
+        await send(airdrop.context, [claim_ix], [airdrop.claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+
+
+@mark.asyncio
+async def test_fund_distributor_shortfall():
+    """Test that claims wait until the creator has funded the vault for every unclaimed token"""
+    amount = 1_000
+    airdrop = await single_leaf_airdrop(amount, 0, funded=False)
+    creator = airdrop.creator
+    fund_accounts = {
+        "distributor": airdrop.distributor,
+        "token_vault": airdrop.distributor_ata,
+        "from_": get_associated_token_address(creator.pubkey(), airdrop.mint),
+        "creator": creator.pubkey(),
+        "mint": airdrop.mint,
+        "token_program": TOKEN_PROGRAM_ID,
+    }
+    claim_ix = new_claim(claim_args(amount), airdrop.claim_accounts)
+
+    fund_ix = fund_distributor({"amount": amount - 1}, fund_accounts)
+    await send(airdrop.context, [fund_ix], [creator])
+    distributor = await get_distributor(airdrop)
+    assert distributor.total_funded == amount - 1
+    assert not distributor.funded
+    with raises(BanksClientError):
+        await send(airdrop.context, [claim_ix], [airdrop.claimant])
+
+    # only the creator funds the distributor
+    other = Keypair()
+    with raises(BanksClientError):
+        ix = fund_distributor({"amount": 1}, {**fund_accounts, "creator": other.pubkey()})
+        await send(airdrop.context, [ix], [other])
+
+    await send(airdrop.context, [fund_distributor({"amount": 1}, fund_accounts)], [creator])
+    assert (await get_distributor(airdrop)).funded
+    await send(airdrop.context, [claim_ix], [airdrop.claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+
+
+@mark.asyncio
+async def test_claim_end():
+    """Test that new claims close at the claim end while existing claims keep withdrawing"""
+    amount_unlocked = 100
+    amount_locked = 900
+    late = await single_leaf_airdrop(amount_unlocked, amount_locked)
+    await set_time(late.context, late.args["claim_end_ts"])
+    with raises(BanksClientError):
+        ix = new_claim(
+            {**claim_args(amount_unlocked), "amount_locked": amount_locked}, late.claim_accounts
+        )
+        await send(late.context, [ix], [late.claimant])
+
+    airdrop = await single_leaf_airdrop(amount_unlocked, amount_locked)
+    ix = new_claim(
+        {**claim_args(amount_unlocked), "amount_locked": amount_locked}, airdrop.claim_accounts
+    )
+    await send(airdrop.context, [ix], [airdrop.claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount_unlocked
+
+    # the claim end is also the end of vesting, the whole locked amount is withdrawable
+    await set_time(airdrop.context, airdrop.args["claim_end_ts"])
+    await send(airdrop.context, [claim_locked(airdrop.claim_accounts)], [airdrop.claimant])
+    assert (
+        await token_amount(airdrop.context, airdrop.claimant_ata) == amount_unlocked + amount_locked
+    )
+
+
+@mark.asyncio
+async def test_clawback_unclaimed():
+    """Test that the admin claws back the unclaimed allocation once, leaving existing claims their
+    locked tokens"""
+    amount_unlocked = 100
+    amount_locked = 900
+    unclaimed = 500
+    amount = amount_unlocked + amount_locked
+    airdrop = await single_leaf_airdrop(
+        amount_unlocked, amount_locked, funded=False, max_total_claim=amount + unclaimed
+    )
+    # the allocation of a leaf missing from the tree is sent to the vault directly
+    airdrop.context.set_account(
+        airdrop.distributor_ata,
+        token_account(airdrop.mint, airdrop.distributor, amount + unclaimed),
+    )
+    await send(
+        airdrop.context,
+        [
+            fund_distributor(
+                {"amount": 0},
+                {
+                    "distributor": airdrop.distributor,
+                    "token_vault": airdrop.distributor_ata,
+                    "from_": get_associated_token_address(airdrop.creator.pubkey(), airdrop.mint),
+                    "creator": airdrop.creator.pubkey(),
+                    "mint": airdrop.mint,
+                    "token_program": TOKEN_PROGRAM_ID,
+                },
+            )
+        ],
+        [airdrop.creator],
+    )
+    ix = new_claim(
+        {**claim_args(amount_unlocked), "amount_locked": amount_locked}, airdrop.claim_accounts
+    )
+    await send(airdrop.context, [ix], [airdrop.claimant])
+
+    await set_time(airdrop.context, airdrop.args["clawback_start_ts"])
+    clawback_ix = clawback_unclaimed(clawback_accounts(airdrop))
+    await send(airdrop.context, [clawback_ix], [airdrop.creator])
+    assert await token_amount(airdrop.context, airdrop.clawback_ata) == unclaimed
+    distributor = await get_distributor(airdrop)
+    assert distributor.unclaimed_clawed_back
+    assert not distributor.clawed_back
+
+    with raises(BanksClientError):
+        await send(airdrop.context, [clawback_ix], [airdrop.creator])
+    assert await token_amount(airdrop.context, airdrop.clawback_ata) == unclaimed
+
+    await send(airdrop.context, [claim_locked(airdrop.claim_accounts)], [airdrop.claimant])
+    assert await token_amount(airdrop.context, airdrop.claimant_ata) == amount
+    assert await token_amount(airdrop.context, airdrop.distributor_ata) == 0
+
+
+@mark.asyncio
+async def test_revoke_claim():
+    """Test that the admin of a revocable distributor returns the unvested tokens of a claim, whose
+    vested tokens stay withdrawable"""
+    amount_unlocked = 100
+    amount_locked = 1_000
+    args = {**claim_args(amount_unlocked), "amount_locked": amount_locked}
+
+    fixed = await single_leaf_airdrop(amount_unlocked, amount_locked)
+    await send(fixed.context, [new_claim(args, fixed.claim_accounts)], [fixed.claimant])
+    with raises(BanksClientError):
+        ix = revoke_claim({**clawback_accounts(fixed), "claim_status": fixed.claim_status})
+        await send(fixed.context, [ix], [fixed.creator])
+
+    airdrop = await single_leaf_airdrop(amount_unlocked, amount_locked, revocable=True)
+    await send(airdrop.context, [new_claim(args, airdrop.claim_accounts)], [airdrop.claimant])
+
+    # half of the locked tokens have vested
+    start_ts = airdrop.args["start_vesting_ts"]
+    end_ts = airdrop.args["end_vesting_ts"]
+    await set_time(airdrop.context, (start_ts + end_ts) // 2)
+    revoke_ix = revoke_claim({**clawback_accounts(airdrop), "claim_status": airdrop.claim_status})
+    await send(airdrop.context, [revoke_ix], [airdrop.creator])
+    assert await token_amount(airdrop.context, airdrop.clawback_ata) == amount_locked // 2
+
+    with raises(BanksClientError):
+        await send(airdrop.context, [revoke_ix], [airdrop.creator])
+
+    await set_time(airdrop.context, end_ts)
+    await send(airdrop.context, [claim_locked(airdrop.claim_accounts)], [airdrop.claimant])
+    assert (
+        await token_amount(airdrop.context, airdrop.claimant_ata)
+        == amount_unlocked + amount_locked // 2
+    )
+
+
+@mark.asyncio
+async def test_transfer_and_recover_claim():
+    """Test that a claim moves to a new wallet by the claimant, or by the admin of a recoverable
+    distributor with the new wallet, and that only its current claimant withdraws"""
+    amount_unlocked = 100
+    amount_locked = 900
+    airdrop = await single_leaf_airdrop(amount_unlocked, amount_locked, recoverable=True)
+    claimant = airdrop.claimant
+    ix = new_claim(
+        {**claim_args(amount_unlocked), "amount_locked": amount_locked}, airdrop.claim_accounts
+    )
+    await send(airdrop.context, [ix], [claimant])
+
+    new_wallet = Keypair()
+    recovered_wallet = Keypair()
+    wallet_accounts = {}
+    for wallet in [new_wallet, recovered_wallet]:
+        ata = get_associated_token_address(wallet.pubkey(), airdrop.mint)
+        airdrop.context.set_account(ata, token_account(airdrop.mint, wallet.pubkey(), 0))
+        wallet_accounts[wallet.pubkey()] = {
+            **airdrop.claim_accounts,
+            "to": ata,
+            "claimant": wallet.pubkey(),
+        }
+    transfer_accounts = {
+        "distributor": airdrop.distributor,
+        "claim_status": airdrop.claim_status,
+        "claimant": new_wallet.pubkey(),
+        "new_claimant": new_wallet.pubkey(),
+    }
+    with raises(BanksClientError):
+        await send(airdrop.context, [transfer_claim(transfer_accounts)], [new_wallet])
+    await send(
+        airdrop.context,
+        [transfer_claim({**transfer_accounts, "claimant": claimant.pubkey()})],
+        [claimant],
+    )
+
+    # half of the locked tokens have vested
+    start_ts = airdrop.args["start_vesting_ts"]
+    end_ts = airdrop.args["end_vesting_ts"]
+    await set_time(airdrop.context, (start_ts + end_ts) // 2)
+    with raises(BanksClientError):
+        await send(airdrop.context, [claim_locked(airdrop.claim_accounts)], [claimant])
+    new_wallet_accounts = wallet_accounts[new_wallet.pubkey()]
+    await send(airdrop.context, [claim_locked(new_wallet_accounts)], [new_wallet])
+    assert await token_amount(airdrop.context, new_wallet_accounts["to"]) == amount_locked // 2
+
+    recover_accounts = {
+        "distributor": airdrop.distributor,
+        "claim_status": airdrop.claim_status,
+        "admin": new_wallet.pubkey(),
+        "new_claimant": recovered_wallet.pubkey(),
+    }
+    with raises(BanksClientError):
+        ix = recover_claim(recover_accounts)
+        await send(airdrop.context, [ix], [new_wallet, recovered_wallet])
+    await send(
+        airdrop.context,
+        [recover_claim({**recover_accounts, "admin": airdrop.creator.pubkey()})],
+        [airdrop.creator, recovered_wallet],
+    )
+
+    await set_time(airdrop.context, end_ts)
+    with raises(BanksClientError):
+        await send(airdrop.context, [claim_locked(new_wallet_accounts)], [new_wallet])
+    recovered_accounts = wallet_accounts[recovered_wallet.pubkey()]
+    await send(airdrop.context, [claim_locked(recovered_accounts)], [recovered_wallet])
+    assert await token_amount(airdrop.context, recovered_accounts["to"]) == amount_locked // 2
+
+
+@mark.asyncio
+async def test_sol_distributor():
+    """Test that a native SOL distributor pays lamports straight to the claimant's wallet, next to a
+    wrapped SOL token distributor of the same creator and version"""
+    amount_unlocked = 100_000_000
+    amount_locked = 900_000_000
+    unclaimed = 500_000_000
+    amount = amount_unlocked + amount_locked
+    creator = Keypair()
+    claimant = Keypair()
+    clawback_receiver = Pubkey.new_unique()
+    (distributor, _) = get_sol_distributor_pda(PROGRAM_ID, creator.pubkey(), 0)
+    (sol_vault, _) = get_sol_vault_pda(PROGRAM_ID, distributor)
+    (token_distributor, _) = get_distributor_pda(NATIVE_MINT, PROGRAM_ID, creator.pubkey(), 0)
+    token_vault = get_associated_token_address(token_distributor, NATIVE_MINT)
+    token_clawback_receiver = get_associated_token_address(clawback_receiver, NATIVE_MINT)
+    wallet = Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID)
+    context = await start_anchor(
+        Path("../"),
+        accounts=[
+            (creator.pubkey(), Account(lamports=10_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID)),
+            (claimant.pubkey(), wallet),
+            (clawback_receiver, wallet),
+            (
+                NATIVE_MINT,
+                Account(
+                    data=bytes(
+                        Mint(decimals=9, mint_authority=None, supply=0, is_initialized=True)
+                    ),
+                    lamports=100_000,
+                    owner=TOKEN_PROGRAM_ID,
+                ),
+            ),
+            (token_vault, token_account(NATIVE_MINT, token_distributor, 0)),
+            (token_clawback_receiver, token_account(NATIVE_MINT, clawback_receiver, 0)),
+        ],
+    )
+    curr_ts = (await context.banks_client.get_clock()).unix_timestamp
+    args = {
+        **new_distributor_args(curr_ts),
+        "root": list(get_leaf_hash(claimant.pubkey(), amount_unlocked, amount_locked)),
+        "max_total_claim": amount,
+    }
+    token_distributor_ix = new_distributor(
+        args,
+        {
+            "distributor": token_distributor,
+            "mint": NATIVE_MINT,
+            "token_vault": token_vault,
+            "clawback_receiver": token_clawback_receiver,
+            "creator": creator.pubkey(),
+            "token_program": TOKEN_PROGRAM_ID,
+        },
+    )
+    sol_distributor_ix = new_sol_distributor(
+        args,
+        {
+            "distributor": distributor,
+            "sol_vault": sol_vault,
+            "clawback_receiver": clawback_receiver,
+            "creator": creator.pubkey(),
+        },
+    )
+    await send(context, [token_distributor_ix, sol_distributor_ix], [creator])
+    for address in [token_distributor, distributor]:
+        assert await context.banks_client.get_account(address) is not None
+
+    (claim_status, _) = Pubkey.find_program_address(
+        [b"ClaimStatus", bytes(claimant.pubkey()), bytes(distributor)], PROGRAM_ID
+    )
+    claim_accounts = {
+        "distributor": distributor,
+        "claim_status": claim_status,
+        "sol_vault": sol_vault,
+        "claimant": claimant.pubkey(),
+        "payer": context.payer.pubkey(),
+    }
+    claim_ix = new_claim_sol(
+        {**claim_args(amount_unlocked), "amount_locked": amount_locked}, claim_accounts
+    )
+    with raises(BanksClientError):
+        await send(context, [claim_ix], [claimant])
+
+    fund_ix = fund_sol_distributor(
+        {"amount": amount + unclaimed},
+        {"distributor": distributor, "sol_vault": sol_vault, "creator": creator.pubkey()},
+    )
+    await send(context, [fund_ix], [creator])
+    await send(context, [claim_ix], [claimant])
+    claimant_wallet = await context.banks_client.get_account(claimant.pubkey())
+    assert claimant_wallet.lamports == wallet.lamports + amount_unlocked
+
+    await set_time(context, args["end_vesting_ts"])
+    await send(context, [claim_locked_sol(claim_accounts)], [claimant])
+    claimant_wallet = await context.banks_client.get_account(claimant.pubkey())
+    assert claimant_wallet.lamports == wallet.lamports + amount
+
+    clawback_ix = clawback_sol(
+        {
+            "distributor": distributor,
+            "sol_vault": sol_vault,
+            "to": clawback_receiver,
+            "admin": creator.pubkey(),
+        }
+    )
+    with raises(BanksClientError):
+        await send(context, [clawback_ix], [creator])
+    await set_time(context, args["clawback_start_ts"])
+    await send(context, [clawback_ix], [creator])
+    receiver_wallet = await context.banks_client.get_account(clawback_receiver)
+    assert receiver_wallet.lamports == wallet.lamports + unclaimed
+
+
+@mark.asyncio
+async def test_stake_locked():
+    """Test that a claimant stakes their idle locked tokens while they vest, and that the admin
+    unstakes them back into the vault"""
+    amount_unlocked = 100
+    amount_locked = 900
+    staked = 600
+    airdrop = await single_leaf_airdrop(
+        amount_unlocked, amount_locked, extra_programs=[MOCK_STAKING_PROGRAM]
+    )
+    context = airdrop.context
+    admin = airdrop.creator
+    (stake_pool, _) = get_stake_pool_pda(airdrop.mint)
+    stake_vault = get_associated_token_address(stake_pool, airdrop.mint)
+    # every claim stakes into its own stake account, owned by the claim's stake authority
+    (stake_authority, _) = Pubkey.find_program_address(
+        [b"StakeAuthority", bytes(airdrop.claim_status)], PROGRAM_ID
+    )
+    (stake_account, _) = get_stake_account_pda(stake_authority, stake_pool)
+    (other_stake_account, _) = get_stake_account_pda(airdrop.claimant.pubkey(), stake_pool)
+    (central_state, _) = get_central_state_pda()
+    fee_account = get_associated_token_address(central_state, airdrop.mint)
+    context.set_account(stake_vault, token_account(airdrop.mint, stake_pool, 0))
+    context.set_account(fee_account, token_account(airdrop.mint, central_state, 0))
+    payer = context.payer.pubkey()
+    await send(
+        context,
+        [
+            init_stake_pool_ix(stake_pool, airdrop.mint, stake_vault, payer, TOKEN_PROGRAM_ID),
+            create_stake_account_ix(stake_account, stake_authority, stake_pool, payer),
+            create_stake_account_ix(
+                other_stake_account, airdrop.claimant.pubkey(), stake_pool, payer
+            ),
+            set_staking_program(
+                {
+                    "distributor": airdrop.distributor,
+                    "staking_program": MOCK_STAKING_PROGRAM_ID,
+                    "stake_pool": stake_pool,
+                    "admin": admin.pubkey(),
+                }
+            ),
+        ],
+        [admin],
+    )
+    ix = new_claim(
+        {**claim_args(amount_unlocked), "amount_locked": amount_locked}, airdrop.claim_accounts
+    )
+    await send(context, [ix], [airdrop.claimant])
+
+    stake_accounts = {
+        "distributor": airdrop.distributor,
+        "claim_status": airdrop.claim_status,
+        "token_vault": airdrop.distributor_ata,
+        "authority": airdrop.claimant.pubkey(),
+        "token_program": TOKEN_PROGRAM_ID,
+        "central_state": central_state,
+        "stake_pool": stake_pool,
+        "stake_authority": stake_authority,
+        "stake_account": stake_account,
+        "stake_vault": stake_vault,
+        "fee_account": fee_account,
+        "staking_program": MOCK_STAKING_PROGRAM_ID,
+    }
+    with raises(BanksClientError):
+        ix = stake_locked({"amount": amount_locked + 1}, stake_accounts)
+        await send(context, [ix], [airdrop.claimant])
+    with raises(BanksClientError):
+        ix = stake_locked(
+            {"amount": staked}, {**stake_accounts, "stake_account": other_stake_account}
+        )
+        await send(context, [ix], [airdrop.claimant])
+
+    await send(context, [stake_locked({"amount": staked}, stake_accounts)], [airdrop.claimant])
+    assert get_stake_amount((await context.banks_client.get_account(stake_account)).data) == staked
+    assert await token_amount(context, airdrop.distributor_ata) == amount_locked - staked
+    assert (await get_distributor(airdrop)).total_locked_staked == staked
+
+    with raises(BanksClientError):
+        ix = unstake_locked({"amount": staked + 1}, stake_accounts)
+        await send(context, [ix], [airdrop.claimant])
+
+    await send(
+        context,
+        [unstake_locked({"amount": staked}, {**stake_accounts, "authority": admin.pubkey()})],
+        [admin],
+    )
+    assert get_stake_amount((await context.banks_client.get_account(stake_account)).data) == 0
+    assert await token_amount(context, airdrop.distributor_ata) == amount_locked
+
+    await set_time(context, airdrop.args["end_vesting_ts"])
+    await send(context, [claim_locked(airdrop.claim_accounts)], [airdrop.claimant])
+    assert (
+        await token_amount(context, airdrop.claimant_ata) == amount_unlocked + amount_locked
+    )
+
+
+@mark.asyncio
+async def test_stake_locked_rejects_fee():
+    """Test that staking into a stake pool charging a fee fails, so the fee is never taken out of
+    the vault"""
+    amount_locked = 900
+    airdrop = await single_leaf_airdrop(0, amount_locked, extra_programs=[MOCK_STAKING_PROGRAM])
+    context = airdrop.context
+    admin = airdrop.creator
+    (stake_pool, _) = get_stake_pool_pda(airdrop.mint)
+    stake_vault = get_associated_token_address(stake_pool, airdrop.mint)
+    (stake_authority, _) = Pubkey.find_program_address(
+        [b"StakeAuthority", bytes(airdrop.claim_status)], PROGRAM_ID
+    )
+    (stake_account, _) = get_stake_account_pda(stake_authority, stake_pool)
+    (central_state, _) = get_central_state_pda()
+    fee_account = get_associated_token_address(central_state, airdrop.mint)
+    context.set_account(stake_vault, token_account(airdrop.mint, stake_pool, 0))
+    context.set_account(fee_account, token_account(airdrop.mint, central_state, 0))
+    payer = context.payer.pubkey()
+    await send(
+        context,
+        [
+            # 1% fee
+            init_stake_pool_ix(
+                stake_pool, airdrop.mint, stake_vault, payer, TOKEN_PROGRAM_ID, 100
+            ),
+            create_stake_account_ix(stake_account, stake_authority, stake_pool, payer),
+            set_staking_program(
+                {
+                    "distributor": airdrop.distributor,
+                    "staking_program": MOCK_STAKING_PROGRAM_ID,
+                    "stake_pool": stake_pool,
+                    "admin": admin.pubkey(),
+                }
+            ),
+        ],
+        [admin],
+    )
+    ix = new_claim({**claim_args(0), "amount_locked": amount_locked}, airdrop.claim_accounts)
+    await send(context, [ix], [airdrop.claimant])
+
+    stake_accounts = {
+        "distributor": airdrop.distributor,
+        "claim_status": airdrop.claim_status,
+        "token_vault": airdrop.distributor_ata,
+        "authority": airdrop.claimant.pubkey(),
+        "token_program": TOKEN_PROGRAM_ID,
+        "central_state": central_state,
+        "stake_pool": stake_pool,
+        "stake_authority": stake_authority,
+        "stake_account": stake_account,
+        "stake_vault": stake_vault,
+        "fee_account": fee_account,
+        "staking_program": MOCK_STAKING_PROGRAM_ID,
+    }
+    # the fee on top of the amount exceeds what the vault delegated, even with idle tokens left
+    with raises(BanksClientError):
+        ix = stake_locked({"amount": 500}, stake_accounts)
+        await send(context, [ix], [airdrop.claimant])
+    assert await token_amount(context, airdrop.distributor_ata) == amount_locked
+    assert await token_amount(context, fee_account) == 0
+
+
+@mark.asyncio
+async def test_sol_custody():
+    """Test that an owner withdraws up to their custody balance once the custody lock has passed"""
+    deposit = 2_000_000_000
+    owner = Keypair()
+    balance = 10_000_000_000
+    context = await start_anchor(
+        Path("../"),
+        accounts=[(owner.pubkey(), Account(lamports=balance, data=b"", owner=SYSTEM_PROGRAM_ID))],
+    )
+    (sol_custody, _) = Pubkey.find_program_address(
+        [b"SolCustodyState", bytes(owner.pubkey())], PROGRAM_ID
+    )
+    (custody_vault, _) = Pubkey.find_program_address(
+        [b"SolCustody", bytes(owner.pubkey())], PROGRAM_ID
+    )
+    accounts = {"sol_custody": sol_custody, "custody_vault": custody_vault, "owner": owner.pubkey()}
+    lock_until_ts = (await context.banks_client.get_clock()).unix_timestamp + 1_000
+
+    ix = deposit_custody_sol({"amount_lamports": deposit, "lock_until_ts": lock_until_ts}, accounts)
+    await send(context, [ix], [owner])
+    custody = SolCustody.decode((await context.banks_client.get_account(sol_custody)).data)
+    assert custody.total_deposited == deposit
+    assert (await context.banks_client.get_account(custody_vault)).lamports == deposit
+
+    # the lock can't be shortened, nor withdrawn from before it passes
+    with raises(BanksClientError):
+        args = {"amount_lamports": 0, "lock_until_ts": lock_until_ts - 1}
+        await send(context, [deposit_custody_sol(args, accounts)], [owner])
+    with raises(BanksClientError):
+        await send(context, [withdraw_custody_sol({"amount_lamports": 1}, accounts)], [owner])
+
+    await set_time(context, lock_until_ts)
+    with raises(BanksClientError):
+        ix = withdraw_custody_sol({"amount_lamports": deposit + 1}, accounts)
+        await send(context, [ix], [owner])
+
+    # lamports sent to the vault directly are withdrawable too
+    sent = 1_000_000_000
+    context.set_account(
+        custody_vault, Account(lamports=deposit + sent, data=b"", owner=SYSTEM_PROGRAM_ID)
+    )
+    owner_lamports = (await context.banks_client.get_account(owner.pubkey())).lamports
+    ix = withdraw_custody_sol({"amount_lamports": deposit + sent}, accounts)
+    await send(context, [ix], [owner])
+    owner_wallet = await context.banks_client.get_account(owner.pubkey())
+    assert owner_wallet.lamports == owner_lamports + deposit + sent
+    assert await context.banks_client.get_account(custody_vault) is None
+    custody = SolCustody.decode((await context.banks_client.get_account(sol_custody)).data)
+    assert custody.total_deposited == custody.total_withdrawn == deposit + sent
+
+
+@mark.asyncio
+async def test_migrate_distributor():
+    """Test that the admin migrates a distributor of the legacy layout once"""
+    max_total_claim = 1_000
+    total_amount_claimed = 400
+    mint = Pubkey.new_unique()
+    creator = Keypair()
+    (distributor, bump) = get_distributor_pda(mint, PROGRAM_ID, creator.pubkey(), 0)
+    token_vault = get_associated_token_address(distributor, mint)
+    clawback_ata = get_associated_token_address(creator.pubkey(), mint)
+    curr_ts = 1_700_000_000
+    # the distributor layout before any fields were added to it, padded to its struct size
+    legacy_data = (
+        MerkleDistributor.discriminator
+        + struct.pack("<BQ", bump, 0)
+        + bytes(32)
+        + bytes(mint)
+        + bytes(token_vault)
+        + struct.pack("<QQQQ", max_total_claim, 1, total_amount_claimed, 1)
+        + struct.pack("<qq", curr_ts, curr_ts + 100)
+        + bytes(creator.pubkey())
+        + bytes(creator.pubkey())
+        + bytes([0])
+    ).ljust(8 + 224, b"\x00")
+    context = await start_anchor(
+        Path("../"),
+        accounts=[
+            (creator.pubkey(), Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID)),
+            (distributor, Account(lamports=3_000_000, data=legacy_data, owner=PROGRAM_ID)),
+            (token_vault, token_account(mint, distributor, max_total_claim - total_amount_claimed)),
+            (clawback_ata, token_account(mint, creator.pubkey(), 0)),
+        ],
+    )
+    claim_end_ts = curr_ts + 1_000
+    accounts = {
+        "distributor": distributor,
+        "token_vault": token_vault,
+        "clawback_receiver": clawback_ata,
+        "admin": creator.pubkey(),
+    }
+    migrate_ix = migrate_distributor({"claim_end_ts": claim_end_ts}, accounts)
+
+    other = Keypair()
+    context.set_account(
+        other.pubkey(), Account(lamports=1_000_000_000, data=b"", owner=SYSTEM_PROGRAM_ID)
+    )
+    with raises(BanksClientError):
+        ix = migrate_distributor({"claim_end_ts": None}, {**accounts, "admin": other.pubkey()})
+        await send(context, [ix], [other])
+
+    await send(context, [migrate_ix], [creator])
+    account = await context.banks_client.get_account(distributor)
+    assert len(account.data) > len(legacy_data)
+    assert account.lamports > 3_000_000
+    migrated = MerkleDistributor.decode(account.data)
+    assert migrated.layout_version == 1
+    assert migrated.total_amount_claimed == total_amount_claimed
+    assert migrated.claim_end_ts == claim_end_ts
+    assert migrated.clawback_receiver == clawback_ata
+    assert migrated.funded
+
+    with raises(BanksClientError):
+        await send(context, [migrate_ix], [creator])
+
+
 @mark.asyncio
 def test_load_merkle_tree():
     """Test that loading the merkle tree works correctly"""
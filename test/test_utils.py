@@ -1,5 +1,25 @@
+from hashlib import sha256
+import struct
+
+from solders.instruction import AccountMeta, Instruction
 from solders.pubkey import Pubkey
 
+SYSTEM_PROGRAM_ID = Pubkey.from_string("11111111111111111111111111111111")
+MOCK_STAKING_PROGRAM_ID = Pubkey.from_string("AHPY5uEMBk8x4KwYkKoHUKFeV4rjt7VyCXxULHHG36Wc")
+MOCK_TRANSFER_HOOK_PROGRAM_ID = Pubkey.from_string("H28i597XTB2oFTJyMk1K6kx8vU6j1ML9VRQT92nHuUvK")
+TOKEN_2022_PROGRAM_ID = Pubkey.from_string("TokenzQdBNbLqP5VEhdkAS6EjFLC1zx4Hnk3GBrqCWQ")
+ASSOCIATED_TOKEN_PROGRAM_ID = Pubkey.from_string("ATokenGPvbdGVxr1b2hvZbsiqW5xWRoWcWgZgHCxGUFv")
+
+# Test-only programs outside the anchor workspace, loaded from target/deploy by start_anchor
+MOCK_STAKING_PROGRAM = ("mock_staking", MOCK_STAKING_PROGRAM_ID)
+MOCK_TRANSFER_HOOK_PROGRAM = ("mock_transfer_hook", MOCK_TRANSFER_HOOK_PROGRAM_ID)
+
+# Token-2022 extension types, see spl_token_2022::extension::ExtensionType
+TRANSFER_FEE_CONFIG = 1
+TRANSFER_FEE_AMOUNT = 2
+TRANSFER_HOOK = 14
+TRANSFER_HOOK_ACCOUNT = 15
+
 
 # get the distributor associated token address
 def get_distributor_pda(mint, program_id, creator, version=0):
@@ -13,3 +33,191 @@ def get_distributor_pda(mint, program_id, creator, version=0):
         program_id
     )
     return distributor, bump
+
+
+# get the native SOL distributor address, which has no mint seed
+def get_sol_distributor_pda(program_id, creator, version=0):
+    return Pubkey.find_program_address(
+        [b"SolDistributor", bytes(creator), version.to_bytes(8, "little")],
+        program_id,
+    )
+
+
+# get the sol vault of a native SOL distributor
+def get_sol_vault_pda(program_id, distributor):
+    return Pubkey.find_program_address([b"SolVault", bytes(distributor)], program_id)
+
+
+# get the leaf hash of a merkle tree node without a vesting schedule
+def get_leaf_hash(claimant, amount_unlocked, amount_locked):
+    node = sha256(
+        bytes(claimant)
+        + amount_unlocked.to_bytes(8, "little")
+        + amount_locked.to_bytes(8, "little")
+    ).digest()
+    return sha256(b"\x00" + node).digest()
+
+
+# get the leaf hash of a bitmap tracked merkle tree node, which has an index and no locked amount
+def get_indexed_leaf_hash(claimant, amount_unlocked, index):
+    node = sha256(
+        bytes(claimant)
+        + amount_unlocked.to_bytes(8, "little")
+        + (0).to_bytes(8, "little")
+        # tagged leaf version, index flag and no extra amounts
+        + bytes([1, 1 << 1, 0])
+        + index.to_bytes(8, "little")
+    ).digest()
+    return sha256(b"\x00" + node).digest()
+
+
+# get the stake pool of a mint in the mock staking program
+def get_stake_pool_pda(mint):
+    return Pubkey.find_program_address(
+        [b"StakePool", bytes(mint)], MOCK_STAKING_PROGRAM_ID
+    )
+
+
+# get the stake account of an owner in the mock staking program, seeded like Access Protocol's
+def get_stake_account_pda(owner, stake_pool):
+    return Pubkey.find_program_address(
+        [b"stake_account", bytes(owner), bytes(stake_pool)], MOCK_STAKING_PROGRAM_ID
+    )
+
+
+# get the central state of the mock staking program, a PDA of its program id like Access Protocol's
+def get_central_state_pda():
+    return Pubkey.find_program_address(
+        [bytes(MOCK_STAKING_PROGRAM_ID)], MOCK_STAKING_PROGRAM_ID
+    )
+
+
+# build a mock staking program setup instruction, data starts with the anchor discriminator
+def mock_staking_ix(name, accounts, data=b""):
+    discriminator = sha256(f"global:{name}".encode()).digest()[:8]
+    return Instruction(MOCK_STAKING_PROGRAM_ID, discriminator + data, accounts)
+
+
+def init_stake_pool_ix(stake_pool, mint, vault, payer, token_program, fee_basis_points=0):
+    return mock_staking_ix(
+        "init_stake_pool",
+        [
+            AccountMeta(stake_pool, False, True),
+            AccountMeta(mint, False, False),
+            AccountMeta(vault, False, False),
+            AccountMeta(payer, True, True),
+            AccountMeta(token_program, False, False),
+            AccountMeta(SYSTEM_PROGRAM_ID, False, False),
+        ],
+        fee_basis_points.to_bytes(2, "little"),
+    )
+
+
+def create_stake_account_ix(stake_account, owner, stake_pool, payer):
+    return mock_staking_ix(
+        "create_stake_account",
+        [
+            AccountMeta(stake_account, False, True),
+            AccountMeta(owner, False, False),
+            AccountMeta(stake_pool, False, False),
+            AccountMeta(payer, True, True),
+            AccountMeta(SYSTEM_PROGRAM_ID, False, False),
+        ],
+    )
+
+
+# read the staked amount of a mock staking program stake account
+def get_stake_amount(stake_account_data):
+    # discriminator, owner and stake pool come first
+    return int.from_bytes(stake_account_data[72:80], "little")
+
+
+# get the associated token address of a Token-2022 account
+def get_associated_token_address_2022(owner, mint):
+    return Pubkey.find_program_address(
+        [bytes(owner), bytes(TOKEN_2022_PROGRAM_ID), bytes(mint)],
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+    )[0]
+
+
+# append Token-2022 extensions to a packed mint or token account, given as (type, data) pairs
+# mints are padded to the token account length, both are followed by their account type
+def with_extensions(base, account_type, extensions):
+    data = base.ljust(165, b"\x00") + bytes([account_type])
+    for (extension_type, extension_data) in extensions:
+        data += struct.pack("<HH", extension_type, len(extension_data)) + extension_data
+    return data
+
+
+# the mint transfer fee extension, the same fee applies to the older and newer epochs
+def transfer_fee_config(basis_points, maximum_fee):
+    transfer_fee = struct.pack("<QQH", 0, maximum_fee, basis_points)
+    # no fee config and withdraw authorities, nothing withheld in the mint
+    return (TRANSFER_FEE_CONFIG, bytes(64) + struct.pack("<Q", 0) + transfer_fee * 2)
+
+
+# the transfer fee withheld in a token account of a transfer fee mint
+def transfer_fee_amount(withheld_amount=0):
+    return (TRANSFER_FEE_AMOUNT, struct.pack("<Q", withheld_amount))
+
+
+# read the transfer fee withheld in a token account with only the transfer fee amount extension
+def get_withheld_amount(token_account_data):
+    # base account, account type and the extension type and length come first
+    return int.from_bytes(token_account_data[170:178], "little")
+
+
+# the mint transfer hook extension, without an authority
+def transfer_hook(program_id):
+    return (TRANSFER_HOOK, bytes(32) + bytes(program_id))
+
+
+# the transferring flag token accounts of a transfer hook mint carry
+def transfer_hook_account():
+    return (TRANSFER_HOOK_ACCOUNT, bytes(1))
+
+
+# get the extra account metas and counter of a mint in the mock transfer hook program
+def get_transfer_hook_pdas(mint):
+    (extra_account_metas, _) = Pubkey.find_program_address(
+        [b"extra-account-metas", bytes(mint)], MOCK_TRANSFER_HOOK_PROGRAM_ID
+    )
+    (counter, _) = Pubkey.find_program_address(
+        [b"Counter", bytes(mint)], MOCK_TRANSFER_HOOK_PROGRAM_ID
+    )
+    return extra_account_metas, counter
+
+
+# the accounts Token-2022 needs to invoke the mock transfer hook, passed as remaining accounts
+def transfer_hook_accounts(mint):
+    (extra_account_metas, counter) = get_transfer_hook_pdas(mint)
+    return [
+        AccountMeta(MOCK_TRANSFER_HOOK_PROGRAM_ID, False, False),
+        AccountMeta(extra_account_metas, False, False),
+        AccountMeta(counter, False, True),
+    ]
+
+
+def initialize_extra_account_meta_list_ix(mint, payer):
+    (extra_account_metas, counter) = get_transfer_hook_pdas(mint)
+    discriminator = sha256(b"global:initialize_extra_account_meta_list").digest()[:8]
+    return Instruction(
+        MOCK_TRANSFER_HOOK_PROGRAM_ID,
+        discriminator,
+        [
+            AccountMeta(extra_account_metas, False, True),
+            AccountMeta(counter, False, True),
+            AccountMeta(mint, False, False),
+            AccountMeta(payer, True, True),
+            AccountMeta(SYSTEM_PROGRAM_ID, False, False),
+        ],
+    )
+
+
+# read the number and total amount of transfers a mock transfer hook counter recorded
+def get_transfer_hook_counter(counter_data):
+    # discriminator and mint come first
+    return (
+        int.from_bytes(counter_data[40:48], "little"),
+        int.from_bytes(counter_data[48:56], "little"),
+    )
@@ -1,4 +1,4 @@
-use solana_program::hash::hashv;
+use solana_program::hash::{hashv, Hash};
 
 /// modified version of https://github.com/saber-hq/merkle-distributor/blob/ac937d1901033ecb7fa3b0db22f7b39569c8e052/programs/merkle-distributor/src/merkle_proof.rs#L8
 /// This function deals with verification of Merkle trees (hash trees).
@@ -21,3 +21,64 @@ pub fn verify(proof: Vec<[u8; 32]>, root: [u8; 32], leaf: [u8; 32]) -> bool {
     // Check if the computed hash (root) is equal to the provided root
     computed_hash == root
 }
+
+/// Version tag of leaves with a vesting schedule, extra amounts or an index.
+pub const LEAF_VERSION_TAGGED: u8 = 1;
+
+/// Flag of [hash_leaf] set when the leaf has its own vesting schedule.
+pub const LEAF_FLAG_VESTING_SCHEDULE: u8 = 1 << 0;
+/// Flag of [hash_leaf] set when the leaf has a bitmap index.
+pub const LEAF_FLAG_INDEX: u8 = 1 << 1;
+
+/// Hashes the claim of a leaf, before the leaf prefix is applied.
+/// Leaves with only the claimant and amounts hash as `claimant‖unlocked‖locked`, so roots
+/// generated before the optional fields existed stay valid. Any other leaf appends the
+/// [LEAF_VERSION_TAGGED] tag, a byte of flags marking which optional fields follow and the
+/// number of extra amounts, so no field can be read as another one.
+pub fn hash_leaf(
+    claimant: &[u8; 32],
+    amount_unlocked: u64,
+    amount_locked: u64,
+    vesting_schedule: Option<(i64, i64)>,
+    extra_amounts: &[(u64, u64)],
+    index: Option<u64>,
+) -> Hash {
+    let amount_unlocked = amount_unlocked.to_le_bytes();
+    let amount_locked = amount_locked.to_le_bytes();
+    let mut fields: Vec<&[u8]> = vec![claimant, &amount_unlocked, &amount_locked];
+    if vesting_schedule.is_none() && extra_amounts.is_empty() && index.is_none() {
+        return hashv(&fields);
+    }
+
+    let mut flags = 0;
+    if vesting_schedule.is_some() {
+        flags |= LEAF_FLAG_VESTING_SCHEDULE;
+    }
+    if index.is_some() {
+        flags |= LEAF_FLAG_INDEX;
+    }
+    // extra mints are capped well below u8::MAX on-chain
+    let tag = [LEAF_VERSION_TAGGED, flags, extra_amounts.len() as u8];
+    fields.push(&tag);
+
+    let vesting_schedule =
+        vesting_schedule.map(|(start_ts, end_ts)| [start_ts.to_le_bytes(), end_ts.to_le_bytes()]);
+    if let Some(vesting_schedule) = &vesting_schedule {
+        fields.extend(vesting_schedule.iter().map(|ts| ts.as_slice()));
+    }
+
+    let extra_amounts: Vec<[u8; 8]> = extra_amounts
+        .iter()
+        .flat_map(|(amount_unlocked, amount_locked)| {
+            [amount_unlocked.to_le_bytes(), amount_locked.to_le_bytes()]
+        })
+        .collect();
+    fields.extend(extra_amounts.iter().map(|amount| amount.as_slice()));
+
+    let index = index.map(u64::to_le_bytes);
+    if let Some(index) = &index {
+        fields.push(index);
+    }
+
+    hashv(&fields)
+}